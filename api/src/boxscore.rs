@@ -6,6 +6,16 @@ use std::collections::HashMap;
 pub struct Boxscore {
     pub teams: Option<Teams>,
     pub info: Option<Vec<LabelValue>>,
+    #[serde(default)]
+    pub officials: Vec<Official>,
+}
+
+/// An umpire and their position, e.g. "Home Plate" or "First Base".
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Official {
+    pub official: Person,
+    pub official_type: String,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -1,63 +1,232 @@
-use crate::live::LiveResponse;
+use crate::content::ContentResponse;
+use crate::draft::DraftResponse;
+use crate::leaders::{LeaderCategory, League, LeagueLeadersResponse};
+use crate::live::{LiveDiff, LiveFeed, LiveResponse};
 use crate::player::PeopleResponse;
-use crate::schedule::ScheduleResponse;
+use crate::plays::Plays;
+use crate::retry::{RetryPolicy, parse_retry_after};
+use crate::schedule::{PostseasonSeriesResponse, ScheduleResponse};
 use crate::season::{GameType, SeasonInfo, SeasonsResponse};
 use crate::standings::StandingsResponse;
 use crate::stats::StatsResponse;
 use crate::team::{RosterResponse, RosterType, TransactionsResponse};
 use crate::teams::{SportId, TeamsResponse};
+use crate::transport::{ReqwestTransport, Transport};
+use crate::vs_player::VsPlayerResponse;
 use crate::win_probability::WinProbabilityResponse;
 use std::fmt;
+use std::sync::Arc;
 use std::time::Duration;
 
 use chrono::{DateTime, Datelike, Local, NaiveDate};
 use derive_builder::Builder;
-use reqwest::Client;
+use reqwest::StatusCode;
 use serde::de::DeserializeOwned;
 
 pub type ApiResult<T> = Result<T, ApiError>;
 
 const BASE_URL: &str = "https://statsapi.mlb.com/api/";
+/// Maximum number of characters of an error response body kept in an `ApiError`.
+const BODY_SNIPPET_LEN: usize = 256;
 
 /// MLB API object
 #[derive(Builder, Debug, Clone)]
 #[allow(clippy::upper_case_acronyms)]
 pub struct MLBApi {
-    #[builder(default = "Client::new()")]
-    client: Client,
+    /// Performs the requests, see `transport` for the available implementations.
+    #[builder(default = "Arc::new(ReqwestTransport::default())")]
+    transport: Arc<dyn Transport>,
     #[builder(default = "Duration::from_secs(10)")]
     timeout: Duration,
     #[builder(setter(into), default = "String::from(BASE_URL)")]
     base_url: String,
+    /// Return `T::default()` for 4xx responses instead of an error. This was the only behavior
+    /// before `ApiError` carried status codes, and is kept for callers that treat a missing
+    /// resource as empty data.
+    #[builder(default)]
+    lenient_client_errors: bool,
+    /// How failed requests are retried. Defaults to a single attempt.
+    #[builder(default = "RetryPolicy::none()")]
+    retry: RetryPolicy,
 }
 
+/// Errors returned by `MLBApi`. Every variant keeps the url that failed, and HTTP errors keep a
+/// snippet of the response body since the API usually explains what was wrong with the request.
 #[derive(Debug)]
 pub enum ApiError {
+    /// The request could not be sent or the connection dropped.
     Network(reqwest::Error, String),
-    API(reqwest::Error, String),
-    Parsing(reqwest::Error, String),
+    /// The request didn't complete within the configured timeout.
+    Timeout { url: String },
+    /// 400, e.g. a season that doesn't exist.
+    BadRequest { url: String, body: String },
+    /// 404, e.g. an unknown game_pk.
+    NotFound { url: String, body: String },
+    /// 429, the API is throttling requests. `retry_after` is taken from the `Retry-After` header.
+    RateLimited {
+        url: String,
+        body: String,
+        retry_after: Option<Duration>,
+    },
+    /// Any other 4xx.
+    ClientError {
+        status: u16,
+        url: String,
+        body: String,
+    },
+    /// 5xx. A 503 may include a `Retry-After` header.
+    ServerError {
+        status: u16,
+        url: String,
+        body: String,
+        retry_after: Option<Duration>,
+    },
+    /// A transport other than HTTP failed, e.g. a replayed response file couldn't be read.
+    Transport { url: String, message: String },
+    /// The response was not the expected JSON. `path` is the serde path to the field that failed,
+    /// e.g. `dates[0].games[3].status.abstractGameState`.
+    Decode {
+        url: String,
+        path: String,
+        message: String,
+    },
 }
 
 impl ApiError {
     pub fn log(&self) -> String {
         match self {
             ApiError::Network(e, url) => format!("Network error for {url}: {e:?}"),
-            ApiError::API(e, url) => format!("API error for {url}: {e:?}"),
-            ApiError::Parsing(e, url) => format!("Parsing error for {url}: {e:?}"),
+            ApiError::Timeout { url } => format!("Timed out waiting for {url}"),
+            ApiError::BadRequest { url, body } => format!("Bad request (400) for {url}: {body}"),
+            ApiError::NotFound { url, body } => format!("Not found (404) for {url}: {body}"),
+            ApiError::RateLimited { url, body, .. } => {
+                format!("Rate limited (429) for {url}: {body}")
+            }
+            ApiError::ClientError { status, url, body } => {
+                format!("Client error ({status}) for {url}: {body}")
+            }
+            ApiError::ServerError {
+                status, url, body, ..
+            } => {
+                format!("Server error ({status}) for {url}: {body}")
+            }
+            ApiError::Transport { url, message } => format!("Transport error for {url}: {message}"),
+            ApiError::Decode { url, path, message } => {
+                format!("Decode error for {url} at `{path}`: {message}")
+            }
+        }
+    }
+
+    /// A few words on what went wrong for a status line, e.g. "timeout" or "server error 503".
+    pub fn summary(&self) -> String {
+        match self {
+            ApiError::Network(..) => "network error".to_string(),
+            ApiError::Timeout { .. } => "timeout".to_string(),
+            ApiError::BadRequest { .. } => "bad request".to_string(),
+            ApiError::NotFound { .. } => "not found".to_string(),
+            ApiError::RateLimited { .. } => "rate limited".to_string(),
+            ApiError::ClientError { status, .. } => format!("error {status}"),
+            ApiError::ServerError { status, .. } => format!("server error {status}"),
+            ApiError::Transport { message, .. } => message.clone(),
+            ApiError::Decode { .. } => "unexpected response".to_string(),
+        }
+    }
+
+    /// The HTTP status code, if the API responded with an error status.
+    pub fn status(&self) -> Option<u16> {
+        match self {
+            ApiError::BadRequest { .. } => Some(400),
+            ApiError::NotFound { .. } => Some(404),
+            ApiError::RateLimited { .. } => Some(429),
+            ApiError::ClientError { status, .. } | ApiError::ServerError { status, .. } => {
+                Some(*status)
+            }
+            ApiError::Network(..)
+            | ApiError::Timeout { .. }
+            | ApiError::Transport { .. }
+            | ApiError::Decode { .. } => None,
+        }
+    }
+
+    /// True if the API couldn't be reached at all, as opposed to answering with an error.
+    pub fn is_connection_failure(&self) -> bool {
+        matches!(
+            self,
+            ApiError::Network(..) | ApiError::Timeout { .. } | ApiError::Transport { .. }
+        )
+    }
+
+    /// The url of the request that failed.
+    pub fn url(&self) -> &str {
+        match self {
+            ApiError::Network(_, url)
+            | ApiError::Timeout { url }
+            | ApiError::BadRequest { url, .. }
+            | ApiError::NotFound { url, .. }
+            | ApiError::RateLimited { url, .. }
+            | ApiError::ClientError { url, .. }
+            | ApiError::ServerError { url, .. }
+            | ApiError::Transport { url, .. }
+            | ApiError::Decode { url, .. } => url,
+        }
+    }
+
+    /// How long the server asked us to wait before retrying, from the `Retry-After` header.
+    pub fn retry_after(&self) -> Option<Duration> {
+        match self {
+            ApiError::RateLimited { retry_after, .. }
+            | ApiError::ServerError { retry_after, .. } => *retry_after,
+            _ => None,
+        }
+    }
+
+    pub(crate) fn from_reqwest(err: reqwest::Error, url: String) -> Self {
+        if err.is_timeout() {
+            ApiError::Timeout { url }
+        } else {
+            ApiError::Network(err, url)
+        }
+    }
+
+    fn from_status(
+        status: StatusCode,
+        url: String,
+        body: &str,
+        retry_after: Option<Duration>,
+    ) -> Self {
+        let body = body.chars().take(BODY_SNIPPET_LEN).collect();
+        match status {
+            StatusCode::BAD_REQUEST => ApiError::BadRequest { url, body },
+            StatusCode::NOT_FOUND => ApiError::NotFound { url, body },
+            StatusCode::TOO_MANY_REQUESTS => ApiError::RateLimited {
+                url,
+                body,
+                retry_after,
+            },
+            s if s.is_client_error() => ApiError::ClientError {
+                status: s.as_u16(),
+                url,
+                body,
+            },
+            s => ApiError::ServerError {
+                status: s.as_u16(),
+                url,
+                body,
+                retry_after,
+            },
         }
     }
 }
 
 /// The available stat groups. These are taken from the "meta" endpoint:
 /// https://statsapi.mlb.com/api/v1/statGroups
-/// I only need to use Hitting and Pitching for now.
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 pub enum StatGroup {
     Hitting,
     Pitching,
-    // Fielding,
-    // Catching,
-    // Running,
+    Fielding,
+    Catching,
+    Running,
     // Game,
     // Team,
     // Streak,
@@ -69,6 +238,9 @@ impl fmt::Display for StatGroup {
         match self {
             StatGroup::Hitting => write!(f, "hitting"),
             StatGroup::Pitching => write!(f, "pitching"),
+            StatGroup::Fielding => write!(f, "fielding"),
+            StatGroup::Catching => write!(f, "catching"),
+            StatGroup::Running => write!(f, "running"),
         }
     }
 }
@@ -79,6 +251,17 @@ impl StatGroup {
         match self {
             StatGroup::Hitting => "plateAppearances",
             StatGroup::Pitching => "inningsPitched",
+            StatGroup::Fielding => "innings",
+            StatGroup::Catching | StatGroup::Running => "gamesPlayed",
+        }
+    }
+
+    /// Extra stat types requested alongside player leaderboards. These are always season totals,
+    /// even when the main type is `byDateRange`, and are only available for hitting and pitching.
+    pub fn advanced_stat_types(&self) -> &'static str {
+        match self {
+            StatGroup::Hitting | StatGroup::Pitching => ",sabermetrics,expectedStatistics",
+            _ => "",
         }
     }
 }
@@ -105,16 +288,26 @@ impl fmt::Display for Qualification {
 impl MLBApi {
     pub async fn get_todays_schedule(&self) -> ApiResult<ScheduleResponse> {
         let url = format!(
-            "{}v1/schedule?sportId=1,51&hydrate=linescore,probablePitcher,stats,decisions",
+            "{}v1/schedule?sportId=1,51&hydrate=linescore,probablePitcher,stats,decisions,broadcasts(all)",
             self.base_url
         );
         self.get(url).await
     }
 
-    pub async fn get_schedule_date(&self, date: NaiveDate) -> ApiResult<ScheduleResponse> {
+    /// The MLB schedule also includes international games, e.g. the World Baseball Classic.
+    pub async fn get_schedule_date(
+        &self,
+        date: NaiveDate,
+        sport: SportId,
+    ) -> ApiResult<ScheduleResponse> {
+        let sport_ids = match sport {
+            SportId::Mlb => format!("{},{}", SportId::Mlb, SportId::International),
+            _ => sport.to_string(),
+        };
         let url = format!(
-            "{}v1/schedule?sportId=1,51&hydrate=linescore,probablePitcher,stats,decisions&date={}",
+            "{}v1/schedule?sportId={}&hydrate=linescore,probablePitcher,stats,decisions,broadcasts(all)&date={}",
             self.base_url,
+            sport_ids,
             date.format("%Y-%m-%d")
         );
         self.get(url).await
@@ -131,6 +324,76 @@ impl MLBApi {
         self.get(url).await
     }
 
+    /// Like `get_live_data`, but keeps the JSON document so the feed can be updated with
+    /// `get_live_diff`.
+    pub async fn get_live_feed(&self, game_id: u64) -> ApiResult<LiveFeed> {
+        if game_id == 0 {
+            return Ok(LiveFeed::default());
+        }
+        let url = format!(
+            "{}v1.1/game/{}/feed/live?language=en",
+            self.base_url, game_id
+        );
+        let document: serde_json::Value = self.get(url.clone()).await?;
+        live_feed(document, url)
+    }
+
+    /// Fetch the changes to a game's live feed since `start_timecode`, normally
+    /// `cached.time_stamp()`, and apply them to a copy of `cached`. When the API can't produce a
+    /// diff it sends the whole feed instead, which is used as is. A patch that doesn't apply
+    /// returns `ApiError::Decode` and the caller should fall back to `get_live_feed`.
+    pub async fn get_live_diff(
+        &self,
+        game_pk: u64,
+        start_timecode: &str,
+        cached: &LiveFeed,
+    ) -> ApiResult<LiveFeed> {
+        let url = format!(
+            "{}v1.1/game/{}/feed/live/diffPatch?language=en&startTimecode={}",
+            self.base_url, game_pk, start_timecode
+        );
+        let diff: serde_json::Value = self.get(url.clone()).await?;
+        let diffs: Vec<LiveDiff> = match diff {
+            serde_json::Value::Array(_) => decode_value(&diff, url.clone())?,
+            document => return live_feed(document, url),
+        };
+        if diffs.is_empty() {
+            return Ok(cached.clone());
+        }
+
+        let mut document = cached.document.clone();
+        for LiveDiff { diff } in diffs {
+            json_patch::patch(&mut document, &diff).map_err(|err| ApiError::Decode {
+                url: url.clone(),
+                path: err.path.to_string(),
+                message: err.to_string(),
+            })?;
+        }
+        live_feed(document, url)
+    }
+
+    /// Only the plays of a game, which is much smaller than the live feed when the rest of the game
+    /// data isn't needed.
+    pub async fn get_play_by_play(&self, game_id: u64) -> ApiResult<Plays> {
+        if game_id == 0 {
+            return Ok(Plays::default());
+        }
+        let url = format!("{}v1/game/{}/playByPlay", self.base_url, game_id);
+        self.get(url).await
+    }
+
+    /// The preview, recap and wrap articles of a game, without the media and highlights.
+    pub async fn get_game_content(&self, game_id: u64) -> ApiResult<ContentResponse> {
+        if game_id == 0 {
+            return Ok(ContentResponse::default());
+        }
+        let url = format!(
+            "{}v1/game/{}/content?fields=editorial&fields=preview&fields=recap&fields=wrap&fields=articles&fields=items&fields=mlb&fields=home&fields=away&fields=headline&fields=subhead&fields=blurb&fields=body",
+            self.base_url, game_id
+        );
+        self.get(url).await
+    }
+
     pub async fn get_win_probability(&self, game_id: u64) -> ApiResult<WinProbabilityResponse> {
         if game_id == 0 {
             return Ok(WinProbabilityResponse::default());
@@ -148,41 +411,69 @@ impl MLBApi {
         Ok(resp.seasons.into_iter().next())
     }
 
+    /// Standings don't exist for the postseason, so those dates get the final regular season
+    /// standings. See `get_postseason_series` for the bracket.
+    ///
+    /// Minor league standings also hydrate the division, since only the MLB division names are
+    /// known ahead of time.
     pub async fn get_standings(
         &self,
         date: NaiveDate,
         game_type: GameType,
+        sport: SportId,
     ) -> ApiResult<StandingsResponse> {
+        let hydrate = if sport.is_minor_league() {
+            "team,division"
+        } else {
+            "team"
+        };
         let url = match game_type {
-            GameType::SpringTraining => format!(
-                "{}v1/standings?sportId=1&season={}&standingsType=springTraining&leagueId=103,104&hydrate=team",
+            // exhibitions between spring training and opening day keep the final spring standings
+            GameType::SpringTraining | GameType::Exhibition if !sport.is_minor_league() => format!(
+                "{}v1/standings?sportId={}&season={}&standingsType=springTraining&leagueId={}&hydrate={}",
                 self.base_url,
+                sport,
                 date.year(),
+                sport.league_ids(),
+                hydrate,
             ),
-            GameType::RegularSeason => format!(
-                "{}v1/standings?sportId=1&season={}&date={}&leagueId=103,104&hydrate=team",
+            _ => format!(
+                "{}v1/standings?sportId={}&season={}&date={}&leagueId={}&hydrate={}",
                 self.base_url,
+                sport,
                 date.year(),
                 date.format("%Y-%m-%d"),
+                sport.league_ids(),
+                hydrate,
             ),
         };
         self.get(url).await
     }
 
+    pub async fn get_postseason_series(&self, season: i32) -> ApiResult<PostseasonSeriesResponse> {
+        let url = format!(
+            "{}v1/schedule/postseason/series?sportId=1&season={}",
+            self.base_url, season
+        );
+        self.get(url).await
+    }
+
     pub async fn get_team_stats(
         &self,
         group: StatGroup,
         game_type: GameType,
+        sport: SportId,
     ) -> ApiResult<StatsResponse> {
         let local: DateTime<Local> = Local::now();
         let mut url = format!(
-            "{}v1/teams/stats?sportId=1&stats=season&season={}&group={}",
+            "{}v1/teams/stats?sportId={}&stats=season&season={}&group={}",
             self.base_url,
+            sport,
             local.year(),
             group
         );
-        if game_type == GameType::SpringTraining {
-            url.push_str("&gameType=S");
+        if let Some(game_type) = game_type.stats_param() {
+            url.push_str(&format!("&gameType={game_type}"));
         }
         self.get(url).await
     }
@@ -192,16 +483,18 @@ impl MLBApi {
         group: StatGroup,
         date: NaiveDate,
         game_type: GameType,
+        sport: SportId,
     ) -> ApiResult<StatsResponse> {
         let mut url = format!(
-            "{}v1/teams/stats?sportId=1&stats=byDateRange&season={}&endDate={}&group={}",
+            "{}v1/teams/stats?sportId={}&stats=byDateRange&season={}&endDate={}&group={}",
             self.base_url,
+            sport,
             date.year(),
             date.format("%Y-%m-%d"),
             group
         );
-        if game_type == GameType::SpringTraining {
-            url.push_str("&gameType=S");
+        if let Some(game_type) = game_type.stats_param() {
+            url.push_str(&format!("&gameType={game_type}"));
         }
         self.get(url).await
     }
@@ -211,19 +504,23 @@ impl MLBApi {
         group: StatGroup,
         qualification: Qualification,
         game_type: GameType,
+        sport: SportId,
     ) -> ApiResult<StatsResponse> {
         let local: DateTime<Local> = Local::now();
         let sort = group.default_sort_stat();
+        let advanced = group.advanced_stat_types();
         let mut url = format!(
-            "{}v1/stats?sportId=1&stats=season&season={}&group={}&limit=3000&sortStat={}&order=desc&playerPool={}",
+            "{}v1/stats?sportId={}&stats=season{}&season={}&group={}&limit=3000&sortStat={}&order=desc&playerPool={}",
             self.base_url,
+            sport,
+            advanced,
             local.year(),
             group,
             sort,
             qualification,
         );
-        if game_type == GameType::SpringTraining {
-            url.push_str("&gameType=S");
+        if let Some(game_type) = game_type.stats_param() {
+            url.push_str(&format!("&gameType={game_type}"));
         }
         self.get(url).await
     }
@@ -234,19 +531,24 @@ impl MLBApi {
         qualification: Qualification,
         date: NaiveDate,
         game_type: GameType,
+        sport: SportId,
     ) -> ApiResult<StatsResponse> {
         let sort = group.default_sort_stat();
-        let url = match game_type {
-            // Spring training doesn't work well with byDateRange, use season instead.
-            GameType::SpringTraining => format!(
-                "{}v1/stats?sportId=1&stats=season&season={}&group={}&limit=3000&sortStat={}&order=desc&gameType=S&playerPool={}",
+        let advanced = group.advanced_stat_types();
+        let url = match game_type.stats_param() {
+            // Other game types don't work well with byDateRange, use season instead.
+            Some(game_type) => format!(
+                "{}v1/stats?sportId={}&stats=season{}&season={}&group={}&limit=3000&sortStat={}&order=desc&gameType={}&playerPool={}",
                 self.base_url,
+                sport,
+                advanced,
                 date.year(),
                 group,
                 sort,
+                game_type,
                 qualification,
             ),
-            GameType::RegularSeason => {
+            None => {
                 let current_year = Local::now().year();
                 // Past seasons use season stats because byDateRange is much slower on a cold cache.
                 // Qualified must also use season stats because the API ignores `playerPool` on
@@ -255,8 +557,10 @@ impl MLBApi {
                     date.year() < current_year || qualification == Qualification::Qualified;
                 if use_season {
                     format!(
-                        "{}v1/stats?sportId=1&stats=season&season={}&group={}&limit=3000&sortStat={}&order=desc&playerPool={}",
+                        "{}v1/stats?sportId={}&stats=season{}&season={}&group={}&limit=3000&sortStat={}&order=desc&playerPool={}",
                         self.base_url,
+                        sport,
+                        advanced,
                         date.year(),
                         group,
                         sort,
@@ -264,8 +568,10 @@ impl MLBApi {
                     )
                 } else {
                     format!(
-                        "{}v1/stats?sportId=1&stats=byDateRange&season={}&endDate={}&group={}&limit=3000&sortStat={}&order=desc&playerPool={}",
+                        "{}v1/stats?sportId={}&stats=byDateRange{}&season={}&endDate={}&group={}&limit=3000&sortStat={}&order=desc&playerPool={}",
                         self.base_url,
+                        sport,
+                        advanced,
                         date.year(),
                         date.format("%Y-%m-%d"),
                         group,
@@ -278,7 +584,72 @@ impl MLBApi {
         self.get(url).await
     }
 
-    /// Fetch player bio and all stats in a single hydrated call.
+    /// The top `limit` players in each category, ranked by the API. This is much smaller than
+    /// sorting the full `get_player_stats` response when only the leaders are needed.
+    pub async fn get_league_leaders(
+        &self,
+        categories: &[LeaderCategory],
+        season: i32,
+        game_type: GameType,
+        sport: SportId,
+        league: Option<League>,
+        limit: u8,
+    ) -> ApiResult<LeagueLeadersResponse> {
+        let names: Vec<_> = categories.iter().map(|c| c.api_name()).collect();
+        let mut groups: Vec<String> = Vec::new();
+        for group in categories.iter().map(|c| c.group().to_string()) {
+            if !groups.contains(&group) {
+                groups.push(group);
+            }
+        }
+        let mut url = format!(
+            "{}v1/stats/leaders?leaderCategories={}&statGroup={}&season={}&sportId={}&limit={}",
+            self.base_url,
+            names.join(","),
+            groups.join(","),
+            season,
+            sport,
+            limit,
+        );
+        if let Some(game_type) = game_type.stats_param() {
+            url.push_str(&format!("&gameType={game_type}"));
+        }
+        if let Some(league) = league {
+            url.push_str(&format!("&leagueId={league}"));
+        }
+        self.get(url).await
+    }
+
+    /// Search every player by name, including retired and minor league players unless
+    /// `active_only` is set.
+    pub async fn search_people(&self, query: &str, active_only: bool) -> ApiResult<PeopleResponse> {
+        let mut url = format!(
+            "{}v1/people/search?names={}&hydrate=currentTeam",
+            self.base_url,
+            encode_query_value(query.trim()),
+        );
+        if active_only {
+            url.push_str("&active=true");
+        }
+        self.get(url).await
+    }
+
+    /// Fetch a batter's career and season totals against a pitcher.
+    pub async fn get_vs_player(
+        &self,
+        batter_id: u64,
+        pitcher_id: u64,
+        season: i32,
+    ) -> ApiResult<VsPlayerResponse> {
+        let url = format!(
+            "{}v1/people/{}/stats?stats=vsPlayer&group=hitting&opposingPlayerId={}&season={}&sportId=1",
+            self.base_url, batter_id, pitcher_id, season
+        );
+        self.get(url).await
+    }
+
+    /// Fetch player bio and all stats in a single hydrated call. Pitchers also get their pitch
+    /// arsenal for the season.
     pub async fn get_player_profile(
         &self,
         person_id: u64,
@@ -286,13 +657,18 @@ impl MLBApi {
         season: i32,
         game_type: GameType,
     ) -> ApiResult<PeopleResponse> {
-        let game_type_param = match game_type {
-            GameType::SpringTraining => ",gameType=S",
-            GameType::RegularSeason => "",
+        let game_type_param = match game_type.stats_param() {
+            None => String::new(),
+            Some(code) if code.contains(',') => format!(",gameType=[{code}]"),
+            Some(code) => format!(",gameType={code}"),
+        };
+        let arsenal = match group {
+            StatGroup::Pitching => ",pitchArsenal",
+            _ => "",
         };
         let url = format!(
-            "{}v1/people/{}?hydrate=currentTeam,draft,stats(group=[{}],type=[season,yearByYear,career,gameLog],season={}{})",
-            self.base_url, person_id, group, season, game_type_param
+            "{}v1/people/{}?hydrate=currentTeam,draft,stats(group=[{}],type=[season,yearByYear,career,gameLog{}],season={}{})",
+            self.base_url, person_id, group, arsenal, season, game_type_param
         );
         self.get(url).await
     }
@@ -338,6 +714,12 @@ impl MLBApi {
         self.get(url).await
     }
 
+    /// Every pick of the amateur draft held in `year`.
+    pub async fn get_draft(&self, year: i32) -> ApiResult<DraftResponse> {
+        let url = format!("{}v1/draft/{}", self.base_url, year);
+        self.get(url).await
+    }
+
     pub async fn get_teams(&self, sport_ids: &[SportId]) -> ApiResult<TeamsResponse> {
         let ids: Vec<String> = sport_ids.iter().map(|id| id.to_string()).collect();
         let url = format!(
@@ -349,35 +731,102 @@ impl MLBApi {
     }
 
     async fn get<T: Default + DeserializeOwned>(&self, url: String) -> ApiResult<T> {
-        let response = self
-            .client
-            .get(&url)
-            .timeout(self.timeout)
-            .send()
-            .await
-            .map_err(|err| ApiError::Network(err, url.clone()))?;
-
-        let status = response.status();
-        match response.error_for_status() {
-            Ok(res) => res
-                .json::<T>()
-                .await
-                .map_err(|err| ApiError::Parsing(err, url.clone())),
-            // 400-5xx returns errors
-            Err(err) => {
-                if status.is_client_error() {
-                    // just swallow 4xx responses
-                    Ok(T::default())
-                } else {
-                    Err(ApiError::API(err, url.clone()))
+        let mut attempt = 1;
+        loop {
+            let err = match self.get_once(&url).await {
+                Ok(data) => return Ok(data),
+                Err(err) => err,
+            };
+            match self.retry.next_delay(attempt, &err) {
+                Some(delay) => {
+                    attempt += 1;
+                    tokio::time::sleep(delay).await;
+                }
+                None if self.lenient_client_errors
+                    && err.status().is_some_and(|s| (400..500).contains(&s)) =>
+                {
+                    return Ok(T::default());
                 }
+                None => return Err(err),
             }
         }
     }
+
+    async fn get_once<T: DeserializeOwned>(&self, url: &str) -> ApiResult<T> {
+        let response = self.transport.get(url, self.timeout).await?;
+
+        let status = response.status;
+        if status.is_client_error() || status.is_server_error() {
+            let retry_after = response.retry_after.as_deref().and_then(parse_retry_after);
+            // the body is only used for context, so invalid UTF-8 isn't worth reporting
+            let body = String::from_utf8_lossy(&response.body);
+            return Err(ApiError::from_status(
+                status,
+                url.to_string(),
+                &body,
+                retry_after,
+            ));
+        }
+
+        decode(&response.body, url.to_string())
+    }
+}
+
+/// Deserialize a response body, tracking the path to the field that failed.
+fn decode<T: DeserializeOwned>(bytes: &[u8], url: String) -> ApiResult<T> {
+    let deserializer = &mut serde_json::Deserializer::from_slice(bytes);
+    serde_path_to_error::deserialize(deserializer).map_err(|err| ApiError::Decode {
+        url,
+        path: err.path().to_string(),
+        message: err.into_inner().to_string(),
+    })
+}
+
+/// Deserialize an already parsed JSON document, tracking the path to the field that failed.
+fn decode_value<T: DeserializeOwned>(value: &serde_json::Value, url: String) -> ApiResult<T> {
+    serde_path_to_error::deserialize(value).map_err(|err| ApiError::Decode {
+        url,
+        path: err.path().to_string(),
+        message: err.into_inner().to_string(),
+    })
+}
+
+fn live_feed(document: serde_json::Value, url: String) -> ApiResult<LiveFeed> {
+    let response = decode_value(&document, url)?;
+    Ok(LiveFeed {
+        response: Arc::new(response),
+        document,
+    })
+}
+
+/// Percent-encode a user supplied query parameter value.
+fn encode_query_value(value: &str) -> String {
+    value
+        .bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                (b as char).to_string()
+            }
+            _ => format!("%{b:02X}"),
+        })
+        .collect()
 }
 
 #[test]
 fn test_stat_group_lowercase() {
     assert_eq!("hitting".to_string(), StatGroup::Hitting.to_string());
     assert_eq!("pitching".to_string(), StatGroup::Pitching.to_string());
+    assert_eq!("fielding".to_string(), StatGroup::Fielding.to_string());
+    assert_eq!("catching".to_string(), StatGroup::Catching.to_string());
+    assert_eq!("running".to_string(), StatGroup::Running.to_string());
+}
+
+#[test]
+fn test_encode_query_value() {
+    assert_eq!(encode_query_value("aaron judge"), "aaron%20judge");
+    assert_eq!(
+        encode_query_value("José Ramírez"),
+        "Jos%C3%A9%20Ram%C3%ADrez"
+    );
+    assert_eq!(encode_query_value("o'neil&x=1"), "o%27neil%26x%3D1");
 }
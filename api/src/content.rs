@@ -0,0 +1,82 @@
+use serde::Deserialize;
+
+/// The editorial text of a game from `v1/game/{pk}/content`. The endpoint also has media and
+/// highlights, which are filtered out of the request since only the articles are used.
+#[derive(Default, Debug, Deserialize)]
+pub struct ContentResponse {
+    #[serde(default)]
+    pub editorial: Editorial,
+}
+
+#[derive(Default, Debug, Deserialize)]
+#[serde(default)]
+pub struct Editorial {
+    /// Written before the game starts.
+    pub preview: Option<Preview>,
+    /// Written after the game ends.
+    pub recap: Option<Recap>,
+    /// A shorter summary that is sometimes published instead of a recap.
+    pub wrap: Option<Recap>,
+}
+
+#[derive(Default, Debug, Deserialize)]
+#[serde(default)]
+pub struct Preview {
+    pub articles: Option<Articles>,
+}
+
+#[derive(Default, Debug, Deserialize)]
+#[serde(default)]
+pub struct Articles {
+    pub items: Vec<Article>,
+}
+
+/// Recaps can be written for the league and for each team's site.
+#[derive(Default, Debug, Deserialize)]
+#[serde(default)]
+pub struct Recap {
+    pub mlb: Option<Article>,
+    pub home: Option<Article>,
+    pub away: Option<Article>,
+}
+
+#[derive(Default, Debug, Deserialize)]
+#[serde(default)]
+pub struct Article {
+    pub headline: String,
+    pub subhead: Option<String>,
+    pub blurb: Option<String>,
+    /// HTML formatted.
+    pub body: Option<String>,
+}
+
+impl Recap {
+    /// The league recap, falling back to the home and then the away team's.
+    pub fn article(&self) -> Option<&Article> {
+        [&self.mlb, &self.home, &self.away]
+            .into_iter()
+            .flatten()
+            .find(|article| !article.headline.is_empty())
+    }
+}
+
+impl ContentResponse {
+    pub fn preview(&self) -> Option<&Article> {
+        self.editorial
+            .preview
+            .as_ref()?
+            .articles
+            .as_ref()?
+            .items
+            .iter()
+            .find(|article| !article.headline.is_empty())
+    }
+
+    pub fn recap(&self) -> Option<&Article> {
+        self.editorial.recap.as_ref()?.article()
+    }
+
+    pub fn wrap(&self) -> Option<&Article> {
+        self.editorial.wrap.as_ref()?.article()
+    }
+}
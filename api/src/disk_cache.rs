@@ -0,0 +1,351 @@
+use crate::transport::{RawResponse, Transport, TransportFuture, file_name, replay_key};
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use reqwest::StatusCode;
+use serde::{Deserialize, Serialize};
+
+/// Name of the file in the cache directory that lists the cached responses.
+const INDEX_FILE: &str = "index.json";
+
+/// Bump when the layout of the cache changes or when responses stored by an older version
+/// shouldn't be used anymore. Caches written with a different version are cleared on open.
+pub const SCHEMA_VERSION: u32 = 1;
+
+/// Default size cap for the cached response bodies.
+pub const DEFAULT_MAX_BYTES: u64 = 256 * 1024 * 1024;
+
+/// Reads only mark their entry as used in memory. The index is written after this many reads, on
+/// every insert and when the cache is dropped.
+const FLUSH_AFTER_READS: u32 = 32;
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct Index {
+    version: u32,
+    /// Incremented on every read and write, used to find the least recently used entry.
+    clock: u64,
+    /// Keyed by the path and query of the url so the cache doesn't depend on the host.
+    entries: BTreeMap<String, IndexEntry>,
+    /// Reads since the index was last written.
+    #[serde(skip)]
+    unflushed_reads: u32,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct IndexEntry {
+    file: String,
+    size: u64,
+    last_used: u64,
+}
+
+impl Index {
+    fn new() -> Self {
+        Self {
+            version: SCHEMA_VERSION,
+            ..Self::default()
+        }
+    }
+
+    fn total_size(&self) -> u64 {
+        self.entries.values().map(|e| e.size).sum()
+    }
+
+    fn tick(&mut self) -> u64 {
+        self.clock += 1;
+        self.clock
+    }
+}
+
+/// Response bodies stored on disk so they survive restarts. Nothing expires, so only responses
+/// that won't change should be stored, e.g. final games or past seasons. When the bodies exceed
+/// `max_bytes` the least recently used ones are removed.
+#[derive(Debug)]
+pub struct DiskCache {
+    dir: PathBuf,
+    max_bytes: u64,
+    index: Mutex<Index>,
+}
+
+impl DiskCache {
+    /// Open the cache in `dir`, creating it if needed. A cache written with a different
+    /// `SCHEMA_VERSION`, or one with an unreadable index, is cleared.
+    pub fn open(dir: impl Into<PathBuf>, max_bytes: u64) -> io::Result<Self> {
+        let dir = dir.into();
+        let index = match read_index(&dir) {
+            Some(index) if index.version == SCHEMA_VERSION => index,
+            _ => {
+                if dir.exists() {
+                    fs::remove_dir_all(&dir)?;
+                }
+                Index::new()
+            }
+        };
+        fs::create_dir_all(&dir)?;
+        let cache = Self {
+            dir,
+            max_bytes,
+            index: Mutex::new(index),
+        };
+        {
+            let mut index = cache.lock();
+            // the cap may have been lowered since the cache was written
+            cache.evict(&mut index)?;
+            cache.write_index(&mut index)?;
+        }
+        Ok(cache)
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Total size of the stored bodies in bytes.
+    pub fn size(&self) -> u64 {
+        self.lock().total_size()
+    }
+
+    pub fn len(&self) -> usize {
+        self.lock().entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The stored body for `url`, marking it as recently used. An entry whose file is missing is
+    /// dropped from the index. Blocks on file I/O, see `DiskCacheTransport` for async use.
+    pub fn get(&self, url: &str) -> Option<Vec<u8>> {
+        let key = replay_key(url);
+        let file = self.lock().entries.get(&key)?.file.clone();
+        let body = fs::read(self.dir.join(&file)).ok();
+
+        let mut index = self.lock();
+        match &body {
+            Some(_) => {
+                let now = index.tick();
+                if let Some(entry) = index.entries.get_mut(&key) {
+                    entry.last_used = now;
+                }
+            }
+            None => {
+                index.entries.remove(&key);
+            }
+        }
+        index.unflushed_reads += 1;
+        if index.unflushed_reads >= FLUSH_AFTER_READS {
+            self.write_index(&mut index).ok();
+        }
+        body
+    }
+
+    /// Write the recently used marks of reads that haven't been written yet.
+    pub fn flush(&self) -> io::Result<()> {
+        let mut index = self.lock();
+        if index.unflushed_reads == 0 {
+            return Ok(());
+        }
+        self.write_index(&mut index)
+    }
+
+    /// Store the body for `url`, evicting the least recently used entries if the cache is over
+    /// its size cap. Bodies larger than the cap aren't stored.
+    pub fn insert(&self, url: &str, body: &[u8]) -> io::Result<()> {
+        let size = body.len() as u64;
+        if size > self.max_bytes {
+            return Ok(());
+        }
+        let file = file_name(url);
+        fs::write(self.dir.join(&file), body)?;
+
+        let mut index = self.lock();
+        let last_used = index.tick();
+        index.entries.insert(
+            replay_key(url),
+            IndexEntry {
+                file,
+                size,
+                last_used,
+            },
+        );
+        self.evict(&mut index)?;
+        self.write_index(&mut index)
+    }
+
+    fn evict(&self, index: &mut Index) -> io::Result<()> {
+        let mut total = index.total_size();
+        while total > self.max_bytes {
+            let Some(oldest) = index
+                .entries
+                .iter()
+                .min_by_key(|(_, e)| e.last_used)
+                .map(|(key, _)| key.clone())
+            else {
+                break;
+            };
+            if let Some(entry) = index.entries.remove(&oldest) {
+                remove_file(&self.dir.join(&entry.file))?;
+                total -= entry.size;
+            }
+        }
+        Ok(())
+    }
+
+    /// Write the index to a temporary file first so an interrupted write can't corrupt it.
+    fn write_index(&self, index: &mut Index) -> io::Result<()> {
+        let json = serde_json::to_string(index)?;
+        let tmp = self.dir.join(format!("{INDEX_FILE}.tmp"));
+        fs::write(&tmp, json)?;
+        fs::rename(tmp, self.dir.join(INDEX_FILE))?;
+        index.unflushed_reads = 0;
+        Ok(())
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Index> {
+        self.index.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl Drop for DiskCache {
+    fn drop(&mut self) {
+        let _ = self.flush();
+    }
+}
+
+fn read_index(dir: &Path) -> Option<Index> {
+    let json = fs::read_to_string(dir.join(INDEX_FILE)).ok()?;
+    serde_json::from_str(&json).ok()
+}
+
+/// Remove a file, ignoring files that are already gone.
+fn remove_file(path: &Path) -> io::Result<()> {
+    match fs::remove_file(path) {
+        Err(err) if err.kind() != io::ErrorKind::NotFound => Err(err),
+        _ => Ok(()),
+    }
+}
+
+/// Wraps another transport and serves responses from a `DiskCache` when it has them. Successful
+/// responses from the inner transport are stored, errors aren't. The file I/O runs on tokio's
+/// blocking threads so it doesn't stall other requests.
+#[derive(Debug)]
+pub struct DiskCacheTransport {
+    inner: Arc<dyn Transport>,
+    cache: Arc<DiskCache>,
+}
+
+impl DiskCacheTransport {
+    pub fn new(inner: Arc<dyn Transport>, cache: Arc<DiskCache>) -> Self {
+        Self { inner, cache }
+    }
+}
+
+impl Transport for DiskCacheTransport {
+    fn get<'a>(&'a self, url: &'a str, timeout: Duration) -> TransportFuture<'a> {
+        Box::pin(async move {
+            let cache = self.cache.clone();
+            let key = url.to_string();
+            let cached = tokio::task::spawn_blocking(move || cache.get(&key))
+                .await
+                .ok()
+                .flatten();
+            if let Some(body) = cached {
+                return Ok(RawResponse {
+                    status: StatusCode::OK,
+                    retry_after: None,
+                    body,
+                });
+            }
+            let response = self.inner.get(url, timeout).await?;
+            // a full or read only disk shouldn't fail the request, it just isn't cached
+            if response.status.is_success() {
+                let cache = self.cache.clone();
+                let key = url.to_string();
+                let body = response.body.clone();
+                let _ = tokio::task::spawn_blocking(move || cache.insert(&key, &body)).await;
+            }
+            Ok(response)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("mlbt-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn evicts_least_recently_used() {
+        let dir = temp_dir("disk-cache-lru");
+        let cache = DiskCache::open(&dir, 10).unwrap();
+        cache.insert("https://host/a", b"aaaa").unwrap();
+        cache.insert("https://host/b", b"bbbb").unwrap();
+        // reading a makes b the oldest
+        assert_eq!(cache.get("https://host/a").as_deref(), Some(&b"aaaa"[..]));
+        cache.insert("https://host/c", b"cccc").unwrap();
+
+        assert!(cache.get("https://host/b").is_none());
+        assert!(cache.get("https://host/a").is_some());
+        assert!(cache.get("https://host/c").is_some());
+        assert_eq!(cache.size(), 8);
+
+        // too big to ever fit
+        cache.insert("https://host/d", &[0; 11]).unwrap();
+        assert!(cache.get("https://host/d").is_none());
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn reads_are_written_in_batches() {
+        let dir = temp_dir("disk-cache-flush");
+        let cache = DiskCache::open(&dir, DEFAULT_MAX_BYTES).unwrap();
+        cache.insert("https://host/a", b"aaaa").unwrap();
+        let last_used = || read_index(&dir).unwrap().entries["/a"].last_used;
+        let inserted = last_used();
+
+        cache.get("https://host/a").unwrap();
+        assert_eq!(last_used(), inserted, "a read doesn't write the index");
+        for _ in 1..FLUSH_AFTER_READS {
+            cache.get("https://host/a").unwrap();
+        }
+        assert!(last_used() > inserted);
+
+        cache.get("https://host/a").unwrap();
+        let flushed = last_used();
+        drop(cache);
+        assert!(
+            last_used() > flushed,
+            "dropping the cache writes pending reads"
+        );
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn reopening_keeps_entries_of_the_same_version() {
+        let dir = temp_dir("disk-cache-version");
+        let cache = DiskCache::open(&dir, DEFAULT_MAX_BYTES).unwrap();
+        cache.insert("https://host/a?x=1", b"{}").unwrap();
+        drop(cache);
+
+        // the host isn't part of the key
+        let cache = DiskCache::open(&dir, DEFAULT_MAX_BYTES).unwrap();
+        assert_eq!(cache.get("http://other/a?x=1").as_deref(), Some(&b"{}"[..]));
+        drop(cache);
+
+        let mut index = read_index(&dir).unwrap();
+        index.version = SCHEMA_VERSION + 1;
+        fs::write(dir.join(INDEX_FILE), serde_json::to_string(&index).unwrap()).unwrap();
+        let cache = DiskCache::open(&dir, DEFAULT_MAX_BYTES).unwrap();
+        assert!(cache.is_empty());
+        assert_eq!(fs::read_dir(&dir).unwrap().count(), 1, "only the new index");
+        let _ = fs::remove_dir_all(&dir);
+    }
+}
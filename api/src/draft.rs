@@ -0,0 +1,60 @@
+use crate::live::PrimaryPosition;
+use crate::schedule::IdNameLink;
+use serde::Deserialize;
+
+/// Every pick of a Rule 4 amateur draft from `v1/draft/{year}`.
+#[derive(Default, Debug, Deserialize)]
+pub struct DraftResponse {
+    #[serde(default)]
+    pub drafts: Draft,
+}
+
+#[derive(Default, Debug, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Draft {
+    pub draft_year: u16,
+    pub rounds: Vec<DraftRound>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct DraftRound {
+    /// Competitive balance and supplemental rounds aren't numbers, e.g. "C-1".
+    pub round: String,
+    #[serde(default)]
+    pub picks: Vec<DraftPick>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DraftPick {
+    pub pick_round: String,
+    /// Overall pick number.
+    pub pick_number: u16,
+    pub round_pick_number: u16,
+    /// Missing for picks that haven't been made yet.
+    pub person: Option<DraftPerson>,
+    pub team: IdNameLink,
+    pub school: Option<School>,
+    pub signing_bonus: Option<String>,
+    pub pick_value: Option<String>,
+    #[serde(default)]
+    pub is_drafted: bool,
+    #[serde(default)]
+    pub is_pass: bool,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DraftPerson {
+    pub id: u64,
+    pub full_name: String,
+    pub primary_position: Option<PrimaryPosition>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct School {
+    pub name: String,
+    /// e.g. "4YR JR" or "HS SR".
+    pub school_class: Option<String>,
+}
@@ -0,0 +1,139 @@
+use crate::client::StatGroup;
+use crate::schedule::IdNameLink;
+use crate::stats::Player;
+use serde::Deserialize;
+use std::fmt;
+
+/// A category from `v1/stats/leaders`. Some names are used by more than one stat group, e.g.
+/// `strikeouts` ranks both hitters and pitchers, so each category is tied to a group.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum LeaderCategory {
+    HomeRuns,
+    BattingAverage,
+    RunsBattedIn,
+    Hits,
+    StolenBases,
+    OnBasePlusSlugging,
+    EarnedRunAverage,
+    Strikeouts,
+    Wins,
+    Saves,
+    Whip,
+}
+
+impl LeaderCategory {
+    pub const ALL: [LeaderCategory; 11] = [
+        LeaderCategory::HomeRuns,
+        LeaderCategory::BattingAverage,
+        LeaderCategory::RunsBattedIn,
+        LeaderCategory::Hits,
+        LeaderCategory::StolenBases,
+        LeaderCategory::OnBasePlusSlugging,
+        LeaderCategory::EarnedRunAverage,
+        LeaderCategory::Strikeouts,
+        LeaderCategory::Wins,
+        LeaderCategory::Saves,
+        LeaderCategory::Whip,
+    ];
+
+    /// The name used in the `leaderCategories` parameter and returned as `leaderCategory`.
+    pub fn api_name(&self) -> &'static str {
+        match self {
+            LeaderCategory::HomeRuns => "homeRuns",
+            LeaderCategory::BattingAverage => "battingAverage",
+            LeaderCategory::RunsBattedIn => "runsBattedIn",
+            LeaderCategory::Hits => "hits",
+            LeaderCategory::StolenBases => "stolenBases",
+            LeaderCategory::OnBasePlusSlugging => "onBasePlusSlugging",
+            LeaderCategory::EarnedRunAverage => "earnedRunAverage",
+            LeaderCategory::Strikeouts => "strikeouts",
+            LeaderCategory::Wins => "wins",
+            LeaderCategory::Saves => "saves",
+            LeaderCategory::Whip => "walksAndHitsPerInningPitched",
+        }
+    }
+
+    pub fn group(&self) -> StatGroup {
+        match self {
+            LeaderCategory::HomeRuns
+            | LeaderCategory::BattingAverage
+            | LeaderCategory::RunsBattedIn
+            | LeaderCategory::Hits
+            | LeaderCategory::StolenBases
+            | LeaderCategory::OnBasePlusSlugging => StatGroup::Hitting,
+            LeaderCategory::EarnedRunAverage
+            | LeaderCategory::Strikeouts
+            | LeaderCategory::Wins
+            | LeaderCategory::Saves
+            | LeaderCategory::Whip => StatGroup::Pitching,
+        }
+    }
+
+    /// Short name used in tables, e.g. "HR".
+    pub fn abbreviation(&self) -> &'static str {
+        match self {
+            LeaderCategory::HomeRuns => "HR",
+            LeaderCategory::BattingAverage => "AVG",
+            LeaderCategory::RunsBattedIn => "RBI",
+            LeaderCategory::Hits => "H",
+            LeaderCategory::StolenBases => "SB",
+            LeaderCategory::OnBasePlusSlugging => "OPS",
+            LeaderCategory::EarnedRunAverage => "ERA",
+            LeaderCategory::Strikeouts => "K",
+            LeaderCategory::Wins => "W",
+            LeaderCategory::Saves => "SV",
+            LeaderCategory::Whip => "WHIP",
+        }
+    }
+}
+
+/// Limits leaders to a single league.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum League {
+    American = 103,
+    National = 104,
+}
+
+impl fmt::Display for League {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", *self as u16)
+    }
+}
+
+#[derive(Default, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LeagueLeadersResponse {
+    #[serde(default)]
+    pub league_leaders: Vec<CategoryLeaders>,
+}
+
+impl LeagueLeadersResponse {
+    /// The leaders for a category in its stat group.
+    pub fn category(&self, category: LeaderCategory) -> Option<&CategoryLeaders> {
+        let group = category.group().to_string();
+        self.league_leaders.iter().find(|l| {
+            l.leader_category == category.api_name() && l.stat_group.as_deref() == Some(&group)
+        })
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CategoryLeaders {
+    pub leader_category: String,
+    pub season: Option<String>,
+    pub stat_group: Option<String>,
+    #[serde(default)]
+    pub leaders: Vec<Leader>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Leader {
+    /// Tied players share a rank.
+    pub rank: u16,
+    /// Formatted by the API, e.g. ".332" or "58".
+    pub value: String,
+    pub team: Option<IdNameLink>,
+    pub person: Player,
+}
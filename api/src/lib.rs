@@ -1,8 +1,13 @@
 pub mod boxscore;
 pub mod client;
+pub mod content;
+pub mod disk_cache;
+pub mod draft;
+pub mod leaders;
 pub mod live;
 pub mod player;
 pub mod plays;
+pub mod retry;
 pub mod schedule;
 pub mod season;
 pub mod serde_dates;
@@ -10,4 +15,6 @@ pub mod standings;
 pub mod stats;
 pub mod team;
 pub mod teams;
+pub mod transport;
+pub mod vs_player;
 pub mod win_probability;
@@ -1,9 +1,10 @@
 use crate::boxscore::Boxscore;
 use crate::plays::Plays;
 use crate::schedule::Status;
-use chrono::NaiveDate;
+use chrono::{DateTime, NaiveDate, Utc};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::sync::Arc;
 
 #[derive(Default, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -15,6 +16,27 @@ pub struct LiveResponse {
     pub live_data: LiveData,
 }
 
+/// A `LiveResponse` along with the JSON document it was decoded from. The diffPatch endpoint sends
+/// JSON patches against the full document, so it has to be kept around to apply them.
+#[derive(Default, Debug, Clone)]
+pub struct LiveFeed {
+    pub response: Arc<LiveResponse>,
+    pub document: serde_json::Value,
+}
+
+impl LiveFeed {
+    /// The feed's `metaData.timeStamp`, which is the `start_timecode` for the next diff.
+    pub fn time_stamp(&self) -> &str {
+        &self.response.meta_data.time_stamp
+    }
+}
+
+/// One entry in a diffPatch response.
+#[derive(Debug, Deserialize)]
+pub(crate) struct LiveDiff {
+    pub diff: json_patch::Patch,
+}
+
 #[derive(Default, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct MetaData {
@@ -32,6 +54,57 @@ pub struct GameData {
     pub players: HashMap<String, FullPlayer>,
     pub abs_challenges: Option<AbsChallenges>,
     pub status: Status,
+    pub weather: Option<Weather>,
+    pub venue: Option<Venue>,
+    pub game_info: Option<GameInfo>,
+}
+
+/// Conditions at first pitch. The values are preformatted, e.g. a temp of "59" and a wind of
+/// "11 mph, In From RF".
+#[derive(Default, Debug, Serialize, Deserialize)]
+pub struct Weather {
+    pub condition: Option<String>,
+    pub temp: Option<String>,
+    pub wind: Option<String>,
+}
+
+#[derive(Default, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Venue {
+    pub id: u32,
+    pub name: String,
+    pub location: Option<VenueLocation>,
+    pub field_info: Option<FieldInfo>,
+}
+
+#[derive(Default, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VenueLocation {
+    pub city: Option<String>,
+    pub state_abbrev: Option<String>,
+}
+
+/// Fence distances are in feet.
+#[derive(Default, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FieldInfo {
+    pub capacity: Option<u32>,
+    pub turf_type: Option<String>,
+    pub roof_type: Option<String>,
+    pub left_line: Option<u16>,
+    pub left_center: Option<u16>,
+    pub center: Option<u16>,
+    pub right_center: Option<u16>,
+    pub right_line: Option<u16>,
+}
+
+/// Only filled in once the game has started.
+#[derive(Default, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GameInfo {
+    pub attendance: Option<u32>,
+    pub first_pitch: Option<DateTime<Utc>>,
+    pub game_duration_minutes: Option<u16>,
 }
 
 #[derive(Default, Debug, Serialize, Deserialize)]
@@ -167,7 +240,7 @@ pub struct PrimaryPosition {
     pub abbreviation: String,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct FullPlayer {
     pub id: u64,
@@ -10,7 +10,7 @@ pub struct PeopleResponse {
 }
 
 /// Full player info with hydrated currentTeam and inline stats.
-#[derive(Debug, Deserialize)]
+#[derive(Default, Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct PersonFull {
     pub id: u64,
@@ -1,7 +1,6 @@
 use crate::live::Person;
 
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
 
 #[derive(Default, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -142,6 +141,7 @@ impl CodeDescription {
     }
 }
 
+/// Pitch tracking data. Distances are in feet unless noted otherwise.
 #[derive(Default, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct PitchData {
@@ -149,10 +149,66 @@ pub struct PitchData {
     pub end_speed: Option<f64>,
     pub strike_zone_top: Option<f64>,
     pub strike_zone_bottom: Option<f64>,
-    pub coordinates: HashMap<String, f64>,
+    #[serde(default)]
+    pub coordinates: PitchCoordinates,
     pub breaks: Option<Breaks>,
     pub zone: Option<u8>,
     pub plate_time: Option<f64>,
+    /// How far in front of the rubber the pitch was released.
+    pub extension: Option<f64>,
+}
+
+/// Distance from the front of the rubber to the back of home plate.
+const RUBBER_DISTANCE: f64 = 60.5;
+
+impl PitchData {
+    /// Where the pitch was released as (horizontal, height), in feet. The trajectory is only
+    /// measured from 50 feet, so it's extrapolated back to the extension in front of the rubber.
+    pub fn release_point(&self) -> Option<(f64, f64)> {
+        let c = &self.coordinates;
+        let (x0, y0, z0) = (c.x0?, c.y0?, c.z0?);
+        let (v_x0, v_y0, v_z0) = (c.v_x0?, c.v_y0?, c.v_z0?);
+        let (a_x, a_y, a_z) = (c.a_x?, c.a_y?, c.a_z?);
+        let release_y = RUBBER_DISTANCE - self.extension?;
+        // solve y0 + v_y0 * t + a_y * t^2 / 2 = release_y for the (negative) time before y0
+        let t = if a_y == 0.0 {
+            (release_y - y0) / v_y0
+        } else {
+            let discriminant = v_y0 * v_y0 - 2.0 * a_y * (y0 - release_y);
+            (-v_y0 - discriminant.sqrt()) / a_y
+        };
+        let at = |p: f64, v: f64, a: f64| p + v * t + a * t * t / 2.0;
+        Some((at(x0, v_x0, a_x), at(z0, v_z0, a_z)))
+    }
+}
+
+/// Pitch trajectory from the catcher's point of view: x is horizontal with positive towards the
+/// first base side, y points from home plate towards the mound and z is the height.
+#[derive(Default, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PitchCoordinates {
+    /// Location when crossing the front of home plate.
+    pub p_x: Option<f64>,
+    pub p_z: Option<f64>,
+    /// Movement compared to a spinless pitch, in inches.
+    pub pfx_x: Option<f64>,
+    pub pfx_z: Option<f64>,
+    /// Position at `y0`, 50 feet from home plate, where the trajectory starts. This is after the
+    /// release, see `PitchData::release_point`.
+    pub x0: Option<f64>,
+    pub y0: Option<f64>,
+    pub z0: Option<f64>,
+    /// Velocity at `y0` in feet per second.
+    pub v_x0: Option<f64>,
+    pub v_y0: Option<f64>,
+    pub v_z0: Option<f64>,
+    /// Acceleration in feet per second squared.
+    pub a_x: Option<f64>,
+    pub a_y: Option<f64>,
+    pub a_z: Option<f64>,
+    /// Location in the Gameday image's pixel space.
+    pub x: Option<f64>,
+    pub y: Option<f64>,
 }
 
 #[derive(Default, Debug, Serialize, Deserialize)]
@@ -163,6 +219,17 @@ pub struct HitData {
     pub total_distance: Option<f64>,
     pub trajectory: Option<String>,
     pub hardness: Option<String>,
+    pub coordinates: Option<HitCoordinates>,
+}
+
+/// Where the ball was fielded, in the Gameday field image's pixel space: x grows to the right
+/// (towards first base) and y grows downward, so home plate is near (125, 199). Either can be
+/// missing, e.g. for some bunts.
+#[derive(Clone, Copy, Default, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HitCoordinates {
+    pub coord_x: Option<f64>,
+    pub coord_y: Option<f64>,
 }
 
 #[derive(Default, Debug, Serialize, Deserialize)]
@@ -171,6 +238,14 @@ pub struct Breaks {
     pub break_angle: Option<f64>,
     pub break_length: Option<f64>,
     pub break_y: Option<f64>,
+    /// Total vertical drop including gravity, in inches.
+    pub break_vertical: Option<f64>,
+    /// Vertical movement caused by spin, in inches.
+    pub break_vertical_induced: Option<f64>,
+    /// In inches, positive towards the first base side.
+    pub break_horizontal: Option<f64>,
+    /// Revolutions per minute.
     pub spin_rate: Option<u32>,
+    /// Spin axis in degrees, where 180 is pure backspin.
     pub spin_direction: Option<u32>,
 }
@@ -0,0 +1,221 @@
+use crate::client::ApiError;
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+
+/// The kinds of `ApiError` that a `RetryPolicy` can retry. Client errors other than 429 are never
+/// retried since sending the same request again won't change the answer.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum RetryOn {
+    /// Connection failures, see `ApiError::Network`.
+    Network,
+    /// See `ApiError::Timeout`.
+    Timeout,
+    /// 429 responses, see `ApiError::RateLimited`.
+    RateLimited,
+    /// 5xx responses, see `ApiError::ServerError`.
+    ServerError,
+}
+
+impl RetryOn {
+    fn matches(&self, err: &ApiError) -> bool {
+        matches!(
+            (self, err),
+            (RetryOn::Network, ApiError::Network(..))
+                | (RetryOn::Timeout, ApiError::Timeout { .. })
+                | (RetryOn::RateLimited, ApiError::RateLimited { .. })
+                | (RetryOn::ServerError, ApiError::ServerError { .. })
+        )
+    }
+}
+
+/// How `MLBApi` retries failed requests. The delay before retry `n` (starting at 1) is
+/// `initial_backoff * 2^(n-1)`, capped at `max_backoff`. With `jitter` the delay is randomized
+/// between half and all of that value so that several clients don't retry in lockstep.
+#[derive(Clone, Debug)]
+pub struct RetryPolicy {
+    /// Total number of attempts, including the first. 1 disables retries.
+    pub max_attempts: u32,
+    pub initial_backoff: Duration,
+    pub max_backoff: Duration,
+    pub jitter: bool,
+    pub retry_on: Vec<RetryOn>,
+    /// Wait for the `Retry-After` header instead of the backoff when the server sends one. If it
+    /// asks for a longer wait than `max_backoff` the error is returned instead.
+    pub respect_retry_after: bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(250),
+            max_backoff: Duration::from_secs(5),
+            jitter: true,
+            retry_on: vec![
+                RetryOn::Network,
+                RetryOn::Timeout,
+                RetryOn::RateLimited,
+                RetryOn::ServerError,
+            ],
+            respect_retry_after: true,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// A policy that never retries.
+    pub fn none() -> Self {
+        Self {
+            max_attempts: 1,
+            ..Self::default()
+        }
+    }
+
+    /// The delay before the next attempt after `attempt` (starting at 1) failed with `err`, or
+    /// `None` if the request shouldn't be retried.
+    pub fn next_delay(&self, attempt: u32, err: &ApiError) -> Option<Duration> {
+        if attempt >= self.max_attempts || !self.retry_on.iter().any(|r| r.matches(err)) {
+            return None;
+        }
+        if self.respect_retry_after
+            && let Some(retry_after) = err.retry_after()
+        {
+            return (retry_after <= self.max_backoff).then_some(retry_after);
+        }
+        Some(self.backoff(attempt))
+    }
+
+    fn backoff(&self, attempt: u32) -> Duration {
+        let exponent = attempt.saturating_sub(1).min(31);
+        let backoff = self
+            .initial_backoff
+            .saturating_mul(1 << exponent)
+            .min(self.max_backoff);
+        if !self.jitter {
+            return backoff;
+        }
+        let half = backoff / 2;
+        let nanos = half.as_nanos() as u64;
+        if nanos == 0 {
+            return backoff;
+        }
+        half + Duration::from_nanos(random_u64() % (nanos + 1))
+    }
+}
+
+/// A random number from the std library's per-process hash seed, which is plenty for jitter and
+/// avoids another dependency.
+fn random_u64() -> u64 {
+    RandomState::new().build_hasher().finish()
+}
+
+/// Parse a `Retry-After` header, which is either a number of seconds or an HTTP date.
+pub(crate) fn parse_retry_after(value: &str) -> Option<Duration> {
+    let value = value.trim();
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+    let date = DateTime::parse_from_rfc2822(value).ok()?;
+    let wait = date.with_timezone(&Utc) - Utc::now();
+    Some(wait.to_std().unwrap_or_default())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn server_error() -> ApiError {
+        ApiError::ServerError {
+            status: 503,
+            url: String::new(),
+            body: String::new(),
+            retry_after: None,
+        }
+    }
+
+    fn no_jitter() -> RetryPolicy {
+        RetryPolicy {
+            max_attempts: 5,
+            initial_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_millis(500),
+            jitter: false,
+            ..RetryPolicy::default()
+        }
+    }
+
+    #[test]
+    fn backoff_doubles_until_capped() {
+        let policy = no_jitter();
+        let delays: Vec<_> = (1..5)
+            .map(|attempt| policy.next_delay(attempt, &server_error()).unwrap())
+            .collect();
+        assert_eq!(
+            delays,
+            [100, 200, 400, 500].map(Duration::from_millis).to_vec()
+        );
+        assert!(policy.next_delay(5, &server_error()).is_none());
+    }
+
+    #[test]
+    fn jitter_stays_between_half_and_full_backoff() {
+        let policy = RetryPolicy {
+            jitter: true,
+            ..no_jitter()
+        };
+        for _ in 0..100 {
+            let delay = policy.next_delay(2, &server_error()).unwrap();
+            assert!(delay >= Duration::from_millis(100) && delay <= Duration::from_millis(200));
+        }
+    }
+
+    #[test]
+    fn only_configured_errors_are_retried() {
+        let policy = RetryPolicy {
+            retry_on: vec![RetryOn::Timeout],
+            ..no_jitter()
+        };
+        assert!(policy.next_delay(1, &server_error()).is_none());
+        let timeout = ApiError::Timeout { url: String::new() };
+        assert!(policy.next_delay(1, &timeout).is_some());
+        let not_found = ApiError::NotFound {
+            url: String::new(),
+            body: String::new(),
+        };
+        assert!(RetryPolicy::default().next_delay(1, &not_found).is_none());
+    }
+
+    #[test]
+    fn retry_after_overrides_backoff() {
+        let policy = no_jitter();
+        let rate_limited = |secs| ApiError::RateLimited {
+            url: String::new(),
+            body: String::new(),
+            retry_after: Some(Duration::from_secs(secs)),
+        };
+        assert_eq!(policy.next_delay(1, &rate_limited(0)), Some(Duration::ZERO));
+        // longer than max_backoff, so give up
+        assert!(policy.next_delay(1, &rate_limited(60)).is_none());
+
+        let ignore = RetryPolicy {
+            respect_retry_after: false,
+            ..no_jitter()
+        };
+        assert_eq!(
+            ignore.next_delay(1, &rate_limited(60)),
+            Some(Duration::from_millis(100))
+        );
+    }
+
+    #[test]
+    fn parse_retry_after_seconds_and_dates() {
+        assert_eq!(parse_retry_after("120"), Some(Duration::from_secs(120)));
+        assert_eq!(
+            parse_retry_after("Wed, 21 Oct 2015 07:28:00 GMT"),
+            Some(Duration::ZERO)
+        );
+        assert!(parse_retry_after("soon").is_none());
+    }
+}
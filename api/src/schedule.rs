@@ -1,3 +1,4 @@
+use crate::season::GameType;
 use crate::stats::DisplayName;
 use chrono::{DateTime, NaiveDate, Utc};
 use serde::{Deserialize, Serialize};
@@ -13,6 +14,29 @@ pub struct ScheduleResponse {
     pub dates: Vec<Dates>, // these are the actual games
 }
 
+/// Response from `v1/schedule/postseason/series`, the games of every postseason series in a season.
+#[derive(Default, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PostseasonSeriesResponse {
+    pub series: Vec<PostseasonSeries>,
+}
+
+#[derive(Default, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PostseasonSeries {
+    pub series: SeriesInfo,
+    #[serde(default)]
+    pub games: Vec<Game>,
+}
+
+#[derive(Default, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SeriesInfo {
+    /// e.g. "F_1" for the first wild card series.
+    pub id: String,
+    pub sort_number: Option<u8>,
+}
+
 #[derive(Default, Debug, Serialize, Deserialize)]
 pub struct LeagueRecord {
     pub wins: u8,
@@ -20,7 +44,7 @@ pub struct LeagueRecord {
     pub pct: String,
 }
 
-#[derive(Default, Debug, Serialize, Deserialize)]
+#[derive(Clone, Default, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct IdNameLink {
     pub id: u16,
@@ -58,7 +82,8 @@ pub struct ScheduleLinescore {
 pub struct Game {
     pub game_pk: u64,
     pub link: String,
-    // pub game_type: Option<GameType>,
+    #[serde(default, deserialize_with = "crate::season::optional_game_type")]
+    pub game_type: Option<GameType>,
     pub season: String,
     pub game_date: DateTime<Utc>,
     pub official_date: NaiveDate,
@@ -71,26 +96,70 @@ pub struct Game {
     pub is_tie: Option<bool>,
     pub game_number: Option<u64>,
     // pub public_facing: Option<bool>,
-    // pub double_header: Option<DoubleHeader>,
+    pub double_header: Option<DoubleHeader>,
     // pub gameday_type: Option<GamedayType>,
     // pub tiebreaker: Option<DoubleHeader>,
     pub calendar_event_id: Option<String>,
     // pub season_display: Option<String>,
-    // pub day_night: Option<DayNight>,
+    pub day_night: Option<DayNight>,
     // pub scheduled_innings: Option<i64>,
     // pub reverse_home_away_status: Option<bool>,
     // pub inning_break_length: Option<i64>,
-    // pub games_in_series: Option<i64>,
-    // pub series_game_number: Option<i64>,
-    // pub series_description: Option<SeriesDescription>,
+    pub games_in_series: Option<u8>,
+    pub series_game_number: Option<u8>,
+    /// e.g. "Regular Season" or "World Series".
+    pub series_description: Option<String>,
     // pub record_source: Option<RecordSource>,
     // pub if_necessary: Option<DoubleHeader>,
     // pub if_necessary_description: Option<IfNecessaryDescription>,
-    // pub rescheduled_from: Option<String>,
-    // pub description: Option<String>,
-    // pub resume_date: Option<String>,
+    /// Original start time of a postponed game that was made up.
+    pub rescheduled_from: Option<String>,
+    #[serde(default, with = "crate::serde_dates::optional_date")]
+    pub rescheduled_from_date: Option<NaiveDate>,
+    /// e.g. "Makeup of 6/18 ppd".
+    pub description: Option<String>,
+    /// Start time of the resumption of a suspended game.
+    pub resume_date: Option<String>,
+    #[serde(default, with = "crate::serde_dates::optional_date")]
+    pub resume_game_date: Option<NaiveDate>,
     // pub reschedule_date: Option<String>,
     pub decisions: Option<Decisions>,
+    /// Only present if `hydrate=broadcasts(all)` is used.
+    #[serde(default)]
+    pub broadcasts: Vec<Broadcast>,
+}
+
+/// A TV or radio broadcast of a game.
+#[derive(Default, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Broadcast {
+    pub id: Option<u64>,
+    /// Missing for some broadcasts, which are skipped rather than failing the whole schedule.
+    #[serde(default)]
+    pub name: String,
+    #[serde(rename = "type")]
+    pub broadcast_type: Option<BroadcastType>,
+    pub call_sign: Option<String>,
+    /// e.g. "en" or "es".
+    pub language: Option<String>,
+    /// "home" or "away", national broadcasts also use "home".
+    pub home_away: Option<String>,
+    pub is_national: Option<bool>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub enum BroadcastType {
+    TV,
+    AM,
+    FM,
+    #[serde(other)]
+    Other,
+}
+
+impl BroadcastType {
+    pub fn is_radio(self) -> bool {
+        matches!(self, Self::AM | Self::FM)
+    }
 }
 
 #[derive(Default, Debug, Serialize, Deserialize)]
@@ -129,17 +198,21 @@ pub struct TeamInfo {
     pub probable_pitcher: Option<ProbablePitcher>,
 }
 
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub enum DayNight {
     Day,
     Night,
 }
 
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
 pub enum DoubleHeader {
+    /// Not a doubleheader.
     N,
+    /// Traditional doubleheader, one admission for both games.
     Y,
+    /// Split doubleheader, separate admission for each game.
+    S,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
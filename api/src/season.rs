@@ -1,16 +1,108 @@
+use crate::schedule::PostseasonSeriesResponse;
 use chrono::{Datelike, NaiveDate};
-use serde::Deserialize;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
 
 /// If the seasons API fails, conservatively assume spring training ends before March 20.
 /// This avoids using spring training params after the regular season has started.
 const SPRING_TRAINING_FALLBACK_MONTH: u32 = 3;
 const SPRING_TRAINING_FALLBACK_DAY: u32 = 20;
 
-/// Whether the date falls in spring training or the regular season.
-#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+/// The type of game, taken from the "meta" endpoint: https://statsapi.mlb.com/api/v1/gameTypes
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
 pub enum GameType {
     SpringTraining,
     RegularSeason,
+    WildCard,
+    DivisionSeries,
+    LeagueChampionshipSeries,
+    WorldSeries,
+    Exhibition,
+    AllStar,
+}
+
+impl GameType {
+    /// The postseason rounds in the order they're played.
+    pub const POSTSEASON: [GameType; 4] = [
+        GameType::WildCard,
+        GameType::DivisionSeries,
+        GameType::LeagueChampionshipSeries,
+        GameType::WorldSeries,
+    ];
+
+    /// The single letter code the API uses for the `gameType` field and parameter.
+    pub fn code(&self) -> &'static str {
+        match self {
+            GameType::SpringTraining => "S",
+            GameType::RegularSeason => "R",
+            GameType::WildCard => "F",
+            GameType::DivisionSeries => "D",
+            GameType::LeagueChampionshipSeries => "L",
+            GameType::WorldSeries => "W",
+            GameType::Exhibition => "E",
+            GameType::AllStar => "A",
+        }
+    }
+
+    pub fn from_code(code: &str) -> Option<Self> {
+        match code {
+            "S" => Some(GameType::SpringTraining),
+            "R" => Some(GameType::RegularSeason),
+            "F" => Some(GameType::WildCard),
+            "D" => Some(GameType::DivisionSeries),
+            "L" => Some(GameType::LeagueChampionshipSeries),
+            "W" => Some(GameType::WorldSeries),
+            "E" => Some(GameType::Exhibition),
+            "A" => Some(GameType::AllStar),
+            _ => None,
+        }
+    }
+
+    pub fn is_postseason(&self) -> bool {
+        GameType::POSTSEASON.contains(self)
+    }
+
+    /// The `gameType` parameter for stats requests, or None to use the API default of regular
+    /// season. Postseason rounds include every postseason game so far since a single round is too
+    /// small a sample to be interesting.
+    pub fn stats_param(&self) -> Option<&'static str> {
+        match self {
+            GameType::RegularSeason => None,
+            t if t.is_postseason() => Some("F,D,L,W"),
+            t => Some(t.code()),
+        }
+    }
+}
+
+impl fmt::Display for GameType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            GameType::SpringTraining => "Spring Training",
+            GameType::RegularSeason => "Regular Season",
+            GameType::WildCard => "Wild Card",
+            GameType::DivisionSeries => "Division Series",
+            GameType::LeagueChampionshipSeries => "League Championship Series",
+            GameType::WorldSeries => "World Series",
+            GameType::Exhibition => "Exhibition",
+            GameType::AllStar => "All-Star Game",
+        };
+        write!(f, "{name}")
+    }
+}
+
+impl Serialize for GameType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.code())
+    }
+}
+
+/// Deserialize a `gameType` code. Codes that aren't a `GameType`, e.g. "I" for intrasquad, are
+/// treated as missing rather than failing the whole response.
+pub(crate) fn optional_game_type<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<GameType>, D::Error> {
+    let code: Option<String> = Option::deserialize(deserializer)?;
+    Ok(code.as_deref().and_then(GameType::from_code))
 }
 
 #[derive(Default, Deserialize)]
@@ -20,19 +112,71 @@ pub(crate) struct SeasonsResponse {
 }
 
 /// Season date boundaries fetched from the MLB seasons API.
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Default, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SeasonInfo {
+    pub spring_end_date: Option<NaiveDate>,
     pub regular_season_start_date: NaiveDate,
+    pub regular_season_end_date: Option<NaiveDate>,
+    pub all_star_date: Option<NaiveDate>,
+    pub post_season_start_date: Option<NaiveDate>,
+    pub post_season_end_date: Option<NaiveDate>,
+    /// The first day of each postseason round that has started. The seasons API only has the
+    /// start and end of the whole postseason, so these are filled in from the postseason schedule
+    /// with `set_postseason_rounds`.
+    #[serde(skip)]
+    pub postseason_rounds: Vec<(GameType, NaiveDate)>,
+}
+
+impl SeasonInfo {
+    /// Whether the date falls between the start and end of the postseason.
+    pub fn is_postseason(&self, date: NaiveDate) -> bool {
+        match (self.post_season_start_date, self.post_season_end_date) {
+            (Some(start), Some(end)) => start <= date && date <= end,
+            (Some(start), None) => start <= date,
+            _ => false,
+        }
+    }
+
+    /// Record when each postseason round starts, using the earliest game of the round.
+    pub fn set_postseason_rounds(&mut self, postseason: &PostseasonSeriesResponse) {
+        let mut rounds: Vec<(GameType, NaiveDate)> = Vec::new();
+        let games = postseason.series.iter().flat_map(|s| s.games.iter());
+        for game in games {
+            let Some(game_type) = game.game_type.filter(GameType::is_postseason) else {
+                continue;
+            };
+            match rounds.iter_mut().find(|(t, _)| *t == game_type) {
+                Some((_, start)) => *start = (*start).min(game.official_date),
+                None => rounds.push((game_type, game.official_date)),
+            }
+        }
+        rounds.sort();
+        self.postseason_rounds = rounds;
+    }
 }
 
 /// Determine the game type for a given date.
-/// Uses SeasonInfo if available, otherwise falls back to a conservative heuristic.
+/// Uses SeasonInfo if available, otherwise falls back to a conservative heuristic. Dates in the
+/// postseason map to the round being played, or the first round if the rounds aren't known yet.
+/// Days between the end of spring training and opening day are exhibitions.
 pub fn game_type_for_date(date: NaiveDate, season_info: Option<&SeasonInfo>) -> GameType {
     match season_info {
         Some(info) => {
-            if date < info.regular_season_start_date {
+            if info.all_star_date == Some(date) {
+                GameType::AllStar
+            } else if info.spring_end_date.is_some_and(|end| end < date)
+                && date < info.regular_season_start_date
+            {
+                GameType::Exhibition
+            } else if date < info.regular_season_start_date {
                 GameType::SpringTraining
+            } else if info.is_postseason(date) {
+                info.postseason_rounds
+                    .iter()
+                    .rev()
+                    .find(|(_, start)| *start <= date)
+                    .map_or(GameType::WildCard, |(game_type, _)| *game_type)
             } else {
                 GameType::RegularSeason
             }
@@ -59,6 +203,7 @@ mod tests {
     fn test_spring_training_with_season_info() {
         let info = SeasonInfo {
             regular_season_start_date: NaiveDate::from_ymd_opt(2026, 3, 25).unwrap(),
+            ..SeasonInfo::default()
         };
         let spring = NaiveDate::from_ymd_opt(2026, 3, 10).unwrap();
         let regular = NaiveDate::from_ymd_opt(2026, 3, 25).unwrap();
@@ -78,6 +223,29 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_exhibitions_after_spring_training() {
+        let date = |m, d| NaiveDate::from_ymd_opt(2024, m, d).unwrap();
+        let info = SeasonInfo {
+            spring_end_date: Some(date(3, 26)),
+            regular_season_start_date: date(3, 28),
+            ..SeasonInfo::default()
+        };
+
+        assert_eq!(
+            game_type_for_date(date(3, 26), Some(&info)),
+            GameType::SpringTraining
+        );
+        assert_eq!(
+            game_type_for_date(date(3, 27), Some(&info)),
+            GameType::Exhibition
+        );
+        assert_eq!(
+            game_type_for_date(date(3, 28), Some(&info)),
+            GameType::RegularSeason
+        );
+    }
+
     #[test]
     fn test_spring_training_fallback_without_season_info() {
         let before_cutoff = NaiveDate::from_ymd_opt(2026, 3, 1).unwrap();
@@ -94,4 +262,79 @@ mod tests {
         );
         assert_eq!(game_type_for_date(on_cutoff, None), GameType::RegularSeason);
     }
+
+    #[test]
+    fn test_postseason_rounds() {
+        let date = |m, d| NaiveDate::from_ymd_opt(2024, m, d).unwrap();
+        let mut info = SeasonInfo {
+            regular_season_start_date: date(3, 20),
+            regular_season_end_date: Some(date(9, 29)),
+            all_star_date: Some(date(7, 16)),
+            post_season_start_date: Some(date(10, 1)),
+            post_season_end_date: Some(date(10, 31)),
+            ..SeasonInfo::default()
+        };
+
+        assert_eq!(
+            game_type_for_date(date(7, 16), Some(&info)),
+            GameType::AllStar
+        );
+        // the rest of the All-Star break is still part of the regular season
+        assert_eq!(
+            game_type_for_date(date(7, 17), Some(&info)),
+            GameType::RegularSeason
+        );
+        assert_eq!(
+            game_type_for_date(date(9, 29), Some(&info)),
+            GameType::RegularSeason
+        );
+        // rounds aren't known yet, so assume the first one
+        assert_eq!(
+            game_type_for_date(date(10, 20), Some(&info)),
+            GameType::WildCard
+        );
+
+        info.postseason_rounds = vec![
+            (GameType::WildCard, date(10, 1)),
+            (GameType::DivisionSeries, date(10, 5)),
+            (GameType::LeagueChampionshipSeries, date(10, 13)),
+            (GameType::WorldSeries, date(10, 25)),
+        ];
+        assert_eq!(
+            game_type_for_date(date(10, 4), Some(&info)),
+            GameType::WildCard
+        );
+        assert_eq!(
+            game_type_for_date(date(10, 20), Some(&info)),
+            GameType::LeagueChampionshipSeries
+        );
+        assert_eq!(
+            game_type_for_date(date(10, 31), Some(&info)),
+            GameType::WorldSeries
+        );
+        assert_eq!(
+            game_type_for_date(date(11, 15), Some(&info)),
+            GameType::RegularSeason
+        );
+    }
+
+    #[test]
+    fn test_game_type_codes() {
+        for game_type in [
+            GameType::SpringTraining,
+            GameType::RegularSeason,
+            GameType::WildCard,
+            GameType::DivisionSeries,
+            GameType::LeagueChampionshipSeries,
+            GameType::WorldSeries,
+            GameType::Exhibition,
+            GameType::AllStar,
+        ] {
+            assert_eq!(GameType::from_code(game_type.code()), Some(game_type));
+        }
+        assert_eq!(GameType::from_code("I"), None);
+        assert_eq!(GameType::RegularSeason.stats_param(), None);
+        assert_eq!(GameType::SpringTraining.stats_param(), Some("S"));
+        assert_eq!(GameType::DivisionSeries.stats_param(), Some("F,D,L,W"));
+    }
 }
@@ -20,8 +20,10 @@ pub struct Record {
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct IdLink {
-    pub id: u8,
+    pub id: u16,
     pub link: String,
+    /// Only set when hydrated, see `MLBApi::get_standings`.
+    pub name: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -2,12 +2,12 @@ use crate::schedule::IdNameLink;
 use chrono::NaiveDate;
 use serde::{Deserialize, Serialize};
 
-#[derive(Default, Debug, Serialize, Deserialize)]
+#[derive(Clone, Default, Debug, Serialize, Deserialize)]
 pub struct StatsResponse {
     pub stats: Vec<Stat>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Stat {
     #[serde(rename = "type")]
@@ -18,13 +18,13 @@ pub struct Stat {
     pub splits: Vec<Split>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct DisplayName {
     pub display_name: String,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Split {
     pub season: Option<String>,
@@ -40,13 +40,13 @@ pub struct Split {
     pub game: Option<GameRef>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct GameRef {
     pub game_pk: u64,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Player {
     pub id: u64,
@@ -55,17 +55,24 @@ pub struct Player {
     pub last_name: Option<String>,
 }
 
-/// StatSplit stores the two options for deserializing a Split.
-/// It uses the `untagged` enum representation to determine which one.
+/// StatSplit stores the options for deserializing a Split, one per stat group.
+/// It uses the `untagged` enum representation to determine which one. Variants are tried in order,
+/// so the groups with the most required fields come first.
 /// https://serde.rs/enum-representations.html#untagged
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum StatSplit {
     Pitching(Box<PitchingStat>),
     Hitting(Box<HittingStat>),
+    Fielding(Box<FieldingStat>),
+    Catching(Box<CatchingStat>),
+    Running(Box<RunningStat>),
+    PitchArsenal(Box<PitchArsenalStat>),
+    Sabermetrics(Box<SabermetricsStat>),
+    Expected(Box<ExpectedStat>),
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct PitchingStat {
     pub wins: u16,
@@ -128,7 +135,7 @@ pub struct PitchingStat {
     sac_flies: u16,
 }
 
-#[derive(Default, Debug, Serialize, Deserialize)]
+#[derive(Clone, Default, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct HittingStat {
     pub games_played: u16,
@@ -164,3 +171,140 @@ pub struct HittingStat {
     pub catchers_interference: Option<u16>,
     pub at_bats_per_home_run: String,
 }
+
+/// Position abbreviation attached to fielding splits, e.g. "SS".
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Position {
+    pub code: String,
+    pub name: String,
+    pub abbreviation: String,
+}
+
+/// Fielding stats. Player splits are per position, so a player can appear more than once.
+#[derive(Clone, Default, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FieldingStat {
+    pub position: Option<Position>,
+    pub games_played: u16,
+    #[serde(default)]
+    pub games_started: u16,
+    pub assists: u16,
+    pub put_outs: u16,
+    pub errors: u16,
+    pub chances: u16,
+    pub fielding: String,
+    #[serde(default)]
+    pub innings: String,
+    #[serde(default)]
+    pub range_factor_per_game: String,
+    #[serde(default)]
+    pub range_factor_per9_inn: String,
+    #[serde(default)]
+    pub double_plays: u16,
+    #[serde(default)]
+    pub triple_plays: u16,
+    #[serde(default)]
+    pub throwing_errors: u16,
+    #[serde(default)]
+    pub passed_ball: u16,
+    #[serde(default)]
+    pub stolen_bases: u16,
+    #[serde(default)]
+    pub caught_stealing: u16,
+    pub caught_stealing_percentage: Option<String>,
+}
+
+/// Catching stats, which are what opposing runners and pitchers did with the player behind the
+/// plate.
+#[derive(Clone, Default, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CatchingStat {
+    pub games_played: u16,
+    pub passed_ball: u16,
+    pub stolen_bases: u16,
+    pub caught_stealing: u16,
+    #[serde(default)]
+    pub stolen_base_percentage: String,
+    pub caught_stealing_percentage: Option<String>,
+    #[serde(default)]
+    pub wild_pitches: u16,
+    #[serde(default)]
+    pub pickoffs: u16,
+    #[serde(default)]
+    pub catchers_interference: u16,
+    #[serde(default)]
+    pub earned_runs: u16,
+    #[serde(rename = "catcherERA")]
+    pub catcher_era: Option<String>,
+    #[serde(default)]
+    pub batters_faced: u16,
+    #[serde(default)]
+    pub strike_outs: u16,
+    #[serde(default)]
+    pub base_on_balls: u16,
+}
+
+/// Base running stats.
+#[derive(Clone, Default, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RunningStat {
+    pub games_played: u16,
+    pub stolen_bases: u16,
+    pub caught_stealing: u16,
+    #[serde(default)]
+    pub stolen_base_percentage: String,
+    #[serde(default)]
+    pub runs: u16,
+    #[serde(default)]
+    pub pickoffs: u16,
+    #[serde(default)]
+    pub ground_into_double_play: u16,
+}
+
+/// One pitch type from the `pitchArsenal` stat type, e.g. a pitcher's four-seam fastball.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PitchArsenalStat {
+    /// Share of all pitches thrown, from 0 to 1.
+    pub percentage: f64,
+    pub count: u32,
+    pub total_pitches: u32,
+    /// Miles per hour.
+    pub average_speed: f64,
+    /// Revolutions per minute, missing for some older seasons.
+    pub average_spin_rate: Option<f64>,
+    #[serde(rename = "type")]
+    pub pitch_type: PitchType,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct PitchType {
+    /// E.g. "FF".
+    pub code: String,
+    /// E.g. "Four-Seam Fastball".
+    pub description: String,
+}
+
+/// Advanced stats from the `sabermetrics` stat type. Hitters get wOBA and wRC+, pitchers get FIP and
+/// xFIP, so only WAR is shared.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SabermetricsStat {
+    pub war: f64,
+    pub woba: Option<f64>,
+    pub w_rc_plus: Option<f64>,
+    pub fip: Option<f64>,
+    pub xfip: Option<f64>,
+}
+
+/// Statcast expected stats from the `expectedStatistics` stat type, based on quality of contact.
+/// For pitchers these are the values allowed.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExpectedStat {
+    pub avg: String,
+    pub slg: String,
+    pub woba: String,
+    pub woba_con: Option<String>,
+}
@@ -7,6 +7,12 @@ use std::fmt;
 pub enum RosterType {
     Active,
     FortyMan,
+    /// Everyone who was on the 40 man roster at some point in the season, including players who
+    /// have since been traded or released.
+    FullSeason,
+    /// Players listed under each position they play, in depth order. A player can be listed at
+    /// more than one position.
+    DepthChart,
 }
 
 impl fmt::Display for RosterType {
@@ -14,6 +20,8 @@ impl fmt::Display for RosterType {
         match self {
             RosterType::Active => write!(f, "active"),
             RosterType::FortyMan => write!(f, "40Man"),
+            RosterType::FullSeason => write!(f, "fullSeason"),
+            RosterType::DepthChart => write!(f, "depthChart"),
         }
     }
 }
@@ -1,14 +1,78 @@
 use serde::Deserialize;
 use std::fmt;
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum SportId {
     /// Major League Baseball
+    #[default]
     Mlb = 1,
+    /// Triple-A
+    TripleA = 11,
+    /// Double-A
+    DoubleA = 12,
+    /// High-A
+    HighA = 13,
+    /// Single-A
+    SingleA = 14,
+    /// Rookie ball, e.g. the Arizona and Florida Complex Leagues
+    Rookie = 16,
     /// International Baseball (World Baseball Classic, etc.)
     International = 51,
 }
 
+impl SportId {
+    /// MLB followed by the minor league levels, highest first.
+    pub const LEVELS: [SportId; 6] = [
+        SportId::Mlb,
+        SportId::TripleA,
+        SportId::DoubleA,
+        SportId::HighA,
+        SportId::SingleA,
+        SportId::Rookie,
+    ];
+
+    pub fn is_minor_league(&self) -> bool {
+        !matches!(self, SportId::Mlb | SportId::International)
+    }
+
+    /// Short name used in titles, e.g. "AAA".
+    pub fn abbreviation(&self) -> &'static str {
+        match self {
+            SportId::Mlb => "MLB",
+            SportId::TripleA => "AAA",
+            SportId::DoubleA => "AA",
+            SportId::HighA => "A+",
+            SportId::SingleA => "A",
+            SportId::Rookie => "ROK",
+            SportId::International => "INTL",
+        }
+    }
+
+    /// The next level in `LEVELS`, wrapping back around to MLB.
+    pub fn next_level(&self) -> SportId {
+        let index = Self::LEVELS.iter().position(|l| l == self).unwrap_or(0);
+        Self::LEVELS[(index + 1) % Self::LEVELS.len()]
+    }
+
+    /// The leagues the standings endpoint needs for this level, which it requires instead of a
+    /// sport id.
+    pub fn league_ids(&self) -> &'static str {
+        match self {
+            SportId::Mlb | SportId::International => "103,104",
+            // International, Pacific Coast
+            SportId::TripleA => "117,112",
+            // Eastern, Southern, Texas
+            SportId::DoubleA => "113,111,109",
+            // Midwest, Northwest, South Atlantic
+            SportId::HighA => "118,126,116",
+            // California, Carolina, Florida State
+            SportId::SingleA => "110,122,123",
+            // Arizona Complex, Florida Complex, Dominican Summer
+            SportId::Rookie => "121,124,130",
+        }
+    }
+}
+
 impl fmt::Display for SportId {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", *self as u16)
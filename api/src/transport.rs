@@ -0,0 +1,270 @@
+use crate::client::{ApiError, ApiResult};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::future::Future;
+use std::path::{Path, PathBuf};
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use reqwest::header::RETRY_AFTER;
+use reqwest::{Client, StatusCode, Url};
+use serde::{Deserialize, Serialize};
+
+/// Name of the file in a recording directory that maps urls to response files.
+pub const INDEX_FILE: &str = "index.json";
+
+pub type TransportFuture<'a> = Pin<Box<dyn Future<Output = ApiResult<RawResponse>> + Send + 'a>>;
+
+/// Performs the HTTP GET for `MLBApi`. Status codes, retries and decoding are handled by the
+/// client, so an implementation only needs to return what the server sent.
+pub trait Transport: fmt::Debug + Send + Sync {
+    fn get<'a>(&'a self, url: &'a str, timeout: Duration) -> TransportFuture<'a>;
+}
+
+/// A response as returned by a `Transport`.
+#[derive(Debug, Clone)]
+pub struct RawResponse {
+    pub status: StatusCode,
+    /// The raw `Retry-After` header, if any.
+    pub retry_after: Option<String>,
+    pub body: Vec<u8>,
+}
+
+/// Sends requests to the API with `reqwest`. This is the default transport.
+#[derive(Debug, Clone, Default)]
+pub struct ReqwestTransport {
+    client: Client,
+}
+
+impl ReqwestTransport {
+    pub fn new(client: Client) -> Self {
+        Self { client }
+    }
+}
+
+impl Transport for ReqwestTransport {
+    fn get<'a>(&'a self, url: &'a str, timeout: Duration) -> TransportFuture<'a> {
+        Box::pin(async move {
+            let response = self
+                .client
+                .get(url)
+                .timeout(timeout)
+                .send()
+                .await
+                .map_err(|err| ApiError::from_reqwest(err, url.to_string()))?;
+
+            let status = response.status();
+            let retry_after = response
+                .headers()
+                .get(RETRY_AFTER)
+                .and_then(|value| value.to_str().ok())
+                .map(String::from);
+            let body = response
+                .bytes()
+                .await
+                .map_err(|err| ApiError::from_reqwest(err, url.to_string()))?;
+            Ok(RawResponse {
+                status,
+                retry_after,
+                body: body.to_vec(),
+            })
+        })
+    }
+}
+
+/// An entry in a recording's `index.json`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecordedResponse {
+    /// The response body, relative to the recording directory.
+    pub file: String,
+    #[serde(default = "default_status")]
+    pub status: u16,
+}
+
+fn default_status() -> u16 {
+    200
+}
+
+/// Wraps another transport and saves every response body to `dir`, along with an `index.json`
+/// that maps each url to its file. The directory can be served with `ReplayTransport`.
+#[derive(Debug)]
+pub struct RecordingTransport {
+    inner: Arc<dyn Transport>,
+    dir: PathBuf,
+    index: Mutex<BTreeMap<String, RecordedResponse>>,
+}
+
+impl RecordingTransport {
+    /// Record into `dir`, creating it if needed. Responses already recorded there are kept unless
+    /// the same url is requested again.
+    pub fn new(inner: Arc<dyn Transport>, dir: impl Into<PathBuf>) -> std::io::Result<Self> {
+        let dir = dir.into();
+        fs::create_dir_all(&dir)?;
+        let index = read_index(&dir).unwrap_or_default();
+        Ok(Self {
+            inner,
+            dir,
+            index: Mutex::new(index),
+        })
+    }
+
+    fn record(&self, url: &str, response: &RawResponse) -> std::io::Result<()> {
+        let file = file_name(url);
+        fs::write(self.dir.join(&file), &response.body)?;
+
+        let mut index = self.index.lock().unwrap_or_else(|e| e.into_inner());
+        index.insert(
+            url.to_string(),
+            RecordedResponse {
+                file,
+                status: response.status.as_u16(),
+            },
+        );
+        let json = serde_json::to_string_pretty(&*index)?;
+        fs::write(self.dir.join(INDEX_FILE), json)
+    }
+}
+
+impl Transport for RecordingTransport {
+    fn get<'a>(&'a self, url: &'a str, timeout: Duration) -> TransportFuture<'a> {
+        Box::pin(async move {
+            let response = self.inner.get(url, timeout).await?;
+            self.record(url, &response)
+                .map_err(|err| ApiError::Transport {
+                    url: url.to_string(),
+                    message: format!("failed to record response: {err}"),
+                })?;
+            Ok(response)
+        })
+    }
+}
+
+/// Serves responses from a directory written by `RecordingTransport`. Urls are matched on their
+/// path and query, so a recording can be replayed against a different host. Urls that weren't
+/// recorded get a 404.
+#[derive(Debug)]
+pub struct ReplayTransport {
+    dir: PathBuf,
+    responses: BTreeMap<String, RecordedResponse>,
+}
+
+impl ReplayTransport {
+    pub fn new(dir: impl Into<PathBuf>) -> std::io::Result<Self> {
+        let dir = dir.into();
+        let responses = read_index(&dir)?
+            .into_iter()
+            .map(|(url, response)| (replay_key(&url), response))
+            .collect();
+        Ok(Self { dir, responses })
+    }
+}
+
+impl Transport for ReplayTransport {
+    fn get<'a>(&'a self, url: &'a str, _timeout: Duration) -> TransportFuture<'a> {
+        Box::pin(async move {
+            let Some(recorded) = self.responses.get(&replay_key(url)) else {
+                return Ok(RawResponse {
+                    status: StatusCode::NOT_FOUND,
+                    retry_after: None,
+                    body: format!("no recorded response for {url}").into_bytes(),
+                });
+            };
+            let body =
+                fs::read(self.dir.join(&recorded.file)).map_err(|err| ApiError::Transport {
+                    url: url.to_string(),
+                    message: format!("failed to read {}: {err}", recorded.file),
+                })?;
+            Ok(RawResponse {
+                status: StatusCode::from_u16(recorded.status).unwrap_or(StatusCode::OK),
+                retry_after: None,
+                body,
+            })
+        })
+    }
+}
+
+/// Fails every request without touching the network, for `--offline`. Only cached responses
+/// can be shown.
+#[derive(Debug, Clone, Default)]
+pub struct OfflineTransport;
+
+impl Transport for OfflineTransport {
+    fn get<'a>(&'a self, url: &'a str, _timeout: Duration) -> TransportFuture<'a> {
+        Box::pin(async move {
+            Err(ApiError::Transport {
+                url: url.to_string(),
+                message: "offline".to_string(),
+            })
+        })
+    }
+}
+
+fn read_index(dir: &Path) -> std::io::Result<BTreeMap<String, RecordedResponse>> {
+    let json = fs::read_to_string(dir.join(INDEX_FILE))?;
+    serde_json::from_str(&json).map_err(std::io::Error::other)
+}
+
+/// The part of a url that a replay is matched on, e.g. `/api/v1/teams?sportId=1`.
+pub(crate) fn replay_key(url: &str) -> String {
+    match Url::parse(url) {
+        Ok(parsed) => match parsed.query() {
+            Some(query) => format!("{}?{query}", parsed.path()),
+            None => parsed.path().to_string(),
+        },
+        Err(_) => url.to_string(),
+    }
+}
+
+/// A readable file name for a url. Long urls are truncated, so a hash of the full url keeps the
+/// names unique.
+pub(crate) fn file_name(url: &str) -> String {
+    const MAX_LEN: usize = 80;
+    let key = replay_key(url);
+    let mut name: String = key
+        .trim_start_matches('/')
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .take(MAX_LEN)
+        .collect();
+    name.push_str(&format!("-{:016x}.json", fnv1a(key.as_bytes())));
+    name
+}
+
+/// FNV-1a, used instead of `DefaultHasher` since file names need to be stable across Rust versions.
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf29ce484222325, |hash, byte| {
+        (hash ^ *byte as u64).wrapping_mul(0x100000001b3)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn replay_key_ignores_host() {
+        assert_eq!(
+            replay_key("https://statsapi.mlb.com/api/v1/teams?sportId=1"),
+            "/api/v1/teams?sportId=1"
+        );
+        assert_eq!(
+            replay_key("http://127.0.0.1:1234/api/v1/teams?sportId=1"),
+            "/api/v1/teams?sportId=1"
+        );
+    }
+
+    #[test]
+    fn file_names_are_readable_and_unique() {
+        let name = file_name("https://statsapi.mlb.com/api/v1/teams?sportId=1");
+        assert!(name.starts_with("api_v1_teams_sportId_1-"), "{name}");
+        assert!(name.ends_with(".json"));
+
+        let long = "x".repeat(200);
+        let a = file_name(&format!("https://statsapi.mlb.com/{long}a"));
+        let b = file_name(&format!("https://statsapi.mlb.com/{long}b"));
+        assert_ne!(a, b);
+        assert!(a.len() < 120);
+    }
+}
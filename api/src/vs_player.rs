@@ -0,0 +1,73 @@
+use crate::stats::{DisplayName, Player};
+use serde::Deserialize;
+
+/// Batter vs pitcher history from `v1/people/{id}/stats?stats=vsPlayer`. The response has a
+/// `vsPlayerTotal` entry with the career totals and a `vsPlayer` entry with one split per season.
+///
+/// These splits don't include every field of a regular hitting split (e.g. runs and stolen bases),
+/// so they get their own types instead of `stats::HittingStat`.
+#[derive(Default, Debug, Deserialize)]
+pub struct VsPlayerResponse {
+    #[serde(default)]
+    pub stats: Vec<VsPlayerStats>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VsPlayerStats {
+    #[serde(rename = "type")]
+    pub stat_type: DisplayName,
+    #[serde(default)]
+    pub splits: Vec<VsPlayerSplit>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VsPlayerSplit {
+    pub season: Option<String>,
+    pub stat: VsPlayerStat,
+    pub batter: Option<Player>,
+    pub pitcher: Option<Player>,
+}
+
+#[derive(Default, Debug, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct VsPlayerStat {
+    pub plate_appearances: u16,
+    pub at_bats: u16,
+    pub hits: u16,
+    pub doubles: u16,
+    pub triples: u16,
+    pub home_runs: u16,
+    pub base_on_balls: u16,
+    pub strike_outs: u16,
+    pub rbi: u16,
+    pub avg: Option<String>,
+    pub ops: Option<String>,
+}
+
+impl VsPlayerResponse {
+    /// Career totals against the pitcher, `None` if they have never faced each other.
+    pub fn career(&self) -> Option<&VsPlayerStat> {
+        self.splits("vsPlayerTotal").next()
+    }
+
+    /// Totals for a single season.
+    pub fn season(&self, season: i32) -> Option<&VsPlayerStat> {
+        let season = season.to_string();
+        self.stats
+            .iter()
+            .filter(|s| s.stat_type.display_name == "vsPlayer")
+            .flat_map(|s| &s.splits)
+            .find(|s| s.season.as_deref() == Some(&season))
+            .map(|s| &s.stat)
+    }
+
+    fn splits(&self, stat_type: &str) -> impl Iterator<Item = &VsPlayerStat> {
+        self.stats
+            .iter()
+            .filter(move |s| s.stat_type.display_name == stat_type)
+            .flat_map(|s| &s.splits)
+            .map(|s| &s.stat)
+    }
+}
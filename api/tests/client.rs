@@ -1,9 +1,16 @@
 use chrono::NaiveDate;
-use mlbt_api::client::{MLBApi, MLBApiBuilder, StatGroup};
-use mlbt_api::season::GameType;
+use mlbt_api::client::{ApiError, MLBApi, MLBApiBuilder, StatGroup};
+use mlbt_api::disk_cache::{DiskCache, DiskCacheTransport};
+use mlbt_api::leaders::{LeaderCategory, League};
+use mlbt_api::live::LiveFeed;
+use mlbt_api::retry::{RetryOn, RetryPolicy};
+use mlbt_api::schedule::{BroadcastType, DayNight, DoubleHeader};
+use mlbt_api::season::{GameType, SeasonInfo};
 use mlbt_api::team::RosterType;
 use mlbt_api::teams::SportId;
+use mlbt_api::transport::{RecordingTransport, ReplayTransport, ReqwestTransport};
 use mockito::{Matcher, ServerGuard};
+use std::sync::Arc;
 use std::time::Duration;
 
 async fn generate_mock_client() -> (MLBApi, ServerGuard) {
@@ -24,11 +31,34 @@ async fn generate_mock_client() -> (MLBApi, ServerGuard) {
     (client, server)
 }
 
+async fn generate_retrying_client(policy: RetryPolicy) -> (MLBApi, ServerGuard) {
+    let server = mockito::Server::new_async().await;
+    let client = MLBApiBuilder::default()
+        .base_url(format!("{}/", server.url()))
+        .retry(policy)
+        .build()
+        .unwrap();
+
+    (client, server)
+}
+
+/// Retry quickly and deterministically so tests don't sleep.
+fn fast_retry(max_attempts: u32) -> RetryPolicy {
+    RetryPolicy {
+        max_attempts,
+        initial_backoff: Duration::from_millis(1),
+        max_backoff: Duration::from_millis(10),
+        jitter: false,
+        ..RetryPolicy::default()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use chrono::{DateTime, Datelike, Local};
     use mlbt_api::client::Qualification;
+    use mlbt_api::stats::StatSplit;
 
     /// Test the schedule for the All Star Game 2021
     #[tokio::test]
@@ -38,7 +68,7 @@ mod tests {
         let m = server
             .mock(
                 "GET",
-                "/v1/schedule?sportId=1,51&hydrate=linescore,probablePitcher,stats,decisions&date=2021-07-13",
+                "/v1/schedule?sportId=1,51&hydrate=linescore,probablePitcher,stats,decisions,broadcasts(all)&date=2021-07-13",
             )
             .with_status(200)
             .with_header("content-type", "application/json;charset=UTF-8")
@@ -46,7 +76,7 @@ mod tests {
             .create();
 
         let date = NaiveDate::from_ymd_opt(2021, 7, 13).unwrap();
-        let resp = client.get_schedule_date(date).await.unwrap();
+        let resp = client.get_schedule_date(date, SportId::Mlb).await.unwrap();
         m.assert(); // assert mock was called
         assert_eq!(resp.total_games, 1);
     }
@@ -59,7 +89,7 @@ mod tests {
         let m = server
             .mock(
                 "GET",
-                "/v1/schedule?sportId=1,51&hydrate=linescore,probablePitcher,stats,decisions&date=2026-03-14",
+                "/v1/schedule?sportId=1,51&hydrate=linescore,probablePitcher,stats,decisions,broadcasts(all)&date=2026-03-14",
             )
             .with_status(200)
             .with_header("content-type", "application/json;charset=UTF-8")
@@ -67,7 +97,7 @@ mod tests {
             .create();
 
         let date = NaiveDate::from_ymd_opt(2026, 3, 14).unwrap();
-        let resp = client.get_schedule_date(date).await.unwrap();
+        let resp = client.get_schedule_date(date, SportId::Mlb).await.unwrap();
         m.assert();
         assert_eq!(resp.total_games, 19);
     }
@@ -80,7 +110,7 @@ mod tests {
         let m = server
             .mock(
                 "GET",
-                "/v1/schedule?sportId=1,51&hydrate=linescore,probablePitcher,stats,decisions&date=2026-04-20",
+                "/v1/schedule?sportId=1,51&hydrate=linescore,probablePitcher,stats,decisions,broadcasts(all)&date=2026-04-20",
             )
             .with_status(200)
             .with_header("content-type", "application/json;charset=UTF-8")
@@ -88,7 +118,7 @@ mod tests {
             .create();
 
         let date = NaiveDate::from_ymd_opt(2026, 4, 20).unwrap();
-        let resp = client.get_schedule_date(date).await.unwrap();
+        let resp = client.get_schedule_date(date, SportId::Mlb).await.unwrap();
         m.assert(); // assert mock was called
         assert_eq!(resp.total_games, 10);
 
@@ -111,6 +141,15 @@ mod tests {
             .winner;
         assert_eq!(winner.full_name, "Garrett Whitlock");
         assert_eq!(winner.stats.len(), 4);
+
+        // Verify the broadcasts for the first game
+        let broadcasts = &first_game.broadcasts;
+        assert_eq!(broadcasts.len(), 5);
+        assert_eq!(broadcasts[1].name, "NESN");
+        assert_eq!(broadcasts[1].broadcast_type, Some(BroadcastType::TV));
+        assert_eq!(broadcasts[1].home_away.as_deref(), Some("home"));
+        assert_eq!(broadcasts[3].language.as_deref(), Some("es"));
+        assert!(broadcasts[3].broadcast_type.unwrap().is_radio());
     }
 
     #[tokio::test]
@@ -129,13 +168,73 @@ mod tests {
             .create();
 
         let resp = client
-            .get_standings(date, GameType::RegularSeason)
+            .get_standings(date, GameType::RegularSeason, SportId::Mlb)
             .await
             .unwrap();
         m.assert(); // assert mock was called
         assert_ne!(resp.records.len(), 0);
     }
 
+    /// Minor league levels pass their own sport id, and standings use that level's leagues.
+    #[tokio::test]
+    async fn test_minor_league_urls() {
+        let (client, mut server) = generate_mock_client().await;
+        let date: NaiveDate = NaiveDate::from_ymd_opt(2026, 4, 20).unwrap();
+        let group = StatGroup::Hitting;
+
+        let schedule = server
+            .mock(
+                "GET",
+                "/v1/schedule?sportId=11&hydrate=linescore,probablePitcher,stats,decisions,broadcasts(all)&date=2026-04-20",
+            )
+            .with_status(200)
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body_from_file("./tests/responses/schedule-by-date.json")
+            .create();
+        let standings = server
+            .mock(
+                "GET",
+                "/v1/standings?sportId=11&season=2026&date=2026-04-20&leagueId=117,112&hydrate=team,division",
+            )
+            .with_status(200)
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body_from_file("./tests/responses/standings.json")
+            .create();
+        let url = format!(
+            "/v1/stats?sportId=11&stats=season,sabermetrics,expectedStatistics&season=2026&group=hitting&limit=3000&sortStat={}&order=desc&gameType=S&playerPool=all",
+            group.default_sort_stat()
+        );
+        let stats = server
+            .mock("GET", Matcher::Exact(url))
+            .with_status(200)
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body_from_file("./tests/responses/player-stats-hitting-date.json")
+            .create();
+
+        client
+            .get_schedule_date(date, SportId::TripleA)
+            .await
+            .unwrap();
+        // spring training standings are MLB only, so the regular season is used instead
+        client
+            .get_standings(date, GameType::SpringTraining, SportId::TripleA)
+            .await
+            .unwrap();
+        client
+            .get_player_stats_on_date(
+                group,
+                Qualification::All,
+                date,
+                GameType::SpringTraining,
+                SportId::TripleA,
+            )
+            .await
+            .unwrap();
+        schedule.assert();
+        standings.assert();
+        stats.assert();
+    }
+
     #[tokio::test]
     async fn test_live() {
         let (client, mut server) = generate_mock_client().await;
@@ -152,6 +251,113 @@ mod tests {
         let resp = client.get_live_data(game_id).await.unwrap();
         m.assert(); // assert mock was called
         assert_eq!(resp.game_pk, game_id);
+
+        let pitch = resp.live_data.plays.all_plays.as_ref().unwrap()[0]
+            .play_events
+            .iter()
+            .find_map(|e| e.pitch_data.as_ref())
+            .unwrap();
+        let coordinates = &pitch.coordinates;
+        assert_eq!(coordinates.p_x, Some(-0.2466830552839107));
+        assert_eq!(coordinates.pfx_z, Some(10.808875495709458));
+        assert_eq!(coordinates.v_x0, Some(4.403276965871634));
+        assert_eq!(coordinates.z0, Some(6.182877870209927));
+        assert_eq!(pitch.extension, Some(6.554365548546379));
+        // extrapolated from 50 feet back to 53.9 feet, so wider and higher than x0, z0
+        let (x, z) = pitch.release_point().unwrap();
+        assert!((x - -1.41).abs() < 0.01, "{x}");
+        assert!((z - 6.32).abs() < 0.01, "{z}");
+        let breaks = pitch.breaks.as_ref().unwrap();
+        assert_eq!(breaks.break_vertical_induced, Some(18.7));
+        assert_eq!(breaks.break_horizontal, Some(8.0));
+        assert_eq!(breaks.spin_direction, Some(204));
+
+        let game_data = &resp.game_data;
+        let weather = game_data.weather.as_ref().unwrap();
+        assert_eq!(weather.temp.as_deref(), Some("59"));
+        assert_eq!(weather.wind.as_deref(), Some("11 mph, In From RF"));
+        let venue = game_data.venue.as_ref().unwrap();
+        assert_eq!(venue.name, "Wrigley Field");
+        let field = venue.field_info.as_ref().unwrap();
+        assert_eq!(field.roof_type.as_deref(), Some("Open"));
+        assert_eq!(field.center, Some(400));
+        let info = game_data.game_info.as_ref().unwrap();
+        assert_eq!(info.attendance, Some(40179));
+        assert_eq!(info.game_duration_minutes, Some(160));
+        assert_eq!(
+            info.first_pitch.unwrap().to_rfc3339(),
+            "2025-06-01T18:20:00+00:00"
+        );
+
+        let officials = &resp.live_data.boxscore.officials;
+        assert_eq!(officials.len(), 4);
+        assert_eq!(officials[0].official_type, "Home Plate");
+        assert_eq!(officials[0].official.full_name, "Brian O'Nora");
+    }
+
+    #[tokio::test]
+    async fn test_game_content() {
+        let (client, mut server) = generate_mock_client().await;
+
+        let game_id = 777687;
+        let url = format!(
+            "/v1/game/{game_id}/content?fields=editorial&fields=preview&fields=recap&fields=wrap&fields=articles&fields=items&fields=mlb&fields=home&fields=away&fields=headline&fields=subhead&fields=blurb&fields=body"
+        );
+        let m = server
+            .mock("GET", Matcher::Exact(url))
+            .with_status(200)
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body_from_file("./tests/responses/game-content.json")
+            .create();
+
+        let resp = client.get_game_content(game_id).await.unwrap();
+        m.assert();
+
+        // the league recap is preferred over the team ones
+        let recap = resp.recap().unwrap();
+        assert_eq!(recap.headline, "Judge's 2 HRs power Yankees past Red Sox");
+        assert!(recap.body.as_ref().unwrap().starts_with("<p>NEW YORK"));
+
+        let preview = resp.preview().unwrap();
+        assert_eq!(
+            preview.subhead.as_deref(),
+            Some("Right-hander looks to build on his last start")
+        );
+
+        // an empty wrap has no article
+        assert!(resp.wrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_play_by_play() {
+        let (client, mut server) = generate_mock_client().await;
+
+        let game_id = 777687;
+        let url = format!("/v1/game/{game_id}/playByPlay");
+        let m = server
+            .mock("GET", Matcher::Exact(url))
+            .with_status(200)
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body_from_file("./tests/responses/play-by-play.json")
+            .create();
+
+        let resp = client.get_play_by_play(game_id).await.unwrap();
+        m.assert();
+        let plays = resp.all_plays.unwrap();
+        assert_eq!(plays.len(), 8);
+
+        // balls in play have the coordinates, strikeouts have no hit data at all
+        let coordinates = |index: usize| {
+            plays[index]
+                .play_events
+                .iter()
+                .find_map(|e| e.hit_data.as_ref())
+                .and_then(|h| h.coordinates)
+        };
+        assert!(coordinates(0).is_none());
+        let single = coordinates(2).unwrap();
+        assert_eq!(single.coord_x, Some(175.92));
+        assert_eq!(single.coord_y, Some(105.36));
     }
 
     #[tokio::test]
@@ -174,7 +380,7 @@ mod tests {
                 .create();
 
             let resp = client
-                .get_team_stats(group, GameType::RegularSeason)
+                .get_team_stats(group, GameType::RegularSeason, SportId::Mlb)
                 .await
                 .unwrap();
             m.assert(); // assert mock was called
@@ -204,7 +410,7 @@ mod tests {
                 .create();
 
             let resp = client
-                .get_team_stats_on_date(group, date, GameType::RegularSeason)
+                .get_team_stats_on_date(group, date, GameType::RegularSeason, SportId::Mlb)
                 .await
                 .unwrap();
             m.assert(); // assert mock was called
@@ -220,7 +426,7 @@ mod tests {
         let local: DateTime<Local> = Local::now();
         for group in [StatGroup::Hitting, StatGroup::Pitching] {
             let url = format!(
-                "/v1/stats?sportId=1&stats=season&season={}&group={}&limit=3000&sortStat={}&order=desc&playerPool=all",
+                "/v1/stats?sportId=1&stats=season,sabermetrics,expectedStatistics&season={}&group={}&limit=3000&sortStat={}&order=desc&playerPool=all",
                 local.year(),
                 group,
                 group.default_sort_stat()
@@ -234,7 +440,12 @@ mod tests {
                 .create();
 
             let resp = client
-                .get_player_stats(group, Qualification::All, GameType::RegularSeason)
+                .get_player_stats(
+                    group,
+                    Qualification::All,
+                    GameType::RegularSeason,
+                    SportId::Mlb,
+                )
                 .await
                 .unwrap();
             m.assert(); // assert mock was called
@@ -243,6 +454,109 @@ mod tests {
         }
     }
 
+    /// The sabermetrics and expected stat types come back as separate stats next to the season
+    /// stats and deserialize into their own `StatSplit` variants.
+    #[tokio::test]
+    async fn test_player_stats_advanced() {
+        let (client, mut server) = generate_mock_client().await;
+
+        let local: DateTime<Local> = Local::now();
+        let group = StatGroup::Hitting;
+        let url = format!(
+            "/v1/stats?sportId=1&stats=season,sabermetrics,expectedStatistics&season={}&group={}&limit=3000&sortStat={}&order=desc&playerPool=all",
+            local.year(),
+            group,
+            group.default_sort_stat()
+        );
+        let m = server
+            .mock("GET", Matcher::Exact(url))
+            .with_status(200)
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body_from_file("./tests/responses/player-stats-advanced.json")
+            .create();
+
+        let resp = client
+            .get_player_stats(
+                group,
+                Qualification::All,
+                GameType::RegularSeason,
+                SportId::Mlb,
+            )
+            .await
+            .unwrap();
+        m.assert();
+        assert_eq!(resp.stats.len(), 3);
+        assert!(
+            resp.stats[0]
+                .splits
+                .iter()
+                .all(|s| matches!(s.stat, StatSplit::Hitting(_)))
+        );
+        match &resp.stats[1].splits[0].stat {
+            StatSplit::Sabermetrics(s) => {
+                assert_eq!(s.war, 7.0);
+                assert_eq!(s.w_rc_plus, Some(163.9));
+                assert_eq!(s.fip, None);
+            }
+            other => panic!("expected a sabermetrics split, got {other:?}"),
+        }
+        match &resp.stats[2].splits[0].stat {
+            StatSplit::Expected(s) => {
+                assert_eq!(s.woba, ".425");
+                assert_eq!(s.woba_con.as_deref(), Some(".451"));
+            }
+            other => panic!("expected an expectedStatistics split, got {other:?}"),
+        }
+    }
+
+    /// Fielding, catching and running splits deserialize into their own `StatSplit` variants.
+    #[tokio::test]
+    async fn test_player_stats_other_groups() {
+        let (client, mut server) = generate_mock_client().await;
+
+        let local: DateTime<Local> = Local::now();
+        for group in [StatGroup::Fielding, StatGroup::Catching, StatGroup::Running] {
+            let url = format!(
+                "/v1/stats?sportId=1&stats=season&season={}&group={}&limit=3000&sortStat={}&order=desc&playerPool=all",
+                local.year(),
+                group,
+                group.default_sort_stat()
+            );
+
+            let m = server
+                .mock("GET", Matcher::Exact(url))
+                .with_status(200)
+                .with_header("content-type", "application/json;charset=UTF-8")
+                .with_body_from_file(format!("./tests/responses/player-stats-{group}.json"))
+                .create();
+
+            let resp = client
+                .get_player_stats(
+                    group,
+                    Qualification::All,
+                    GameType::RegularSeason,
+                    SportId::Mlb,
+                )
+                .await
+                .unwrap();
+            m.assert();
+            assert_eq!(resp.stats[0].group.display_name, group.to_string());
+
+            let splits = &resp.stats[0].splits;
+            assert!(!splits.is_empty());
+            for split in splits {
+                match (group, &split.stat) {
+                    (StatGroup::Fielding, StatSplit::Fielding(s)) => {
+                        assert!(s.position.is_some())
+                    }
+                    (StatGroup::Catching, StatSplit::Catching(_)) => {}
+                    (StatGroup::Running, StatSplit::Running(_)) => {}
+                    (group, stat) => panic!("{group} split parsed as {stat:?}"),
+                }
+            }
+        }
+    }
+
     #[tokio::test]
     async fn test_player_stats_on_date_past_season() {
         let (client, mut server) = generate_mock_client().await;
@@ -250,7 +564,7 @@ mod tests {
 
         for group in [StatGroup::Hitting, StatGroup::Pitching] {
             let url = format!(
-                "/v1/stats?sportId=1&stats=season&season={}&group={}&limit=3000&sortStat={}&order=desc&playerPool=all",
+                "/v1/stats?sportId=1&stats=season,sabermetrics,expectedStatistics&season={}&group={}&limit=3000&sortStat={}&order=desc&playerPool=all",
                 date.year(),
                 group,
                 group.default_sort_stat()
@@ -264,7 +578,13 @@ mod tests {
                 .create();
 
             let resp = client
-                .get_player_stats_on_date(group, Qualification::All, date, GameType::RegularSeason)
+                .get_player_stats_on_date(
+                    group,
+                    Qualification::All,
+                    date,
+                    GameType::RegularSeason,
+                    SportId::Mlb,
+                )
                 .await
                 .unwrap();
             m.assert(); // assert mock was called
@@ -281,7 +601,7 @@ mod tests {
 
         for group in [StatGroup::Hitting, StatGroup::Pitching] {
             let url = format!(
-                "/v1/stats?sportId=1&stats=byDateRange&season={}&endDate={}&group={}&limit=3000&sortStat={}&order=desc&playerPool=all",
+                "/v1/stats?sportId=1&stats=byDateRange,sabermetrics,expectedStatistics&season={}&endDate={}&group={}&limit=3000&sortStat={}&order=desc&playerPool=all",
                 date.year(),
                 date.format("%Y-%m-%d"),
                 group,
@@ -296,7 +616,13 @@ mod tests {
                 .create();
 
             let resp = client
-                .get_player_stats_on_date(group, Qualification::All, date, GameType::RegularSeason)
+                .get_player_stats_on_date(
+                    group,
+                    Qualification::All,
+                    date,
+                    GameType::RegularSeason,
+                    SportId::Mlb,
+                )
                 .await
                 .unwrap();
             m.assert(); // assert mock was called
@@ -321,7 +647,7 @@ mod tests {
             .create();
 
         let resp = client
-            .get_standings(date, GameType::SpringTraining)
+            .get_standings(date, GameType::SpringTraining, SportId::Mlb)
             .await
             .unwrap();
         m.assert();
@@ -345,6 +671,16 @@ mod tests {
             info.regular_season_start_date,
             NaiveDate::from_ymd_opt(2026, 3, 25).unwrap()
         );
+        assert_eq!(
+            info.post_season_start_date,
+            NaiveDate::from_ymd_opt(2026, 9, 28)
+        );
+        assert_eq!(
+            info.post_season_end_date,
+            NaiveDate::from_ymd_opt(2026, 10, 31)
+        );
+        assert_eq!(info.spring_end_date, NaiveDate::from_ymd_opt(2026, 3, 24));
+        assert_eq!(info.all_star_date, NaiveDate::from_ymd_opt(2026, 7, 14));
     }
 
     #[tokio::test]
@@ -368,7 +704,7 @@ mod tests {
                 .create();
 
             let resp = client
-                .get_team_stats_on_date(group, date, GameType::SpringTraining)
+                .get_team_stats_on_date(group, date, GameType::SpringTraining, SportId::Mlb)
                 .await
                 .unwrap();
             m.assert();
@@ -413,7 +749,7 @@ mod tests {
 
         for group in [StatGroup::Hitting, StatGroup::Pitching] {
             let url = format!(
-                "/v1/stats?sportId=1&stats=season&season={}&group={}&limit=3000&sortStat={}&order=desc&gameType=S&playerPool=all",
+                "/v1/stats?sportId=1&stats=season,sabermetrics,expectedStatistics&season={}&group={}&limit=3000&sortStat={}&order=desc&gameType=S&playerPool=all",
                 date.year(),
                 group,
                 group.default_sort_stat()
@@ -427,7 +763,13 @@ mod tests {
                 .create();
 
             let resp = client
-                .get_player_stats_on_date(group, Qualification::All, date, GameType::SpringTraining)
+                .get_player_stats_on_date(
+                    group,
+                    Qualification::All,
+                    date,
+                    GameType::SpringTraining,
+                    SportId::Mlb,
+                )
                 .await
                 .unwrap();
             m.assert();
@@ -435,6 +777,227 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_player_stats_postseason_url() {
+        let (client, mut server) = generate_mock_client().await;
+        let date: NaiveDate = NaiveDate::from_ymd_opt(2024, 10, 20).unwrap();
+        let group = StatGroup::Hitting;
+
+        let url = format!(
+            "/v1/stats?sportId=1&stats=season,sabermetrics,expectedStatistics&season=2024&group=hitting&limit=3000&sortStat={}&order=desc&gameType=F,D,L,W&playerPool=all",
+            group.default_sort_stat()
+        );
+        let m = server
+            .mock("GET", Matcher::Exact(url))
+            .with_status(200)
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body_from_file("./tests/responses/player-stats-hitting-date.json")
+            .create();
+
+        client
+            .get_player_stats_on_date(
+                group,
+                Qualification::All,
+                date,
+                GameType::WorldSeries,
+                SportId::Mlb,
+            )
+            .await
+            .unwrap();
+        m.assert();
+    }
+
+    #[tokio::test]
+    async fn test_league_leaders() {
+        let (client, mut server) = generate_mock_client().await;
+
+        let m = server
+            .mock(
+                "GET",
+                Matcher::Exact(
+                    "/v1/stats/leaders?leaderCategories=homeRuns,strikeouts,battingAverage&statGroup=hitting,pitching&season=2024&sportId=1&limit=3&leagueId=103"
+                        .to_string(),
+                ),
+            )
+            .with_status(200)
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body_from_file("./tests/responses/league-leaders.json")
+            .create();
+
+        let categories = [
+            LeaderCategory::HomeRuns,
+            LeaderCategory::Strikeouts,
+            LeaderCategory::BattingAverage,
+        ];
+        let resp = client
+            .get_league_leaders(
+                &categories,
+                2024,
+                GameType::RegularSeason,
+                SportId::Mlb,
+                Some(League::American),
+                3,
+            )
+            .await
+            .unwrap();
+        m.assert();
+
+        let home_runs = resp.category(LeaderCategory::HomeRuns).unwrap();
+        assert_eq!(home_runs.leaders[0].person.full_name, "Aaron Judge");
+        assert_eq!(home_runs.leaders[0].value, "58");
+        // strikeouts are returned for both groups, the category is the pitching one
+        let strikeouts = resp.category(LeaderCategory::Strikeouts).unwrap();
+        assert_eq!(strikeouts.leaders[0].person.full_name, "Tarik Skubal");
+        assert!(resp.category(LeaderCategory::Saves).is_none());
+    }
+
+    #[tokio::test]
+    async fn test_league_leaders_spring_training_url() {
+        let (client, mut server) = generate_mock_client().await;
+
+        let m = server
+            .mock(
+                "GET",
+                Matcher::Exact(
+                    "/v1/stats/leaders?leaderCategories=homeRuns&statGroup=hitting&season=2026&sportId=11&limit=3&gameType=S"
+                        .to_string(),
+                ),
+            )
+            .with_status(200)
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body_from_file("./tests/responses/league-leaders.json")
+            .create();
+
+        client
+            .get_league_leaders(
+                &[LeaderCategory::HomeRuns],
+                2026,
+                GameType::SpringTraining,
+                SportId::TripleA,
+                None,
+                3,
+            )
+            .await
+            .unwrap();
+        m.assert();
+    }
+
+    #[tokio::test]
+    async fn test_vs_player() {
+        let (client, mut server) = generate_mock_client().await;
+
+        let m = server
+            .mock(
+                "GET",
+                Matcher::Exact(
+                    "/v1/people/592450/stats?stats=vsPlayer&group=hitting&opposingPlayerId=605483&season=2026&sportId=1"
+                        .to_string(),
+                ),
+            )
+            .with_status(200)
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body_from_file("./tests/responses/vs-player.json")
+            .create();
+
+        let resp = client.get_vs_player(592450, 605483, 2026).await.unwrap();
+        m.assert();
+        let career = resp.career().unwrap();
+        assert_eq!((career.hits, career.at_bats), (4, 11));
+        assert_eq!((career.home_runs, career.strike_outs), (2, 3));
+        let season = resp.season(2026).unwrap();
+        assert_eq!((season.hits, season.at_bats, season.home_runs), (1, 3, 0));
+        assert!(resp.season(2024).is_none());
+    }
+
+    #[tokio::test]
+    async fn test_search_people() {
+        let (client, mut server) = generate_mock_client().await;
+
+        let m = server
+            .mock(
+                "GET",
+                Matcher::Exact("/v1/people/search?names=judge&hydrate=currentTeam".to_string()),
+            )
+            .with_status(200)
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body_from_file("./tests/responses/people-search.json")
+            .create();
+        let active = server
+            .mock(
+                "GET",
+                Matcher::Exact(
+                    "/v1/people/search?names=aaron%20judge&hydrate=currentTeam&active=true"
+                        .to_string(),
+                ),
+            )
+            .with_status(200)
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body_from_file("./tests/responses/people-search.json")
+            .create();
+
+        let resp = client.search_people("judge", false).await.unwrap();
+        m.assert();
+        assert_eq!(resp.people.len(), 2);
+        assert_eq!(
+            resp.people[0].current_team.as_ref().unwrap().name,
+            "New York Yankees"
+        );
+        // retired players have no current team
+        assert_eq!(resp.people[1].active, Some(false));
+        assert!(resp.people[1].current_team.is_none());
+
+        client.search_people(" aaron judge ", true).await.unwrap();
+        active.assert();
+    }
+
+    #[tokio::test]
+    async fn test_postseason_series() {
+        let (client, mut server) = generate_mock_client().await;
+
+        let m = server
+            .mock(
+                "GET",
+                "/v1/schedule/postseason/series?sportId=1&season=2024",
+            )
+            .with_status(200)
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body_from_file("./tests/responses/postseason-series.json")
+            .create();
+
+        let resp = client.get_postseason_series(2024).await.unwrap();
+        m.assert();
+        assert_eq!(resp.series.len(), 2);
+        assert_eq!(resp.series[0].series.id, "F_1");
+        let world_series = &resp.series[1].games;
+        assert_eq!(world_series.len(), 5);
+        assert!(
+            world_series
+                .iter()
+                .all(|g| g.game_type == Some(GameType::WorldSeries))
+        );
+
+        let mut info = SeasonInfo {
+            regular_season_start_date: NaiveDate::from_ymd_opt(2024, 3, 20).unwrap(),
+            post_season_start_date: NaiveDate::from_ymd_opt(2024, 10, 1),
+            post_season_end_date: NaiveDate::from_ymd_opt(2024, 10, 31),
+            ..SeasonInfo::default()
+        };
+        info.set_postseason_rounds(&resp);
+        assert_eq!(
+            info.postseason_rounds,
+            vec![
+                (
+                    GameType::WildCard,
+                    NaiveDate::from_ymd_opt(2024, 10, 1).unwrap()
+                ),
+                (
+                    GameType::WorldSeries,
+                    NaiveDate::from_ymd_opt(2024, 10, 25).unwrap()
+                ),
+            ]
+        );
+    }
+
     #[tokio::test]
     async fn test_team_schedule() {
         let (client, mut server) = generate_mock_client().await;
@@ -453,6 +1016,27 @@ mod tests {
         m.assert();
         assert_eq!(resp.total_games, 194);
         assert!(!resp.dates.is_empty());
+
+        // the second game of a split doubleheader that made up a postponed game
+        let makeup = resp
+            .dates
+            .iter()
+            .flat_map(|d| d.games.iter().flatten())
+            .find(|g| g.game_pk == 777464 && g.double_header == Some(DoubleHeader::S))
+            .unwrap();
+        assert_eq!(makeup.game_number, Some(2));
+        assert_eq!(makeup.day_night, Some(DayNight::Night));
+        assert_eq!(
+            (makeup.series_game_number, makeup.games_in_series),
+            (Some(3), Some(3))
+        );
+        assert_eq!(makeup.series_description.as_deref(), Some("Regular Season"));
+        assert_eq!(
+            makeup.rescheduled_from_date,
+            NaiveDate::from_ymd_opt(2025, 6, 18)
+        );
+        assert_eq!(makeup.description.as_deref(), Some("Makeup of 6/18 ppd"));
+        assert!(makeup.resume_game_date.is_none());
     }
 
     #[tokio::test]
@@ -505,6 +1089,76 @@ mod tests {
         assert!(!resp.roster.is_empty());
     }
 
+    #[tokio::test]
+    async fn test_team_roster_depth_chart() {
+        let (client, mut server) = generate_mock_client().await;
+
+        let m = server
+            .mock(
+                "GET",
+                Matcher::Exact(
+                    "/v1/teams/134/roster/depthChart?season=2025&hydrate=person".to_string(),
+                ),
+            )
+            .with_status(200)
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body_from_file("./tests/responses/team-roster-depth-chart.json")
+            .create();
+
+        let resp = client
+            .get_team_roster(134, 2025, RosterType::DepthChart)
+            .await
+            .unwrap();
+        m.assert();
+        let positions: Vec<&str> = resp
+            .roster
+            .iter()
+            .map(|e| e.position.abbreviation.as_str())
+            .collect();
+        assert_eq!(&positions[..5], ["SP", "SP", "SP", "CP", "C"]);
+        // a utility player is listed at each position they play
+        let cheng: Vec<&str> = resp
+            .roster
+            .iter()
+            .filter(|e| e.person.full_name == "Tsung-Che Cheng")
+            .map(|e| e.position.abbreviation.as_str())
+            .collect();
+        assert_eq!(cheng, ["2B", "SS"]);
+    }
+
+    #[tokio::test]
+    async fn test_draft() {
+        let (client, mut server) = generate_mock_client().await;
+
+        let m = server
+            .mock("GET", Matcher::Exact("/v1/draft/2024".to_string()))
+            .with_status(200)
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body_from_file("./tests/responses/draft.json")
+            .create();
+
+        let resp = client.get_draft(2024).await.unwrap();
+        m.assert();
+        let draft = resp.drafts;
+        assert_eq!(draft.draft_year, 2024);
+        let rounds: Vec<&str> = draft.rounds.iter().map(|r| r.round.as_str()).collect();
+        assert_eq!(rounds, ["1", "C-1", "2"]);
+
+        let first = &draft.rounds[0].picks[0];
+        assert_eq!(first.pick_number, 1);
+        assert_eq!(first.team.id, 114);
+        let person = first.person.as_ref().unwrap();
+        assert_eq!(person.full_name, "Travis Bazzana");
+        assert_eq!(person.primary_position.as_ref().unwrap().abbreviation, "2B");
+        assert_eq!(first.school.as_ref().unwrap().name, "Oregon State");
+        assert_eq!(first.signing_bonus.as_deref(), Some("8950000"));
+
+        // a pick that hasn't been made has no person
+        let unmade = &draft.rounds[2].picks[0];
+        assert!(unmade.person.is_none());
+        assert!(!unmade.is_drafted);
+    }
+
     #[tokio::test]
     async fn test_team_transactions() {
         let (client, mut server) = generate_mock_client().await;
@@ -538,9 +1192,12 @@ mod tests {
         let (client, mut server) = generate_mock_client().await;
 
         for group in [StatGroup::Hitting, StatGroup::Pitching] {
+            let types = match group {
+                StatGroup::Pitching => "season,yearByYear,career,gameLog,pitchArsenal",
+                _ => "season,yearByYear,career,gameLog",
+            };
             let url = format!(
-                "/v1/people/660271?hydrate=currentTeam,draft,stats(group=[{}],type=[season,yearByYear,career,gameLog],season=2025)",
-                group
+                "/v1/people/660271?hydrate=currentTeam,draft,stats(group=[{group}],type=[{types}],season=2025)"
             );
             let m = server
                 .mock("GET", Matcher::Exact(url))
@@ -559,11 +1216,462 @@ mod tests {
             if group == StatGroup::Pitching {
                 assert_eq!(person.full_name, "Shohei Ohtani");
                 assert!(person.drafts.as_ref().is_none());
+                assert_eq!(person.stats.len(), 5);
+                let arsenal = person
+                    .stats
+                    .iter()
+                    .find(|s| s.stat_type.display_name == "pitchArsenal")
+                    .unwrap();
+                let StatSplit::PitchArsenal(pitch) = &arsenal.splits[0].stat else {
+                    panic!("expected a pitch arsenal split");
+                };
+                assert_eq!(pitch.pitch_type.code, "FF");
+                assert_eq!(pitch.average_spin_rate, Some(2397.0));
             } else {
                 assert_eq!(person.full_name, "Paul Goldschmidt");
                 assert_eq!(person.drafts.as_ref().unwrap().len(), 2);
+                assert_eq!(person.stats.len(), 4);
             }
-            assert_eq!(person.stats.len(), 4);
         }
     }
+
+    #[tokio::test]
+    async fn test_error_status_variants() {
+        let (client, mut server) = generate_mock_client().await;
+        let url = "/v1.1/game/1/feed/live?language=en";
+
+        for (status, summary) in [
+            (400, "bad request"),
+            (404, "not found"),
+            (429, "rate limited"),
+            (403, "error 403"),
+            (503, "server error 503"),
+        ] {
+            let m = server
+                .mock("GET", url)
+                .with_status(status)
+                .with_body(r#"{"message": "Object not found"}"#)
+                .create();
+
+            let err = client.get_live_data(1).await.unwrap_err();
+            m.assert();
+            m.remove();
+            assert_eq!(err.status(), Some(status as u16));
+            assert_eq!(err.summary(), summary);
+            assert!(err.url().ends_with(url));
+            match (status, &err) {
+                (400, ApiError::BadRequest { body, .. })
+                | (404, ApiError::NotFound { body, .. })
+                | (429, ApiError::RateLimited { body, .. })
+                | (403, ApiError::ClientError { body, .. })
+                | (503, ApiError::ServerError { body, .. }) => {
+                    assert!(body.contains("Object not found"))
+                }
+                _ => panic!("unexpected error for {status}: {err:?}"),
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_error_body_is_truncated() {
+        let (client, mut server) = generate_mock_client().await;
+
+        let m = server
+            .mock("GET", "/v1.1/game/1/feed/live?language=en")
+            .with_status(500)
+            .with_body("x".repeat(10_000))
+            .create();
+
+        let err = client.get_live_data(1).await.unwrap_err();
+        m.assert();
+        let ApiError::ServerError { status, body, .. } = err else {
+            panic!("expected a server error, got {err:?}");
+        };
+        assert_eq!(status, 500);
+        assert!(body.len() < 10_000);
+    }
+
+    #[tokio::test]
+    async fn test_lenient_client_errors_returns_default() {
+        let mut server = mockito::Server::new_async().await;
+        let client = MLBApiBuilder::default()
+            .base_url(format!("{}/", server.url()))
+            .lenient_client_errors(true)
+            .build()
+            .unwrap();
+
+        let m = server
+            .mock("GET", "/v1.1/game/1/feed/live?language=en")
+            .with_status(404)
+            .create();
+        let resp = client.get_live_data(1).await.unwrap();
+        m.assert();
+        assert_eq!(resp.game_pk, 0);
+
+        // server errors are still reported
+        let m = server
+            .mock("GET", "/v1.1/game/2/feed/live?language=en")
+            .with_status(500)
+            .create();
+        let err = client.get_live_data(2).await.unwrap_err();
+        m.assert();
+        assert_eq!(err.status(), Some(500));
+    }
+
+    #[tokio::test]
+    async fn test_decode_error_reports_path() {
+        let (client, mut server) = generate_mock_client().await;
+
+        let m = server
+            .mock("GET", "/v1/seasons/2026?sportId=1")
+            .with_status(200)
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body(r#"{"seasons": [{"regularSeasonStartDate": "not a date"}]}"#)
+            .create();
+
+        let err = client.get_season_info(2026).await.unwrap_err();
+        m.assert();
+        let ApiError::Decode { path, .. } = err else {
+            panic!("expected a decode error, got {err:?}");
+        };
+        assert_eq!(path, "seasons[0].regularSeasonStartDate");
+    }
+
+    #[tokio::test]
+    async fn test_timeout() {
+        // accept connections but never respond
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        std::thread::spawn(move || {
+            let mut sockets = Vec::new();
+            while let Ok((socket, _)) = listener.accept() {
+                sockets.push(socket);
+            }
+        });
+
+        let client = MLBApiBuilder::default()
+            .base_url(format!("http://{addr}/"))
+            .timeout(Duration::from_millis(100))
+            .build()
+            .unwrap();
+
+        let err = client.get_season_info(2026).await.unwrap_err();
+        assert!(matches!(err, ApiError::Timeout { .. }), "{err:?}");
+    }
+
+    #[tokio::test]
+    async fn test_retry_staged_server_errors() {
+        let (client, mut server) = generate_retrying_client(fast_retry(3)).await;
+        let url = "/v1/seasons/2026?sportId=1";
+
+        // mockito serves the first mock that hasn't been hit its expected number of times
+        let first = server.mock("GET", url).with_status(500).expect(1).create();
+        let second = server.mock("GET", url).with_status(503).expect(1).create();
+        let ok = server
+            .mock("GET", url)
+            .with_status(200)
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body_from_file("./tests/responses/season-info.json")
+            .expect(1)
+            .create();
+
+        let resp = client.get_season_info(2026).await;
+        first.assert();
+        second.assert();
+        ok.assert();
+        assert!(resp.is_ok(), "{resp:?}");
+    }
+
+    #[tokio::test]
+    async fn test_retry_gives_up_after_max_attempts() {
+        let (client, mut server) = generate_retrying_client(fast_retry(3)).await;
+
+        let m = server
+            .mock("GET", "/v1.1/game/1/feed/live?language=en")
+            .with_status(503)
+            .expect(3)
+            .create();
+
+        let err = client.get_live_data(1).await.unwrap_err();
+        m.assert();
+        assert_eq!(err.status(), Some(503));
+    }
+
+    #[tokio::test]
+    async fn test_retry_only_configured_errors() {
+        let policy = RetryPolicy {
+            retry_on: vec![RetryOn::RateLimited],
+            ..fast_retry(3)
+        };
+        let (client, mut server) = generate_retrying_client(policy).await;
+
+        let m = server
+            .mock("GET", "/v1.1/game/1/feed/live?language=en")
+            .with_status(500)
+            .expect(1)
+            .create();
+        client.get_live_data(1).await.unwrap_err();
+        m.assert();
+
+        // client errors are never retried
+        let m = server
+            .mock("GET", "/v1.1/game/2/feed/live?language=en")
+            .with_status(404)
+            .expect(1)
+            .create();
+        client.get_live_data(2).await.unwrap_err();
+        m.assert();
+    }
+
+    #[tokio::test]
+    async fn test_retry_after_header() {
+        let (client, mut server) = generate_retrying_client(fast_retry(3)).await;
+        let url = "/v1.1/game/1/feed/live?language=en";
+
+        let limited = server
+            .mock("GET", url)
+            .with_status(429)
+            .with_header("retry-after", "0")
+            .expect(1)
+            .create();
+        let ok = server
+            .mock("GET", url)
+            .with_status(200)
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body_from_file("./tests/responses/live.json")
+            .expect(1)
+            .create();
+        let resp = client.get_live_data(1).await;
+        limited.assert();
+        ok.assert();
+        assert!(resp.is_ok(), "{resp:?}");
+
+        // asking for a longer wait than max_backoff returns the error instead of blocking
+        let url = "/v1.1/game/2/feed/live?language=en";
+        let m = server
+            .mock("GET", url)
+            .with_status(503)
+            .with_header("retry-after", "120")
+            .expect(1)
+            .create();
+        let err = client.get_live_data(2).await.unwrap_err();
+        m.assert();
+        assert_eq!(err.retry_after(), Some(Duration::from_secs(120)));
+    }
+
+    async fn mock_live_feed(client: &MLBApi, server: &mut ServerGuard) -> LiveFeed {
+        let m = server
+            .mock("GET", "/v1.1/game/777/feed/live?language=en")
+            .with_status(200)
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body_from_file("./tests/responses/live.json")
+            .create();
+        let feed = client.get_live_feed(777).await.unwrap();
+        m.assert();
+        feed
+    }
+
+    const LIVE_DIFF_URL: &str =
+        "/v1.1/game/777/feed/live/diffPatch?language=en&startTimecode=20250601_210028";
+
+    #[tokio::test]
+    async fn test_live_diff_applies_patches() {
+        let (client, mut server) = generate_mock_client().await;
+        let cached = mock_live_feed(&client, &mut server).await;
+        assert_eq!(cached.time_stamp(), "20250601_210028");
+
+        let m = server
+            .mock("GET", LIVE_DIFF_URL)
+            .with_status(200)
+            .with_body(
+                r#"[
+                    {"diff": [{"op": "replace", "path": "/liveData/linescore/outs", "value": 1}]},
+                    {"diff": [{"op": "replace", "path": "/metaData/timeStamp", "value": "20250601_210500"}]}
+                ]"#,
+            )
+            .create();
+        let feed = client
+            .get_live_diff(777, cached.time_stamp(), &cached)
+            .await
+            .unwrap();
+        m.assert();
+        assert_eq!(feed.response.live_data.linescore.outs, Some(1));
+        assert_eq!(feed.time_stamp(), "20250601_210500");
+        assert_eq!(feed.document["liveData"]["linescore"]["outs"], 1);
+        // the cached feed is left alone
+        assert_eq!(cached.response.live_data.linescore.outs, Some(3));
+    }
+
+    #[tokio::test]
+    async fn test_live_diff_without_changes() {
+        let (client, mut server) = generate_mock_client().await;
+        let cached = mock_live_feed(&client, &mut server).await;
+
+        let m = server
+            .mock("GET", LIVE_DIFF_URL)
+            .with_status(200)
+            .with_body("[]")
+            .create();
+        let feed = client
+            .get_live_diff(777, cached.time_stamp(), &cached)
+            .await
+            .unwrap();
+        m.assert();
+        assert_eq!(feed.document, cached.document);
+    }
+
+    #[tokio::test]
+    async fn test_live_diff_full_feed_response() {
+        let (client, mut server) = generate_mock_client().await;
+        let cached = LiveFeed::default();
+
+        let m = server
+            .mock(
+                "GET",
+                "/v1.1/game/777/feed/live/diffPatch?language=en&startTimecode=old",
+            )
+            .with_status(200)
+            .with_body_from_file("./tests/responses/live.json")
+            .create();
+        let feed = client.get_live_diff(777, "old", &cached).await.unwrap();
+        m.assert();
+        assert_eq!(feed.time_stamp(), "20250601_210028");
+    }
+
+    #[tokio::test]
+    async fn test_live_diff_patch_failure() {
+        let (client, mut server) = generate_mock_client().await;
+        let cached = mock_live_feed(&client, &mut server).await;
+
+        let m = server
+            .mock("GET", LIVE_DIFF_URL)
+            .with_status(200)
+            .with_body(r#"[{"diff": [{"op": "replace", "path": "/missing/field", "value": 1}]}]"#)
+            .create();
+        let err = client
+            .get_live_diff(777, cached.time_stamp(), &cached)
+            .await
+            .unwrap_err();
+        m.assert();
+        let ApiError::Decode { path, .. } = err else {
+            panic!("expected a decode error, got {err:?}");
+        };
+        assert_eq!(path, "/missing/field");
+    }
+
+    fn replay_client(dir: impl Into<std::path::PathBuf>, base_url: &str) -> MLBApi {
+        MLBApiBuilder::default()
+            .base_url(base_url)
+            .transport(Arc::new(ReplayTransport::new(dir).unwrap()))
+            .build()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_replay_fixtures() {
+        let client = replay_client("./tests/responses", "https://statsapi.mlb.com/api/");
+
+        let feed = client.get_live_data(777687).await.unwrap();
+        assert_eq!(feed.game_pk, 777687);
+        let season = client.get_season_info(2026).await.unwrap();
+        assert!(season.is_some());
+        let teams = client
+            .get_teams(&[SportId::Mlb, SportId::International])
+            .await
+            .unwrap();
+        assert!(!teams.teams.is_empty());
+
+        let err = client.get_live_data(1).await.unwrap_err();
+        assert!(matches!(err, ApiError::NotFound { .. }), "{err:?}");
+    }
+
+    #[tokio::test]
+    async fn test_record_then_replay() {
+        let dir = std::env::temp_dir().join(format!("mlbt-recording-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+
+        let mut server = mockito::Server::new_async().await;
+        let recording = RecordingTransport::new(Arc::new(ReqwestTransport::default()), &dir);
+        let client = MLBApiBuilder::default()
+            .base_url(format!("{}/", server.url()))
+            .transport(Arc::new(recording.unwrap()))
+            .build()
+            .unwrap();
+        let ok = server
+            .mock("GET", "/v1/seasons/2026?sportId=1")
+            .with_status(200)
+            .with_body_from_file("./tests/responses/season-info.json")
+            .create();
+        let missing = server
+            .mock("GET", "/v1.1/game/1/feed/live?language=en")
+            .with_status(404)
+            .create();
+        let recorded = client.get_season_info(2026).await.unwrap();
+        client.get_live_data(1).await.unwrap_err();
+        ok.assert();
+        missing.assert();
+        drop(server);
+
+        // the mock server is gone, so these can only come from the recording. Only the path is
+        // matched so the host doesn't matter.
+        let client = replay_client(&dir, "http://replay.invalid/");
+        let replayed = client.get_season_info(2026).await.unwrap();
+        assert_eq!(
+            replayed.map(|s| s.regular_season_start_date),
+            recorded.map(|s| s.regular_season_start_date)
+        );
+        let err = client.get_live_data(1).await.unwrap_err();
+        assert_eq!(err.status(), Some(404));
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_disk_cache_survives_the_server() {
+        let dir = std::env::temp_dir().join(format!("mlbt-disk-cache-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        let cache = Arc::new(DiskCache::open(&dir, 1024 * 1024).unwrap());
+
+        let mut server = mockito::Server::new_async().await;
+        let transport = DiskCacheTransport::new(Arc::new(ReqwestTransport::default()), cache);
+        let client = MLBApiBuilder::default()
+            .base_url(format!("{}/", server.url()))
+            .transport(Arc::new(transport))
+            .build()
+            .unwrap();
+        let ok = server
+            .mock("GET", "/v1/seasons/2026?sportId=1")
+            .with_status(200)
+            .with_body_from_file("./tests/responses/season-info.json")
+            .create();
+        let missing = server
+            .mock("GET", "/v1.1/game/1/feed/live?language=en")
+            .with_status(404)
+            .expect(2)
+            .create();
+        let fetched = client.get_season_info(2026).await.unwrap();
+        client.get_live_data(1).await.unwrap_err();
+        // errors aren't cached, so this goes to the server again
+        client.get_live_data(1).await.unwrap_err();
+        ok.assert();
+        missing.assert();
+        drop(server);
+
+        // a new process opening the same directory is served from disk
+        let cache = Arc::new(DiskCache::open(&dir, 1024 * 1024).unwrap());
+        assert_eq!(cache.len(), 1);
+        let transport = DiskCacheTransport::new(Arc::new(ReqwestTransport::default()), cache);
+        let client = MLBApiBuilder::default()
+            .base_url("http://cache.invalid/")
+            .transport(Arc::new(transport))
+            .build()
+            .unwrap();
+        let cached = client.get_season_info(2026).await.unwrap();
+        assert_eq!(
+            cached.map(|s| s.regular_season_start_date),
+            fetched.map(|s| s.regular_season_start_date)
+        );
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
 }
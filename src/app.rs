@@ -1,10 +1,15 @@
+use crate::components::game::spray_chart::BattedBall;
 use crate::config::TomlFileStore;
 use crate::state::app_settings::AppSettings;
 use crate::state::app_state::AppState;
 use crate::state::messages::NetworkRequest;
+use crate::state::player_profile::PlayerProfileState;
+use crate::state::request_errors::FailedRequest;
 use crate::state::settings_editor::SettingsStatus;
 use chrono::{NaiveDate, ParseError, Utc};
 use log::{error, info};
+use mlbt_api::client::StatGroup;
+use mlbt_api::draft::DraftResponse;
 use mlbt_api::live::LiveResponse;
 use mlbt_api::player::PeopleResponse;
 use mlbt_api::schedule::ScheduleResponse;
@@ -161,17 +166,24 @@ impl App {
         let mut requests = Vec::new();
         if self.state.schedule.date_selector.date == previous {
             self.state.schedule.set_date_from_valid_input(today);
-            requests.push(NetworkRequest::Schedule { date: today });
+            requests.push(NetworkRequest::Schedule {
+                date: today,
+                sport: self.state.schedule.sport,
+            });
         }
         if self.state.standings.date_selector.date == previous {
             self.state.standings.set_date_from_valid_input(today);
-            requests.push(NetworkRequest::Standings { date: today });
+            requests.push(NetworkRequest::Standings {
+                date: today,
+                sport: self.state.standings.sport,
+            });
         }
         if self.state.stats.date_selector.date == previous {
             self.state.stats.set_date_from_valid_input(today);
             requests.push(NetworkRequest::Stats {
                 date: today,
                 stat_type: self.state.stats.stat_type,
+                sport: self.state.stats.sport,
             });
         }
         (today, requests)
@@ -218,6 +230,47 @@ impl App {
         }
     }
 
+    /// The request for the selected game's preview or recap, if a Recap panel is shown and it
+    /// hasn't been requested yet.
+    pub fn game_content_request(&mut self) -> Option<NetworkRequest> {
+        let visible = match self.state.active_tab {
+            MenuItem::Scoreboard => self.state.schedule.show_recap,
+            MenuItem::Gameday => self.state.gameday.panels.recap,
+            _ => false,
+        };
+        if !visible {
+            return None;
+        }
+        let game_id = self.state.gameday.current_game_id();
+        let is_final = self.state.gameday.is_final();
+        self.state.recap.request(game_id, is_final)
+    }
+
+    /// The tab whose content is shown, the date picker and help are drawn over another tab.
+    pub fn content_tab(&self) -> MenuItem {
+        match self.state.active_tab {
+            MenuItem::DatePicker | MenuItem::Help => self.state.previous_tab,
+            tab => tab,
+        }
+    }
+
+    /// The request that failed on the active tab, if it can be retried from there.
+    pub fn failed_request(&self) -> Option<&FailedRequest> {
+        match self.state.active_tab {
+            MenuItem::DatePicker | MenuItem::Help => None,
+            tab => self.state.request_errors.get(tab),
+        }
+    }
+
+    /// Clear the active tab's failed request and return it to send again.
+    pub fn retry_request(&mut self) -> Option<NetworkRequest> {
+        self.failed_request()?;
+        self.state
+            .request_errors
+            .take(self.state.active_tab)
+            .map(|failed| failed.request)
+    }
+
     pub fn update_tab(&mut self, next: MenuItem) {
         // don't switch tabs if already on the correct tab
         if self.state.active_tab == next {
@@ -302,7 +355,12 @@ impl App {
         self.settings.full_screen = !self.settings.full_screen;
     }
 
-    pub fn update_player_profile(&mut self, data: Arc<PeopleResponse>, game_type: GameType) {
+    pub fn update_player_profile(
+        &mut self,
+        data: Arc<PeopleResponse>,
+        group: StatGroup,
+        game_type: GameType,
+    ) {
         match self.state.active_tab {
             MenuItem::Standings if self.state.standings.has_team_page() => {
                 self.state
@@ -313,12 +371,61 @@ impl App {
                 self.state.stats.update_team_player_profile(data, game_type);
             }
             MenuItem::Stats => {
-                self.state.stats.update_player_profile(data, game_type);
+                self.state
+                    .stats
+                    .update_player_profile(data, group, game_type);
             }
             _ => {}
         }
     }
 
+    /// Any of the open player profiles could have requested the spray chart, so offer it to each
+    /// of them and let the profile check the player id.
+    pub fn update_spray_chart(&mut self, player_id: u64, balls: Arc<Vec<BattedBall>>) {
+        for profile in self.player_profiles() {
+            profile.update_spray_chart(player_id, balls.clone());
+        }
+    }
+
+    /// Let the state that sent a request know it failed, so it can request it again.
+    pub fn request_failed(&mut self, request: &NetworkRequest) {
+        match request {
+            NetworkRequest::SprayChart { player_id, .. } => {
+                for profile in self.player_profiles() {
+                    profile.spray_chart_failed(*player_id);
+                }
+            }
+            NetworkRequest::VsPlayer {
+                batter_id,
+                pitcher_id,
+                ..
+            } => self
+                .state
+                .gameday
+                .head_to_head_failed(*batter_id, *pitcher_id),
+            NetworkRequest::GameContent { game_id } => self.state.recap.failed(*game_id),
+            _ => {}
+        }
+    }
+
+    fn player_profiles(&mut self) -> impl Iterator<Item = &mut PlayerProfileState> {
+        [
+            self.state.stats.player_profile.as_mut(),
+            self.state
+                .stats
+                .team_page
+                .as_mut()
+                .and_then(|tp| tp.player_profile.as_mut()),
+            self.state
+                .standings
+                .team_page
+                .as_mut()
+                .and_then(|tp| tp.player_profile.as_mut()),
+        ]
+        .into_iter()
+        .flatten()
+    }
+
     pub fn update_team_page(
         &mut self,
         team_id: u16,
@@ -381,15 +488,48 @@ impl App {
             _ => {}
         }
     }
+
+    pub fn update_draft(&mut self, year: i32, draft: &DraftResponse) {
+        let team_page = match self.state.active_tab {
+            MenuItem::Standings => self.state.standings.team_page.as_mut(),
+            MenuItem::Stats => self.state.stats.team_page.as_mut(),
+            _ => None,
+        };
+        if let Some(tp) = team_page {
+            tp.update_draft(year, draft);
+        }
+    }
+
+    pub fn update_injured_list(
+        &mut self,
+        team_id: u16,
+        roster: &RosterResponse,
+        transactions: &TransactionsResponse,
+    ) {
+        match self.state.active_tab {
+            MenuItem::Standings => {
+                self.state
+                    .standings
+                    .update_injured_list(team_id, roster, transactions);
+            }
+            MenuItem::Stats => {
+                self.state
+                    .stats
+                    .update_injured_list(team_id, roster, transactions);
+            }
+            _ => {}
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::components::constants::lookup_team_by_id;
+    use crate::components::game_context::GameContext;
     use crate::components::probable_pitchers::ProbablePitcher;
     use crate::components::schedule::ScheduleRow;
-    use crate::components::team_page::TeamGame;
+    use crate::components::team_page::{RosterView, TeamGame};
     use crate::config::ConfigFile;
     use crate::state::settings_editor::{PickerState, SettingsField, TEAM_OPTIONS};
     use crate::state::team_page::{TeamPageState, TeamSection};
@@ -424,10 +564,14 @@ mod tests {
                 ),
                 is_home: false,
                 is_past: false,
+                context: GameContext::default(),
+                series_start: true,
             }],
             schedule_selection: TableState::default(),
             roster: vec![],
-            roster_type: RosterType::Active,
+            roster_view: RosterView::Active,
+            depth_chart: vec![],
+            depth_chart_selection: TableState::default(),
             transactions: vec![],
             selected_transaction: 0,
             transaction_scroll: 0,
@@ -437,6 +581,7 @@ mod tests {
             roster_header_rows: std::collections::HashSet::new(),
             roster_row_map: vec![],
             player_profile: None,
+            draft: None,
             show_calendar: true,
         }
     }
@@ -458,6 +603,8 @@ mod tests {
             decision_pitchers: None,
             abstract_game_state: None,
             current_inning: None,
+            context: GameContext::default(),
+            broadcasts: Vec::new(),
         }
     }
 
@@ -9,6 +9,16 @@ use tui::text::Span;
 use tui::widgets::Cell;
 
 const TOTALS_NAME: &str = "Totals";
+/// Game notes that are also shown in Gameday's Game Info section.
+const GAME_INFO_LABELS: &[&str] = &[
+    "Weather",
+    "Wind",
+    "First pitch",
+    "T",
+    "Att",
+    "Venue",
+    "Umpires",
+];
 
 #[derive(Default)]
 pub struct Boxscore {
@@ -393,8 +403,11 @@ impl Boxscore {
         }
     }
 
-    pub fn get_game_notes(&self) -> &[Note] {
-        &self.game_notes
+    /// The game notes, leaving out the ones in the Game Info section if it's shown next to them.
+    pub fn get_game_notes(&self, skip_game_info: bool) -> impl Iterator<Item = &Note> {
+        self.game_notes
+            .iter()
+            .filter(move |n| !(skip_game_info && n.is_game_info()))
     }
 
     fn generate_game_notes(live_response: &LiveResponse) -> Vec<Note> {
@@ -429,6 +442,10 @@ impl Note {
             Note::Game(n) => n.to_line(),
         }
     }
+
+    fn is_game_info(&self) -> bool {
+        matches!(self, Note::Game(n) if GAME_INFO_LABELS.contains(&n.label.as_str()))
+    }
 }
 
 impl GameNote {
@@ -486,3 +503,44 @@ fn styled_cell<T: ToString, S: Into<tui::style::Style>>(
         cell.style(style)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mlbt_api::boxscore::LabelValue;
+
+    #[test]
+    fn game_notes_skip_the_game_info_only_next_to_it() {
+        let note = |label: &str| LabelValue {
+            label: label.to_string(),
+            value: Some("x".to_string()),
+        };
+        let mut live = LiveResponse::default();
+        live.live_data.boxscore.info = Some(vec![
+            note("Weather"),
+            note("Umpires"),
+            note("Pitches-strikes"),
+            note("Att"),
+        ]);
+        let boxscore = Boxscore {
+            game_notes: Boxscore::generate_game_notes(&live),
+            ..Boxscore::default()
+        };
+        let labels = |skip_game_info| {
+            boxscore
+                .get_game_notes(skip_game_info)
+                .map(|n| match n {
+                    Note::Game(n) => n.label.as_str(),
+                    Note::Batting(n) => n.label.as_str(),
+                })
+                .collect::<Vec<_>>()
+        };
+        // next to the Game Info section
+        assert_eq!(labels(true), ["Pitches-strikes"]);
+        // the Scoreboard, or Gameday with Game Info hidden
+        assert_eq!(
+            labels(false),
+            ["Weather", "Umpires", "Pitches-strikes", "Att"]
+        );
+    }
+}
@@ -0,0 +1,218 @@
+use crate::components::constants::lookup_team_by_id;
+use crate::components::standings::Team;
+use mlbt_api::schedule::{
+    AbstractGameState, Game, IdNameLink, PostseasonSeries, PostseasonSeriesResponse,
+};
+use mlbt_api::season::GameType;
+
+/// The postseason bracket for a season, one column per round that has been scheduled.
+#[derive(Debug, Default)]
+pub struct Bracket {
+    pub rounds: Vec<BracketRound>,
+}
+
+#[derive(Debug)]
+pub struct BracketRound {
+    pub game_type: GameType,
+    pub matchups: Vec<Matchup>,
+}
+
+/// One postseason series. The team with home field in game 1 is listed first.
+#[derive(Debug)]
+pub struct Matchup {
+    /// "AL" or "NL", empty for the World Series.
+    pub league: &'static str,
+    pub teams: [SeriesTeam; 2],
+}
+
+#[derive(Debug)]
+pub struct SeriesTeam {
+    pub team: Team,
+    pub wins: u8,
+    pub clinched: bool,
+}
+
+impl Bracket {
+    pub fn from_response(response: &PostseasonSeriesResponse) -> Self {
+        let rounds = GameType::POSTSEASON
+            .iter()
+            .filter_map(|game_type| {
+                let mut series: Vec<&PostseasonSeries> = response
+                    .series
+                    .iter()
+                    .filter(|s| series_game_type(s) == Some(*game_type))
+                    .collect();
+                if series.is_empty() {
+                    return None;
+                }
+                series.sort_by_key(|s| s.series.sort_number);
+                Some(BracketRound {
+                    game_type: *game_type,
+                    matchups: series
+                        .into_iter()
+                        .filter_map(Matchup::from_series)
+                        .collect(),
+                })
+            })
+            .collect();
+        Self { rounds }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rounds.is_empty()
+    }
+}
+
+impl Matchup {
+    fn from_series(series: &PostseasonSeries) -> Option<Self> {
+        let first = series.games.first()?;
+        let wins_needed = first.games_in_series.map(|n| n / 2 + 1);
+
+        let wins_for = |team_id: u16| {
+            series
+                .games
+                .iter()
+                .filter(|g| is_final(g))
+                .filter(|g| {
+                    [&g.teams.home, &g.teams.away]
+                        .iter()
+                        .any(|t| t.team.id == team_id && t.is_winner == Some(true))
+                })
+                .count() as u8
+        };
+        let series_team = |team: &IdNameLink| {
+            let wins = wins_for(team.id);
+            SeriesTeam {
+                team: lookup_team_by_id(team.id).unwrap_or_else(|| Team::from_schedule(team)),
+                wins,
+                clinched: wins_needed.is_some_and(|needed| wins >= needed),
+            }
+        };
+
+        let home = series_team(&first.teams.home.team);
+        let away = series_team(&first.teams.away.team);
+        let league = match first.game_type {
+            Some(GameType::WorldSeries) => "",
+            _ => league_abbreviation(home.team.division_id),
+        };
+        Some(Self {
+            league,
+            teams: [home, away],
+        })
+    }
+}
+
+/// The round of a series. Every game in a series has the same type, so the first one is used.
+fn series_game_type(series: &PostseasonSeries) -> Option<GameType> {
+    series.games.first().and_then(|g| g.game_type)
+}
+
+fn is_final(game: &Game) -> bool {
+    matches!(
+        game.status.abstract_game_state,
+        Some(AbstractGameState::Final)
+    )
+}
+
+fn league_abbreviation(division_id: u16) -> &'static str {
+    match division_id {
+        200..=202 => "AL",
+        203..=205 => "NL",
+        _ => "",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mlbt_api::schedule::{SeriesInfo, Status, TeamInfo, Teams};
+
+    fn team(id: u16, is_winner: Option<bool>) -> TeamInfo {
+        TeamInfo {
+            team: IdNameLink {
+                id,
+                ..IdNameLink::default()
+            },
+            is_winner,
+            ..TeamInfo::default()
+        }
+    }
+
+    fn game(game_type: GameType, home: u16, away: u16, home_won: Option<bool>) -> Game {
+        let state = match home_won {
+            Some(_) => AbstractGameState::Final,
+            None => AbstractGameState::Preview,
+        };
+        Game {
+            game_type: Some(game_type),
+            games_in_series: Some(3),
+            status: Status {
+                abstract_game_state: Some(state),
+                ..Status::default()
+            },
+            teams: Teams {
+                home: team(home, home_won),
+                away: team(away, home_won.map(|w| !w)),
+            },
+            ..Game::default()
+        }
+    }
+
+    fn series(id: &str, sort_number: u8, games: Vec<Game>) -> PostseasonSeries {
+        PostseasonSeries {
+            series: SeriesInfo {
+                id: id.to_string(),
+                sort_number: Some(sort_number),
+            },
+            games,
+        }
+    }
+
+    #[test]
+    fn counts_series_wins_and_clinches() {
+        // Guardians (114) host the Tigers (116)
+        let response = PostseasonSeriesResponse {
+            series: vec![series(
+                "F_1",
+                1,
+                vec![
+                    game(GameType::WildCard, 114, 116, Some(false)),
+                    game(GameType::WildCard, 114, 116, Some(true)),
+                    game(GameType::WildCard, 116, 114, Some(false)),
+                ],
+            )],
+        };
+        let bracket = Bracket::from_response(&response);
+
+        assert_eq!(bracket.rounds.len(), 1);
+        let matchup = &bracket.rounds[0].matchups[0];
+        assert_eq!(matchup.league, "AL");
+        let [home, away] = &matchup.teams;
+        assert_eq!((home.team.id, home.wins, home.clinched), (114, 2, true));
+        assert_eq!((away.team.id, away.wins, away.clinched), (116, 1, false));
+    }
+
+    #[test]
+    fn orders_rounds_and_series() {
+        let response = PostseasonSeriesResponse {
+            series: vec![
+                series("W_1", 13, vec![game(GameType::WorldSeries, 119, 147, None)]),
+                series("F_2", 2, vec![game(GameType::WildCard, 143, 144, None)]),
+                series("F_1", 1, vec![game(GameType::WildCard, 117, 116, None)]),
+            ],
+        };
+        let bracket = Bracket::from_response(&response);
+
+        let rounds: Vec<_> = bracket.rounds.iter().map(|r| r.game_type).collect();
+        assert_eq!(rounds, [GameType::WildCard, GameType::WorldSeries]);
+        let wild_card: Vec<_> = bracket.rounds[0]
+            .matchups
+            .iter()
+            .map(|m| m.teams[0].team.id)
+            .collect();
+        assert_eq!(wild_card, [117, 143]);
+        let world_series = &bracket.rounds[1].matchups[0];
+        assert_eq!(world_series.league, "");
+        assert_eq!(world_series.teams[0].wins, 0);
+    }
+}
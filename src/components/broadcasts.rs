@@ -0,0 +1,132 @@
+use crate::config::BroadcastLanguage;
+use mlbt_api::schedule::Game;
+
+/// A TV or radio broadcast of a scheduled game.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GameBroadcast {
+    pub name: String,
+    pub call_sign: Option<String>,
+    pub is_radio: bool,
+    /// Carried by the home team's broadcaster, national broadcasts are listed as home.
+    pub is_home: bool,
+    pub is_national: bool,
+    /// e.g. "en" or "es".
+    pub language: String,
+}
+
+impl GameBroadcast {
+    pub fn from_game(game: &Game) -> Vec<Self> {
+        let mut broadcasts: Vec<Self> = Vec::with_capacity(game.broadcasts.len());
+        for b in game.broadcasts.iter().filter(|b| !b.name.is_empty()) {
+            let broadcast = GameBroadcast {
+                name: b.name.clone(),
+                call_sign: b.call_sign.clone(),
+                is_radio: b.broadcast_type.is_some_and(|t| t.is_radio()),
+                is_home: b.home_away.as_deref() != Some("away"),
+                is_national: b.is_national.unwrap_or(false),
+                language: b.language.clone().unwrap_or_else(|| "en".to_string()),
+            };
+            // the same network can be listed once per video feed
+            if !broadcasts.contains(&broadcast) {
+                broadcasts.push(broadcast);
+            }
+        }
+        broadcasts
+    }
+
+    fn matches(&self, broadcaster: &str) -> bool {
+        self.name.eq_ignore_ascii_case(broadcaster)
+            || self
+                .call_sign
+                .as_deref()
+                .is_some_and(|c| c.eq_ignore_ascii_case(broadcaster))
+    }
+}
+
+/// Broadcasts in the chosen language. If any of the preferred broadcasters carry the game only
+/// those are kept, otherwise every broadcast in the language is.
+pub fn filter_broadcasts<'a>(
+    broadcasts: &'a [GameBroadcast],
+    language: BroadcastLanguage,
+    preferred: &[String],
+) -> Vec<&'a GameBroadcast> {
+    let in_language: Vec<&GameBroadcast> = broadcasts
+        .iter()
+        .filter(|b| language.code().is_none_or(|code| b.language == code))
+        .collect();
+    let preferred_only: Vec<&GameBroadcast> = in_language
+        .iter()
+        .copied()
+        .filter(|b| preferred.iter().any(|p| b.matches(p)))
+        .collect();
+    if preferred_only.is_empty() {
+        in_language
+    } else {
+        preferred_only
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mlbt_api::schedule::Broadcast;
+
+    fn broadcast(name: &str, call_sign: &str, language: &str) -> GameBroadcast {
+        GameBroadcast {
+            name: name.to_string(),
+            call_sign: Some(call_sign.to_string()),
+            is_radio: false,
+            is_home: true,
+            is_national: false,
+            language: language.to_string(),
+        }
+    }
+
+    fn names(broadcasts: Vec<&GameBroadcast>) -> Vec<&str> {
+        broadcasts.iter().map(|b| b.name.as_str()).collect()
+    }
+
+    #[test]
+    fn filters_by_language_then_preferred_broadcaster() {
+        let broadcasts = [
+            broadcast("NESN", "NESN", "en"),
+            broadcast("WEEI 93.7", "WEEI", "en"),
+            broadcast("WRCA 1330 AM", "WRCA", "es"),
+        ];
+
+        let all = filter_broadcasts(&broadcasts, BroadcastLanguage::All, &[]);
+        assert_eq!(all.len(), 3);
+
+        let spanish = filter_broadcasts(&broadcasts, BroadcastLanguage::Spanish, &[]);
+        assert_eq!(names(spanish), ["WRCA 1330 AM"]);
+
+        // matched by call sign, ignoring case
+        let preferred = ["weei".to_string()];
+        let english = filter_broadcasts(&broadcasts, BroadcastLanguage::English, &preferred);
+        assert_eq!(names(english), ["WEEI 93.7"]);
+    }
+
+    #[test]
+    fn skips_broadcasts_without_a_name() {
+        let game = Game {
+            broadcasts: vec![
+                Broadcast::default(),
+                Broadcast {
+                    name: "NESN".to_string(),
+                    ..Broadcast::default()
+                },
+            ],
+            ..Game::default()
+        };
+        let broadcasts = GameBroadcast::from_game(&game);
+        assert_eq!(names(broadcasts.iter().collect()), ["NESN"]);
+    }
+
+    #[test]
+    fn falls_back_when_no_preferred_broadcaster_carries_the_game() {
+        let broadcasts = [broadcast("NESN", "NESN", "en")];
+        let preferred = ["Marquee Sports Network".to_string()];
+        let filtered = filter_broadcasts(&broadcasts, BroadcastLanguage::All, &preferred);
+        assert_eq!(names(filtered), ["NESN"]);
+    }
+}
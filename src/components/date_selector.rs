@@ -1,4 +1,5 @@
 use chrono::{NaiveDate, Utc};
+use mlbt_api::teams::SportId;
 
 #[derive(Debug)]
 pub struct DateSelector {
@@ -36,8 +37,14 @@ impl DateSelector {
         self.date + chrono::Duration::days(self.selection_offset)
     }
 
-    /// Format the data to be used in the title of a border;
-    pub fn format_date_border_title(&self) -> String {
-        self.date.format(" %B %d, %Y ").to_string()
+    /// Format the data to be used in the title of a border. Minor league levels are appended so
+    /// it's clear the view isn't showing MLB.
+    pub fn format_date_border_title(&self, sport: SportId) -> String {
+        let date = self.date.format("%B %d, %Y");
+        if sport.is_minor_league() {
+            format!(" {date} [{}] ", sport.abbreviation())
+        } else {
+            format!(" {date} ")
+        }
     }
 }
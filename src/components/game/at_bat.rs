@@ -1,6 +1,7 @@
 use crate::components::game::matchup::Matchup;
 use crate::components::game::pitches::Pitches;
 use crate::components::game::plays::PlayResult;
+use crate::components::game::spray_chart::BattedBall;
 use crate::components::game::strikezone::StrikeZone;
 use mlbt_api::plays::Play;
 
@@ -15,6 +16,8 @@ pub struct AtBat {
     pub matchup: Matchup,
     /// Play information
     pub play_result: PlayResult,
+    /// Where the ball in play went, if there was one
+    pub batted_ball: Option<BattedBall>,
 }
 
 #[derive(Default)]
@@ -32,6 +35,7 @@ impl From<&Play> for AtBat {
             pitches: play.into(),
             matchup: play.into(),
             play_result: play.into(),
+            batted_ball: BattedBall::from_play(play),
         }
     }
 }
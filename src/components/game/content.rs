@@ -0,0 +1,228 @@
+use mlbt_api::content::{Article, ContentResponse};
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum StoryKind {
+    Preview,
+    Recap,
+    Wrap,
+}
+
+impl StoryKind {
+    pub fn label(&self) -> &'static str {
+        match self {
+            StoryKind::Preview => "Preview",
+            StoryKind::Recap => "Recap",
+            StoryKind::Wrap => "Wrap",
+        }
+    }
+}
+
+/// An article about a game with the HTML stripped out of the body.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Story {
+    pub kind: StoryKind,
+    pub headline: String,
+    pub subhead: Option<String>,
+    pub paragraphs: Vec<String>,
+}
+
+/// The articles written about a game.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct GameContent {
+    pub preview: Option<Story>,
+    pub recap: Option<Story>,
+    pub wrap: Option<Story>,
+}
+
+impl From<&ContentResponse> for GameContent {
+    fn from(content: &ContentResponse) -> Self {
+        let story = |kind, article: Option<&Article>| article.map(|a| Story::new(kind, a));
+        Self {
+            preview: story(StoryKind::Preview, content.preview()),
+            recap: story(StoryKind::Recap, content.recap()),
+            wrap: story(StoryKind::Wrap, content.wrap()),
+        }
+    }
+}
+
+impl GameContent {
+    /// The recap, or the wrap if there isn't one, for final games and the preview otherwise.
+    pub fn story(&self, is_final: bool) -> Option<&Story> {
+        match is_final {
+            true => self.recap.as_ref().or(self.wrap.as_ref()),
+            false => self.preview.as_ref(),
+        }
+    }
+}
+
+impl Story {
+    fn new(kind: StoryKind, article: &Article) -> Self {
+        let paragraphs = match article.body.as_deref() {
+            Some(body) => html_paragraphs(body),
+            None => article.blurb.iter().cloned().collect(),
+        };
+        Self {
+            kind,
+            headline: decode_entities(&article.headline),
+            subhead: article
+                .subhead
+                .as_deref()
+                .map(decode_entities)
+                .filter(|s| !s.is_empty()),
+            paragraphs,
+        }
+    }
+}
+
+/// Split an article body into plain text paragraphs. Block level tags end a paragraph, every other
+/// tag is dropped along with embeds (e.g. videos) that don't have any text of their own.
+fn html_paragraphs(html: &str) -> Vec<String> {
+    const BLOCK_TAGS: [&str; 6] = ["p", "div", "br", "li", "h2", "h3"];
+
+    let mut paragraphs = Vec::new();
+    let mut current = String::new();
+    let mut rest = html;
+    while let Some(start) = rest.find('<') {
+        current.push_str(&rest[..start]);
+        let Some(end) = rest[start..].find('>') else {
+            rest = "";
+            break;
+        };
+        let tag = rest[start + 1..start + end]
+            .trim_start_matches('/')
+            .split(|c: char| c.is_whitespace() || c == '/')
+            .next()
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+        if BLOCK_TAGS.contains(&tag.as_str()) {
+            paragraphs.push(std::mem::take(&mut current));
+        }
+        rest = &rest[start + end + 1..];
+    }
+    current.push_str(rest);
+    paragraphs.push(current);
+
+    paragraphs
+        .iter()
+        .map(|p| decode_entities(&p.split_whitespace().collect::<Vec<_>>().join(" ")))
+        .filter(|p| !p.is_empty())
+        .collect()
+}
+
+/// Letters with accents that can be written as named entities, e.g. `&oacute;`, as
+/// (accent, plain letters, accented letters).
+const ACCENTS: &[(&str, &str, &str)] = &[
+    ("acute", "AEIOUYaeiouy", "ÁÉÍÓÚÝáéíóúý"),
+    ("grave", "AEIOUaeiou", "ÀÈÌÒÙàèìòù"),
+    ("circ", "AEIOUaeiou", "ÂÊÎÔÛâêîôû"),
+    ("uml", "AEIOUaeiouy", "ÄËÏÖÜäëïöüÿ"),
+    ("tilde", "ANOano", "ÃÑÕãñõ"),
+    ("cedil", "Cc", "Çç"),
+];
+
+/// Decode HTML entities in one pass. Numeric entities like `&#8217;` and `&#x2019;` are decoded
+/// generically, unknown ones are left as they are.
+fn decode_entities(text: &str) -> String {
+    let mut decoded = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('&') {
+        decoded.push_str(&rest[..start]);
+        rest = &rest[start + 1..];
+        let entity = rest
+            .find(';')
+            .and_then(|end| decode_entity(&rest[..end]).map(|c| (c, end)));
+        match entity {
+            Some((c, end)) => {
+                decoded.push(c);
+                rest = &rest[end + 1..];
+            }
+            None => decoded.push('&'),
+        }
+    }
+    decoded.push_str(rest);
+    decoded
+}
+
+/// The character for an entity without its `&` and `;`.
+fn decode_entity(entity: &str) -> Option<char> {
+    if let Some(number) = entity.strip_prefix('#') {
+        let code = match number.strip_prefix(['x', 'X']) {
+            Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+            None => number.parse().ok()?,
+        };
+        return char::from_u32(code);
+    }
+    let c = match entity {
+        "nbsp" => ' ',
+        "quot" | "rdquo" | "ldquo" => '"',
+        "apos" | "rsquo" | "lsquo" => '\'',
+        "mdash" => '—',
+        "ndash" => '–',
+        "hellip" => '…',
+        "lt" => '<',
+        "gt" => '>',
+        "amp" => '&',
+        name => {
+            return ACCENTS.iter().find_map(|(accent, plain, accented)| {
+                let mut letter = name.strip_suffix(accent)?.chars();
+                let (Some(letter), None) = (letter.next(), letter.next()) else {
+                    return None;
+                };
+                let i = plain.chars().position(|c| c == letter)?;
+                accented.chars().nth(i)
+            });
+        }
+    };
+    Some(c)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn strips_html_into_paragraphs() {
+        let body = "<p>NEW YORK -- Judge  homered\n twice.</p>\n<div class=\"video\"><a href=\"#\"></a></div>\
+                    <p><b>Up next:</b> Rodón &amp; &quot;the bullpen&quot;<br/>Saturday</p>";
+        assert_eq!(
+            html_paragraphs(body),
+            [
+                "NEW YORK -- Judge homered twice.",
+                "Up next: Rodón & \"the bullpen\"",
+                "Saturday",
+            ]
+        );
+    }
+
+    #[test]
+    fn decodes_numeric_and_accented_entities() {
+        assert_eq!(
+            decode_entities("Rod&oacute;n&#8217;s &#x2019;27 &Ntilde; &amp;lt; &#39;"),
+            "Rodón’s ’27 Ñ &lt; '"
+        );
+        // not entities, or not ones we know
+        assert_eq!(
+            decode_entities("R&D; 3 & 4; &bogus; &#xZZ;"),
+            "R&D; 3 & 4; &bogus; &#xZZ;"
+        );
+    }
+
+    #[test]
+    fn story_depends_on_game_state() {
+        let story = |kind| Story {
+            kind,
+            headline: String::new(),
+            subhead: None,
+            paragraphs: vec![],
+        };
+        let mut content = GameContent {
+            preview: Some(story(StoryKind::Preview)),
+            recap: None,
+            wrap: Some(story(StoryKind::Wrap)),
+        };
+        assert_eq!(content.story(false).unwrap().kind, StoryKind::Preview);
+        assert_eq!(content.story(true).unwrap().kind, StoryKind::Wrap);
+        content.recap = Some(story(StoryKind::Recap));
+        assert_eq!(content.story(true).unwrap().kind, StoryKind::Recap);
+    }
+}
@@ -0,0 +1,310 @@
+use crate::components::datetime::format_game_time;
+use crate::components::util::last_name;
+use crate::ui::styling::dim_style;
+use chrono::{DateTime, Utc};
+use chrono_tz::Tz;
+use mlbt_api::live::LiveResponse;
+use tui::prelude::*;
+
+/// Details about where and under what conditions a game is played.
+#[derive(Debug, Default, PartialEq)]
+pub struct GameInfo {
+    pub venue: Option<Venue>,
+    pub weather: Option<Weather>,
+    pub umpires: Vec<Umpire>,
+    pub first_pitch: Option<DateTime<Utc>>,
+    pub duration_minutes: Option<u16>,
+    pub attendance: Option<u32>,
+}
+
+#[derive(Debug, Default, PartialEq)]
+pub struct Venue {
+    pub name: String,
+    /// e.g. "Chicago, IL"
+    pub location: Option<String>,
+    pub roof: Option<String>,
+    pub turf: Option<String>,
+    /// Fence distances in feet from left field to right field.
+    pub dimensions: Vec<(&'static str, u16)>,
+}
+
+#[derive(Debug, Default, PartialEq)]
+pub struct Weather {
+    /// Degrees Fahrenheit.
+    pub temp: Option<String>,
+    pub condition: Option<String>,
+    pub wind: Option<String>,
+}
+
+#[derive(Debug, Default, PartialEq)]
+pub struct Umpire {
+    /// e.g. "HP" or "1B"
+    pub position: String,
+    pub name: String,
+}
+
+impl GameInfo {
+    pub fn from_live(live: &LiveResponse) -> Self {
+        let game_data = &live.game_data;
+        let game_info = game_data.game_info.as_ref();
+        Self {
+            venue: game_data.venue.as_ref().map(Venue::from),
+            weather: game_data.weather.as_ref().map(Weather::from),
+            umpires: live
+                .live_data
+                .boxscore
+                .officials
+                .iter()
+                .map(|o| Umpire {
+                    position: umpire_position(&o.official_type),
+                    name: o.official.full_name.clone(),
+                })
+                .collect(),
+            first_pitch: game_info.and_then(|i| i.first_pitch),
+            duration_minutes: game_info.and_then(|i| i.game_duration_minutes),
+            attendance: game_info.and_then(|i| i.attendance),
+        }
+    }
+
+    /// One labeled line per group of details, skipping any that aren't available yet.
+    pub fn lines(&self, tz: Tz) -> Vec<Line<'static>> {
+        let venue = self.venue.as_ref();
+        let rows = [
+            ("Venue", venue.map(Venue::format).unwrap_or_default()),
+            (
+                "Field",
+                venue.map(|v| join(v.field_parts())).unwrap_or_default(),
+            ),
+            (
+                "Weather",
+                self.weather
+                    .as_ref()
+                    .map(Weather::format)
+                    .unwrap_or_default(),
+            ),
+            (
+                "Umpires",
+                join(
+                    self.umpires
+                        .iter()
+                        .map(|u| format!("{} {}", u.position, last_name(&u.name))),
+                ),
+            ),
+            ("Game", self.format_game(tz)),
+        ];
+
+        let mut lines = vec![Line::styled("Game Info", dim_style().bold())];
+        lines.extend(rows.into_iter().filter(|(_, value)| !value.is_empty()).map(
+            |(label, value)| {
+                Line::from(vec![Span::from(format!("{label}: ")).bold(), value.into()])
+            },
+        ));
+        lines
+    }
+
+    /// First pitch, duration and attendance, e.g. "first pitch 1:20 pm · 2:40 · 40,179 fans".
+    fn format_game(&self, tz: Tz) -> String {
+        join(
+            [
+                self.first_pitch
+                    .map(|t| format!("first pitch {}", format_game_time(t, tz))),
+                self.duration_minutes
+                    .map(|m| format!("{}:{:02}", m / 60, m % 60)),
+                self.attendance.map(|a| format!("{} fans", thousands(a))),
+            ]
+            .into_iter()
+            .flatten(),
+        )
+    }
+}
+
+impl Venue {
+    /// e.g. "Wrigley Field (Chicago, IL)"
+    fn format(&self) -> String {
+        match &self.location {
+            Some(location) => format!("{} ({location})", self.name),
+            None => self.name.clone(),
+        }
+    }
+
+    /// e.g. ["open roof", "grass", "LF 355", ...]
+    fn field_parts(&self) -> impl Iterator<Item = String> {
+        let surface = [
+            self.roof
+                .as_ref()
+                .map(|r| format!("{} roof", r.to_lowercase())),
+            self.turf.as_ref().map(|t| t.to_lowercase()),
+        ];
+        let dimensions = self
+            .dimensions
+            .iter()
+            .map(|(label, feet)| format!("{label} {feet}"));
+        surface.into_iter().flatten().chain(dimensions)
+    }
+}
+
+impl From<&mlbt_api::live::Venue> for Venue {
+    fn from(venue: &mlbt_api::live::Venue) -> Self {
+        let location = venue
+            .location
+            .as_ref()
+            .and_then(|l| match (&l.city, &l.state_abbrev) {
+                (Some(city), Some(state)) => Some(format!("{city}, {state}")),
+                (Some(city), None) => Some(city.clone()),
+                _ => None,
+            });
+        let field = venue.field_info.as_ref();
+        let dimensions = field
+            .map(|f| {
+                [
+                    ("LF", f.left_line),
+                    ("LC", f.left_center),
+                    ("CF", f.center),
+                    ("RC", f.right_center),
+                    ("RF", f.right_line),
+                ]
+                .into_iter()
+                .filter_map(|(label, feet)| Some((label, feet?)))
+                .collect()
+            })
+            .unwrap_or_default();
+        Self {
+            name: venue.name.clone(),
+            location,
+            roof: field.and_then(|f| f.roof_type.clone()),
+            turf: field.and_then(|f| f.turf_type.clone()),
+            dimensions,
+        }
+    }
+}
+
+impl Weather {
+    /// e.g. "59°F, Sunny · wind 11 mph, In From RF"
+    fn format(&self) -> String {
+        let conditions = [
+            self.temp.as_ref().map(|t| format!("{t}°F")),
+            self.condition.clone(),
+        ]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>()
+        .join(", ");
+        let wind = self.wind.as_ref().map(|w| format!("wind {w}"));
+        join([conditions].into_iter().chain(wind))
+    }
+}
+
+impl From<&mlbt_api::live::Weather> for Weather {
+    fn from(weather: &mlbt_api::live::Weather) -> Self {
+        let non_empty = |s: &Option<String>| s.clone().filter(|s| !s.is_empty());
+        Self {
+            temp: non_empty(&weather.temp),
+            condition: non_empty(&weather.condition),
+            wind: non_empty(&weather.wind),
+        }
+    }
+}
+
+/// Abbreviate an official type, e.g. "Home Plate" to "HP". Unknown types are kept as is.
+fn umpire_position(official_type: &str) -> String {
+    match official_type {
+        "Home Plate" => "HP",
+        "First Base" => "1B",
+        "Second Base" => "2B",
+        "Third Base" => "3B",
+        "Left Field" => "LF",
+        "Right Field" => "RF",
+        other => other,
+    }
+    .to_string()
+}
+
+fn join(parts: impl IntoIterator<Item = String>) -> String {
+    parts
+        .into_iter()
+        .filter(|p| !p.is_empty())
+        .collect::<Vec<_>>()
+        .join(" · ")
+}
+
+/// e.g. 40179 -> "40,179"
+fn thousands(n: u32) -> String {
+    let digits = n.to_string();
+    let mut out = String::new();
+    for (i, c) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i).is_multiple_of(3) {
+            out.push(',');
+        }
+        out.push(c);
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mlbt_api::boxscore::Official;
+    use mlbt_api::live::{FieldInfo, GameInfo as ApiGameInfo, Person, Weather as ApiWeather};
+
+    fn text(line: &Line) -> String {
+        line.spans.iter().map(|s| s.content.as_ref()).collect()
+    }
+
+    #[test]
+    fn formats_available_details() {
+        let mut live = LiveResponse::default();
+        live.game_data.venue = Some(mlbt_api::live::Venue {
+            name: "Wrigley Field".to_string(),
+            field_info: Some(FieldInfo {
+                roof_type: Some("Open".to_string()),
+                left_line: Some(355),
+                center: Some(400),
+                ..FieldInfo::default()
+            }),
+            ..Default::default()
+        });
+        live.game_data.weather = Some(ApiWeather {
+            condition: Some("Sunny".to_string()),
+            temp: Some("59".to_string()),
+            wind: Some(String::new()),
+        });
+        live.game_data.game_info = Some(ApiGameInfo {
+            attendance: Some(40179),
+            first_pitch: None,
+            game_duration_minutes: Some(160),
+        });
+        live.live_data.boxscore.officials = vec![Official {
+            official: Person {
+                id: 1,
+                full_name: "Brian O'Nora".to_string(),
+                link: None,
+            },
+            official_type: "Home Plate".to_string(),
+        }];
+
+        let lines: Vec<String> = GameInfo::from_live(&live)
+            .lines(Tz::America__Chicago)
+            .iter()
+            .map(text)
+            .collect();
+        assert_eq!(
+            lines,
+            [
+                "Game Info",
+                "Venue: Wrigley Field",
+                "Field: open roof · LF 355 · CF 400",
+                "Weather: 59°F, Sunny",
+                "Umpires: HP O'Nora",
+                "Game: 2:40 · 40,179 fans",
+            ]
+        );
+    }
+
+    #[test]
+    fn formats_thousands() {
+        assert_eq!(thousands(0), "0");
+        assert_eq!(thousands(999), "999");
+        assert_eq!(thousands(40179), "40,179");
+        assert_eq!(thousands(1234567), "1,234,567");
+    }
+}
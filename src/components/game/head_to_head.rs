@@ -0,0 +1,129 @@
+use mlbt_api::vs_player::{VsPlayerResponse, VsPlayerStat};
+
+/// A batter's history against a pitcher, for their careers and for the current season.
+#[derive(Debug, Default)]
+pub struct HeadToHead {
+    pub season: i32,
+    pub career_totals: Option<Totals>,
+    pub season_totals: Option<Totals>,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Totals {
+    pub at_bats: u16,
+    pub hits: u16,
+    pub home_runs: u16,
+    pub walks: u16,
+    pub strikeouts: u16,
+}
+
+impl From<&VsPlayerStat> for Totals {
+    fn from(stat: &VsPlayerStat) -> Self {
+        Self {
+            at_bats: stat.at_bats,
+            hits: stat.hits,
+            home_runs: stat.home_runs,
+            walks: stat.base_on_balls,
+            strikeouts: stat.strike_outs,
+        }
+    }
+}
+
+impl Totals {
+    /// E.g. "4-for-11, 2 HR, 3 K", leaving out counts that are zero.
+    fn format(&self) -> String {
+        let mut parts = vec![format!("{}-for-{}", self.hits, self.at_bats)];
+        for (count, label) in [
+            (self.home_runs, "HR"),
+            (self.walks, "BB"),
+            (self.strikeouts, "K"),
+        ] {
+            if count > 0 {
+                parts.push(format!("{count} {label}"));
+            }
+        }
+        parts.join(", ")
+    }
+
+    fn is_empty(&self) -> bool {
+        *self == Totals::default()
+    }
+}
+
+impl HeadToHead {
+    pub fn from_response(response: &VsPlayerResponse, season: i32) -> Self {
+        Self {
+            season,
+            career_totals: response.career().map(Totals::from),
+            season_totals: response.season(season).map(Totals::from),
+        }
+    }
+
+    /// One line summary for the at bat panel. The season is only added when it differs from the
+    /// career totals, i.e. they also faced each other in earlier seasons.
+    pub fn summary(&self) -> String {
+        let career = match self.career_totals {
+            Some(career) if !career.is_empty() => career,
+            _ => return "vs this pitcher: first meeting".to_string(),
+        };
+        match self.season_totals {
+            Some(season) if !season.is_empty() && season != career => format!(
+                "vs this pitcher: {} ({}: {})",
+                career.format(),
+                self.season,
+                season.format()
+            ),
+            _ => format!("vs this pitcher: {}", career.format()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn totals(at_bats: u16, hits: u16, home_runs: u16, strikeouts: u16) -> Option<Totals> {
+        Some(Totals {
+            at_bats,
+            hits,
+            home_runs,
+            walks: 0,
+            strikeouts,
+        })
+    }
+
+    #[test]
+    fn summary_leaves_out_zero_counts() {
+        let h2h = HeadToHead {
+            season: 2026,
+            career_totals: totals(11, 4, 2, 0),
+            season_totals: None,
+        };
+        assert_eq!(h2h.summary(), "vs this pitcher: 4-for-11, 2 HR");
+    }
+
+    #[test]
+    fn summary_adds_season_when_it_differs() {
+        let h2h = HeadToHead {
+            season: 2026,
+            career_totals: totals(11, 4, 2, 3),
+            season_totals: totals(3, 1, 0, 1),
+        };
+        assert_eq!(
+            h2h.summary(),
+            "vs this pitcher: 4-for-11, 2 HR, 3 K (2026: 1-for-3, 1 K)"
+        );
+
+        let same = HeadToHead {
+            season_totals: h2h.career_totals,
+            ..h2h
+        };
+        assert_eq!(same.summary(), "vs this pitcher: 4-for-11, 2 HR, 3 K");
+    }
+
+    #[test]
+    fn summary_without_history() {
+        let h2h = HeadToHead::from_response(&VsPlayerResponse::default(), 2026);
+        assert_eq!(h2h.summary(), "vs this pitcher: first meeting");
+    }
+}
@@ -1,5 +1,7 @@
 use crate::components::constants::lookup_team_or;
 use crate::components::game::at_bat::AtBat;
+use crate::components::game::game_info::GameInfo;
+use crate::components::game::head_to_head::HeadToHead;
 use crate::components::game::player::{Player, PlayerStats};
 use crate::components::game::win_probability::WinProbability;
 use crate::components::linescore::LineScore;
@@ -35,6 +37,10 @@ pub struct GameState {
     /// Remaining ABS challenges, if available.
     pub away_abs_challenges: Option<u8>,
     pub abstract_game_state: Option<AbstractGameState>,
+    pub info: GameInfo,
+    /// Batter vs pitcher history keyed by `(batter, pitcher)`. `None` while the request is in
+    /// flight so each matchup is only requested once per game.
+    pub head_to_head: HashMap<(PlayerId, PlayerId), Option<HeadToHead>>,
 }
 
 impl GameState {
@@ -49,6 +55,7 @@ impl GameState {
         self.set_on_deck(live_data);
         self.set_abs_challenges(live_data);
         self.abstract_game_state = live_data.game_data.status.abstract_game_state;
+        self.info = GameInfo::from_live(live_data);
         self.current_at_bat = Self::get_current_play_ab_index(live_data);
         self.linescore = LineScore::from_live_data(live_data, &self.home_team, &self.away_team);
         if let Some(plays) = &live_data.live_data.plays.all_plays {
@@ -1,4 +1,7 @@
 pub mod at_bat;
+pub mod content;
+pub mod game_info;
+pub mod head_to_head;
 pub mod live_game;
 pub mod matchup;
 pub mod pitch_event;
@@ -6,5 +9,6 @@ pub mod pitches;
 pub mod player;
 pub mod plays;
 mod review;
+pub mod spray_chart;
 pub mod strikezone;
 pub mod win_probability;
@@ -63,11 +63,12 @@ impl From<&mlbt_api::plays::PlayEvent> for PitchEvent {
 
 impl PitchEvent {
     /// Convert a pitch event into a TUI Line item.
-    /// If it's a pitch, display the pitch information.
+    /// If it's a pitch, display the pitch information, and the tracking data if it's `selected`.
     /// Otherwise, display the description.
     pub fn as_lines(
         &self,
         debug: bool,
+        selected: bool,
         home_team: &Team,
         away_team: &Team,
         players: &PlayerMap,
@@ -76,7 +77,7 @@ impl PitchEvent {
             PitchEventType::Pitch if self.pitch.is_some() => self
                 .pitch
                 .as_ref()
-                .map(|pitch| pitch.as_lines(debug, home_team, away_team, players)),
+                .map(|pitch| pitch.as_lines(debug, selected, home_team, away_team, players)),
             PitchEventType::Pitch => None,
             _ => Some(self.format_non_pitch_event(home_team.abbreviation, away_team.abbreviation)),
         }
@@ -3,7 +3,8 @@ use crate::components::game::pitch_event::PitchEvent;
 use crate::components::game::review::ReviewDetails;
 use crate::components::game::strikezone::{DEFAULT_SZ_BOT, DEFAULT_SZ_TOP};
 use crate::components::standings::Team;
-use crate::ui::styling::convert_color;
+use crate::ui::gameday::plays::SELECTION_SYMBOL;
+use crate::ui::styling::{convert_color, dim_style};
 use mlbt_api::plays::{Play, PlayEvent};
 use tui::{
     style::{Color, Style},
@@ -30,6 +31,75 @@ pub struct Pitch {
     pub strike_zone_top: f64,
     pub count: Count,
     pub review_details: Option<ReviewDetails>,
+    pub tracking: PitchTracking,
+}
+
+/// Pitch tracking details, shown when the pitch is selected. Missing values are left out.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct PitchTracking {
+    /// Movement compared to a spinless pitch as (horizontal, vertical), in inches.
+    pub movement: Option<(f64, f64)>,
+    /// Feet in front of the rubber.
+    pub extension: Option<f64>,
+    /// Release point as (horizontal, height), in feet.
+    pub release: Option<(f64, f64)>,
+    /// In inches.
+    pub induced_vertical_break: Option<f64>,
+    /// In inches.
+    pub horizontal_break: Option<f64>,
+    pub spin_rate: Option<u32>,
+    /// Degrees, 180 is pure backspin.
+    pub spin_axis: Option<u32>,
+    /// Where the pitch crossed the plate as (horizontal, height), in feet.
+    pub plate: Option<(f64, f64)>,
+}
+
+impl From<&mlbt_api::plays::PitchData> for PitchTracking {
+    fn from(data: &mlbt_api::plays::PitchData) -> Self {
+        let c = &data.coordinates;
+        let both = |a: Option<f64>, b: Option<f64>| a.zip(b);
+        let breaks = data.breaks.as_ref();
+        Self {
+            movement: both(c.pfx_x, c.pfx_z),
+            extension: data.extension,
+            release: data.release_point(),
+            induced_vertical_break: breaks.and_then(|b| b.break_vertical_induced),
+            horizontal_break: breaks.and_then(|b| b.break_horizontal),
+            spin_rate: breaks.and_then(|b| b.spin_rate),
+            spin_axis: breaks.and_then(|b| b.spin_direction),
+            plate: both(c.p_x, c.p_z),
+        }
+    }
+}
+
+impl PitchTracking {
+    /// E.g. `["IVB: 18.7\" | HB: 8.0\" | pfx: -5.0\", 10.8\"", ...]`, with one line each for
+    /// movement, spin and position. Lines without any values are skipped.
+    pub fn format(&self) -> Vec<String> {
+        let pair = |(a, b): (f64, f64), unit: &str| format!("{a:.1}{unit}, {b:.1}{unit}");
+        let lines = [
+            vec![
+                self.induced_vertical_break
+                    .map(|v| format!("IVB: {v:.1}\"")),
+                self.horizontal_break.map(|v| format!("HB: {v:.1}\"")),
+                self.movement.map(|m| format!("pfx: {}", pair(m, "\""))),
+            ],
+            vec![
+                self.spin_rate.map(|v| format!("spin: {v} rpm")),
+                self.spin_axis.map(|v| format!("axis: {v}°")),
+                self.extension.map(|v| format!("ext: {v:.1}'")),
+            ],
+            vec![
+                self.release.map(|r| format!("release: {}", pair(r, "'"))),
+                self.plate.map(|p| format!("plate: {}", pair(p, "'"))),
+            ],
+        ];
+        lines
+            .into_iter()
+            .map(|parts| parts.into_iter().flatten().collect::<Vec<_>>().join(" | "))
+            .filter(|line| !line.is_empty())
+            .collect()
+    }
 }
 
 impl Default for Pitch {
@@ -46,6 +116,7 @@ impl Default for Pitch {
             strike_zone_top: DEFAULT_SZ_TOP,
             count: Count::default(),
             review_details: None,
+            tracking: PitchTracking::default(),
         }
     }
 }
@@ -77,8 +148,8 @@ impl From<&PlayEvent> for Pitch {
         // x coordinate is left/right
         // y coordinate is catcher looking towards pitcher
         // z coordinate is up/down
-        let x_coord = pitch_coords.get("pX").unwrap_or(&0.0);
-        let z_coord = pitch_coords.get("pZ").unwrap_or(&2.0);
+        let x_coord = pitch_coords.p_x.unwrap_or(0.0);
+        let z_coord = pitch_coords.p_z.unwrap_or(2.0);
 
         Pitch {
             strike: pitch_details.is_strike.unwrap_or(false),
@@ -95,12 +166,13 @@ impl From<&PlayEvent> for Pitch {
                 .as_ref()
                 .map(|pt| pt.description.clone())
                 .unwrap_or_default(),
-            location: (*x_coord, *z_coord),
+            location: (x_coord, z_coord),
             index: play.pitch_number.unwrap_or_default(),
             strike_zone_bot: pitch_data.strike_zone_bottom.unwrap_or(DEFAULT_SZ_BOT),
             strike_zone_top: pitch_data.strike_zone_top.unwrap_or(DEFAULT_SZ_TOP),
             count: play.count.clone().into(),
             review_details: play.review_details.as_ref().map(ReviewDetails::from),
+            tracking: PitchTracking::from(pitch_data),
         }
     }
 }
@@ -122,10 +194,12 @@ impl Pitch {
     /// Convert a pitch into a TUI Line item, displaying the pitch index, result (ball, strike, ect)
     /// and pitch type (cutter, changeup, ect). For example: "1  Foul | Four-Seam Fastball"
     /// If the pitch is under review, a new line will displayed under the pitch with the player and
-    /// team that initiated the review.
+    /// team that initiated the review. A selected pitch is marked and followed by its tracking
+    /// data.
     pub fn as_lines(
         &self,
         debug: bool,
+        selected: bool,
         home_team: &Team,
         away_team: &Team,
         players: &PlayerMap,
@@ -141,13 +215,23 @@ impl Pitch {
             lines.push(Line::from(in_progress_spans));
         }
 
+        let marker = if selected { SELECTION_SYMBOL } else { ' ' };
         let mut pitch_line_spans = vec![Span::styled(
-            format!(" {:<2}", self.index),
+            format!("{marker}{:<2}", self.index),
             Style::default().fg(self.color),
         )];
         pitch_line_spans.extend(self.format_spans(debug, players));
         lines.push(Line::from(pitch_line_spans));
 
+        if selected {
+            lines.extend(
+                self.tracking
+                    .format()
+                    .into_iter()
+                    .map(|line| Line::styled(format!("    {line}"), dim_style())),
+            );
+        }
+
         lines
     }
 
@@ -177,3 +261,27 @@ impl From<&Play> for Pitches {
         Pitches { pitch_events }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tracking_skips_missing_values() {
+        let tracking = PitchTracking {
+            movement: Some((-4.95, 10.81)),
+            induced_vertical_break: Some(18.7),
+            spin_rate: Some(2295),
+            spin_axis: Some(204),
+            ..PitchTracking::default()
+        };
+        assert_eq!(
+            tracking.format(),
+            [
+                "IVB: 18.7\" | pfx: -5.0\", 10.8\"",
+                "spin: 2295 rpm | axis: 204°",
+            ]
+        );
+        assert!(PitchTracking::default().format().is_empty());
+    }
+}
@@ -0,0 +1,144 @@
+use crate::components::game::live_game::PlayerId;
+use crate::ui::gameday::plays::{BLUE, GREEN, RED};
+use mlbt_api::plays::Play;
+use tui::prelude::Color;
+
+/// Home plate in the Gameday coordinate space, see `mlbt_api::plays::HitCoordinates`.
+const HOME_PLATE_X: f64 = 125.42;
+const HOME_PLATE_Y: f64 = 198.27;
+/// Feet per Gameday coordinate unit.
+const FEET_PER_UNIT: f64 = 2.5;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum HitOutcome {
+    Single,
+    Double,
+    Triple,
+    HomeRun,
+    /// Outs, errors, fielder's choices, etc.
+    Other,
+}
+
+impl HitOutcome {
+    /// All outcomes in the order they are shown in the legend.
+    pub const ALL: [HitOutcome; 5] = [
+        HitOutcome::Single,
+        HitOutcome::Double,
+        HitOutcome::Triple,
+        HitOutcome::HomeRun,
+        HitOutcome::Other,
+    ];
+
+    fn from_event_type(event_type: &str) -> Self {
+        match event_type {
+            "single" => HitOutcome::Single,
+            "double" => HitOutcome::Double,
+            "triple" => HitOutcome::Triple,
+            "home_run" => HitOutcome::HomeRun,
+            _ => HitOutcome::Other,
+        }
+    }
+
+    pub fn color(&self) -> Color {
+        match self {
+            HitOutcome::Single => GREEN,
+            HitOutcome::Double => BLUE,
+            HitOutcome::Triple => Color::Yellow,
+            HitOutcome::HomeRun => RED,
+            HitOutcome::Other => Color::DarkGray,
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            HitOutcome::Single => "1B",
+            HitOutcome::Double => "2B",
+            HitOutcome::Triple => "3B",
+            HitOutcome::HomeRun => "HR",
+            HitOutcome::Other => "out/other",
+        }
+    }
+}
+
+/// A ball in play with where it landed or was fielded. The position is in feet from home plate,
+/// with x towards first base and y towards center field.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BattedBall {
+    pub batter_id: PlayerId,
+    pub pitcher_id: PlayerId,
+    pub x: f64,
+    pub y: f64,
+    pub outcome: HitOutcome,
+}
+
+impl BattedBall {
+    /// The ball in play of a plate appearance, if there was one with coordinates.
+    pub fn from_play(play: &Play) -> Option<Self> {
+        let coordinates = play
+            .play_events
+            .iter()
+            .rev()
+            .find_map(|event| event.hit_data.as_ref()?.coordinates)?;
+        let (x, y) = (coordinates.coord_x?, coordinates.coord_y?);
+        let outcome = play
+            .result
+            .event_type
+            .as_deref()
+            .map(HitOutcome::from_event_type)
+            .unwrap_or(HitOutcome::Other);
+        Some(Self {
+            batter_id: play.matchup.batter.id,
+            pitcher_id: play.matchup.pitcher.id,
+            x: (x - HOME_PLATE_X) * FEET_PER_UNIT,
+            y: (HOME_PLATE_Y - y) * FEET_PER_UNIT,
+            outcome,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mlbt_api::plays::{HitCoordinates, HitData, PlayEvent};
+
+    fn play(event_type: &str, coordinates: Option<HitCoordinates>) -> Play {
+        let mut play = Play::default();
+        play.result.event_type = Some(event_type.to_string());
+        play.matchup.batter.id = 1;
+        play.play_events = vec![
+            PlayEvent::default(),
+            PlayEvent {
+                hit_data: Some(HitData {
+                    coordinates,
+                    ..HitData::default()
+                }),
+                ..PlayEvent::default()
+            },
+        ];
+        play
+    }
+
+    #[test]
+    fn converts_coordinates_to_feet_from_home() {
+        let coordinates = HitCoordinates {
+            coord_x: Some(175.92),
+            coord_y: Some(105.36),
+        };
+        let ball = BattedBall::from_play(&play("single", Some(coordinates))).unwrap();
+        assert_eq!(ball.outcome, HitOutcome::Single);
+        assert_eq!(ball.batter_id, 1);
+        // right field
+        assert!((ball.x - 126.25).abs() < 0.01);
+        assert!((ball.y - 232.275).abs() < 0.01);
+    }
+
+    #[test]
+    fn requires_both_coordinates() {
+        let partial = HitCoordinates {
+            coord_x: Some(120.0),
+            coord_y: None,
+        };
+        assert!(BattedBall::from_play(&play("field_out", Some(partial))).is_none());
+        assert!(BattedBall::from_play(&play("strikeout", None)).is_none());
+    }
+}
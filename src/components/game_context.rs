@@ -0,0 +1,118 @@
+use chrono::{Datelike, NaiveDate};
+use mlbt_api::schedule::{DayNight, DoubleHeader, Game};
+
+/// Series, doubleheader and suspension context for a game from the schedule.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct GameContext {
+    /// (game number, games in series), only for series longer than one game.
+    pub series: Option<(u8, u8)>,
+    /// Which game of a doubleheader this is.
+    pub doubleheader_game: Option<u64>,
+    pub day_night: Option<DayNight>,
+    pub suspended: bool,
+    /// The date a suspended game will be finished, if it has been scheduled.
+    pub resumes: Option<NaiveDate>,
+}
+
+impl GameContext {
+    pub fn from_game(game: &Game) -> Self {
+        let series = match (game.series_game_number, game.games_in_series) {
+            (Some(number), Some(total)) if total > 1 => Some((number, total)),
+            _ => None,
+        };
+        let doubleheader_game = match game.double_header {
+            Some(DoubleHeader::Y | DoubleHeader::S) => game.game_number,
+            _ => None,
+        };
+        let suspended = game
+            .status
+            .detailed_state
+            .as_deref()
+            .is_some_and(|s| s.starts_with("Suspended"));
+        Self {
+            series,
+            doubleheader_game,
+            day_night: game.day_night,
+            suspended,
+            resumes: game.resume_game_date,
+        }
+    }
+
+    /// Labels for the schedule status column, e.g. "Gm 2 of 3", "DH Game 2" or
+    /// "Suspended, resumes 7/14".
+    pub fn labels(&self) -> Vec<String> {
+        let mut labels = Vec::new();
+        if let Some((number, total)) = self.series {
+            labels.push(format!("Gm {number} of {total}"));
+        }
+        if let Some(game) = self.doubleheader_game {
+            labels.push(format!("DH Game {game}"));
+        }
+        if let Some(label) = self.suspended_label() {
+            labels.push(label);
+        }
+        labels
+    }
+
+    /// Short marker for narrow tables, e.g. "DH2" or "Susp".
+    pub fn short_label(&self) -> Option<String> {
+        if self.suspended {
+            return Some("Susp".to_string());
+        }
+        self.doubleheader_game.map(|game| format!("DH{game}"))
+    }
+
+    fn suspended_label(&self) -> Option<String> {
+        if !self.suspended {
+            return None;
+        }
+        Some(match self.resumes {
+            Some(date) => format!("Suspended, resumes {}/{}", date.month(), date.day()),
+            None => "Suspended".to_string(),
+        })
+    }
+
+    pub fn day_night_symbol(&self) -> &'static str {
+        match self.day_night {
+            Some(DayNight::Day) => "☀",
+            Some(DayNight::Night) => "☾",
+            None => " ",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn labels_for_a_suspended_doubleheader_game() {
+        let context = GameContext {
+            series: Some((2, 3)),
+            doubleheader_game: Some(2),
+            day_night: Some(DayNight::Night),
+            suspended: true,
+            resumes: NaiveDate::from_ymd_opt(2025, 7, 14),
+        };
+        assert_eq!(
+            context.labels(),
+            ["Gm 2 of 3", "DH Game 2", "Suspended, resumes 7/14"]
+        );
+        assert_eq!(context.short_label().as_deref(), Some("Susp"));
+        assert_eq!(context.day_night_symbol(), "☾");
+    }
+
+    #[test]
+    fn single_game_series_has_no_labels() {
+        let game = Game {
+            games_in_series: Some(1),
+            series_game_number: Some(1),
+            double_header: Some(DoubleHeader::N),
+            game_number: Some(1),
+            ..Game::default()
+        };
+        let context = GameContext::from_game(&game);
+        assert!(context.labels().is_empty());
+        assert_eq!(context.short_label(), None);
+    }
+}
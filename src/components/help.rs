@@ -11,7 +11,7 @@ pub const DOCS_LEN: usize = GENERAL_DOCS.len()
     + TEAM_PAGE_DOCS.len()
     + PLAYER_PROFILE_DOCS.len();
 
-const GENERAL_DOCS: &[&[&str; 2]; 9] = &[
+const GENERAL_DOCS: &[&[&str; 2]; 11] = &[
     &["Exit help", "Esc"],
     &["Move down", "j/↓"],
     &["Move up", "k/↑"],
@@ -19,10 +19,12 @@ const GENERAL_DOCS: &[&[&str; 2]; 9] = &[
     &["Page up", "Shift + k/↑"],
     &["Quit", "q"],
     &["Full screen", "f"],
+    &["Search players", "/"],
+    &["Retry failed request", "R"],
     &["Focus settings", "Tab"],
     &["Change setting", "Enter"],
 ];
-const SCOREBOARD_DOCS: &[&[&str; 2]; 10] = &[
+const SCOREBOARD_DOCS: &[&[&str; 2]; 12] = &[
     &["Scoreboard", "1"],
     &["Move down", "j/↓"],
     &["Move up", "k/↑"],
@@ -32,9 +34,11 @@ const SCOREBOARD_DOCS: &[&[&str; 2]; 10] = &[
     &["Scroll boxscore down", "Shift + j/↓"],
     &["Scroll boxscore up", "Shift + k/↑"],
     &["Toggle win probability", "w"],
+    &["Toggle recap/preview", "r"],
     &["Toggle sort mode", "s"],
+    &["Switch MLB/minor league level", "v"],
 ];
-const GAMEDAY_DOCS: &[&[&str; 2]; 13] = &[
+const GAMEDAY_DOCS: &[&[&str; 2]; 19] = &[
     &["Gameday", "2"],
     &["Toggle game info", "i"],
     &["Toggle pitches", "p"],
@@ -43,15 +47,22 @@ const GAMEDAY_DOCS: &[&[&str; 2]; 13] = &[
     &["Scroll boxscore down", "Shift + j/↓"],
     &["Scroll boxscore up", "Shift + k/↑"],
     &["Toggle win probability", "w"],
+    &["Toggle venue/weather/umpires", "g"],
+    &["Toggle spray chart", "c"],
+    &["Switch spray chart batter/game", "C"],
+    &["Toggle recap/preview", "r"],
     &["Move down at bat", "j/↓"],
     &["Move up at bat", "k/↑"],
     &["Go to live at bat", "l"],
     &["Go to first at bat", "s"],
+    &["Select next pitch", "]"],
+    &["Select previous pitch", "["],
     &["Toggle scoring plays only", "!"],
 ];
-const STATS_DOCS: &[&[&str; 2]; 17] = &[
+const STATS_DOCS: &[&[&str; 2]; 20] = &[
     &["Stats", "3"],
     &["Switch hitting/pitching", "h/p"],
+    &["Switch fielding/catching/running", "e/c/n"],
     &["Switch team/player", "t/l"],
     &["Switch all/qualified", "a/u"],
     &["Switch pane", "←/→/Tab"],
@@ -61,6 +72,8 @@ const STATS_DOCS: &[&[&str; 2]; 17] = &[
     &["Page up", "Shift + k/↑"],
     &["View player/team", "Enter"],
     &["Select date", ":"],
+    &["Switch MLB/minor league level", "v"],
+    &["Toggle league leaders", "L"],
     &["Search", " "],
     &[" Fuzzy search", "Ctrl + f"],
     &["Options", " "],
@@ -68,15 +81,17 @@ const STATS_DOCS: &[&[&str; 2]; 17] = &[
     &[" Sort by stat", "s"],
     &[" Toggle options pane", "o"],
 ];
-const STANDINGS_DOCS: &[&[&str; 2]; 6] = &[
+const STANDINGS_DOCS: &[&[&str; 2]; 8] = &[
     &["Standings", "4"],
     &["Move down", "j/↓"],
     &["Move up", "k/↑"],
     &["View team", "Enter"],
     &["Select date", ":"],
     &["Toggle division/league", "l"],
+    &["Toggle postseason bracket", "b"],
+    &["Switch MLB/minor league level", "v"],
 ];
-const TEAM_PAGE_DOCS: &[&[&str; 2]; 10] = &[
+const TEAM_PAGE_DOCS: &[&[&str; 2]; 13] = &[
     &["Team Page", " "],
     &[" Switch section", "←/→/Tab"],
     &[" Move down", "j/↓"],
@@ -84,13 +99,17 @@ const TEAM_PAGE_DOCS: &[&[&str; 2]; 10] = &[
     &[" Page down", "Shift + j/↓"],
     &[" Page up", "Shift + k/↑"],
     &[" Toggle calendar", "c"],
-    &[" Toggle roster type", "r"],
+    &[" Cycle roster view", "r"],
     &[" View player", "Enter"],
+    &[" Open draft", "d"],
+    &[" Draft year", "←/→"],
+    &[" Draft team/all picks", "t"],
     &[" Close team page", "Esc"],
 ];
-const PLAYER_PROFILE_DOCS: &[&[&str; 2]; 7] = &[
+const PLAYER_PROFILE_DOCS: &[&[&str; 2]; 8] = &[
     &["Player Profile", " "],
-    &[" Toggle category", "s"],
+    &[" Season type", "s"],
+    &[" Toggle spray chart", "c"],
     &[" Scroll down", "j/↓"],
     &[" Scroll up", "k/↑"],
     &[" Page down", "Shift + j/↓"],
@@ -1,11 +1,14 @@
 pub mod banner;
 pub mod boxscore;
+pub mod bracket;
+pub mod broadcasts;
 pub mod constants;
 pub mod date_selector;
 pub mod datetime;
 pub mod debug;
 pub mod decision_pitchers;
 pub mod game;
+pub mod game_context;
 pub mod help;
 pub mod linescore;
 pub mod probable_pitchers;
@@ -1,3 +1,4 @@
+use crate::components::broadcasts::GameBroadcast;
 use crate::components::standings::Team;
 use crate::components::util::OptionDisplayExt;
 use mlbt_api::schedule::TeamInfo;
@@ -18,6 +19,7 @@ pub struct ProbablePitcherMatchup<'a> {
     pub home_team: Team,
     pub away_pitcher: &'a ProbablePitcher,
     pub away_team: Team,
+    pub broadcasts: &'a [GameBroadcast],
 }
 
 impl Default for ProbablePitcher {
@@ -1,7 +1,9 @@
+use crate::components::broadcasts::GameBroadcast;
 use crate::components::constants::lookup_team_or;
 use crate::components::date_selector::DateSelector;
 use crate::components::datetime::format_game_time_padded;
 use crate::components::decision_pitchers::GameDecisionPitchers;
+use crate::components::game_context::GameContext;
 use crate::components::probable_pitchers::{ProbablePitcher, ProbablePitcherMatchup};
 use crate::components::standings::Team;
 use crate::state::app_settings::AppSettings;
@@ -10,6 +12,7 @@ use chrono::{DateTime, NaiveDate, Utc};
 use chrono_tz::Tz;
 use core::option::Option::{None, Some};
 use mlbt_api::schedule::{AbstractGameState, Game, LeagueRecord, ScheduleResponse};
+use mlbt_api::teams::SportId;
 use std::cmp::Ordering;
 use tui::widgets::TableState;
 
@@ -24,7 +27,11 @@ pub struct ScheduleState {
     pub state: TableState,
     pub schedule: Vec<ScheduleRow>,
     pub date_selector: DateSelector,
+    /// MLB or one of the minor league levels.
+    pub sport: SportId,
     pub show_win_probability: bool,
+    /// Show the selected game's recap or preview in place of the box score.
+    pub show_recap: bool,
     pub sort_mode: SortMode,
 }
 
@@ -34,7 +41,9 @@ impl Default for ScheduleState {
             state: TableState::default(),
             schedule: Vec::new(),
             date_selector: DateSelector::default(),
+            sport: SportId::default(),
             show_win_probability: true,
+            show_recap: false,
             sort_mode: SortMode::Time,
         }
     }
@@ -60,6 +69,8 @@ pub struct ScheduleRow {
     pub decision_pitchers: Option<GameDecisionPitchers>,
     pub abstract_game_state: Option<AbstractGameState>,
     pub current_inning: Option<i64>,
+    pub context: GameContext,
+    pub broadcasts: Vec<GameBroadcast>,
 }
 
 #[derive(Default, Copy, Clone)]
@@ -130,6 +141,7 @@ impl ScheduleState {
             home_team: row.home_team,
             away_pitcher: &row.away_probable_pitcher,
             away_team: row.away_team,
+            broadcasts: &row.broadcasts,
         })
     }
 
@@ -147,6 +159,10 @@ impl ScheduleState {
         self.show_win_probability = !self.show_win_probability;
     }
 
+    pub fn toggle_recap(&mut self) {
+        self.show_recap = !self.show_recap;
+    }
+
     /// Re-render every row's `start_time` string using the given timezone.
     /// Called after the user changes timezone so times update without a schedule refetch.
     pub fn refresh_start_times(&mut self, tz: Tz) {
@@ -302,6 +318,8 @@ impl ScheduleRow {
             decision_pitchers: GameDecisionPitchers::from_game(game),
             abstract_game_state: game.status.abstract_game_state,
             current_inning: game.linescore.as_ref().and_then(|l| l.current_inning),
+            context: GameContext::from_game(game),
+            broadcasts: GameBroadcast::from_game(game),
         }
     }
 
@@ -427,6 +445,8 @@ mod tests {
             decision_pitchers: None,
             abstract_game_state: None,
             current_inning: None,
+            context: GameContext::default(),
+            broadcasts: Vec::new(),
         }
     }
 
@@ -436,7 +456,9 @@ mod tests {
             state: TableState::default(),
             schedule: vec![row(30, 114, 115), row(10, 108, 109), row(20, 112, 113)],
             date_selector: DateSelector::default(),
+            sport: SportId::default(),
             show_win_probability: true,
+            show_recap: false,
             sort_mode: SortMode::Time,
         };
         state.state.select(Some(2));
@@ -461,7 +483,9 @@ mod tests {
             state: TableState::default(),
             schedule: vec![row(30, 114, 115), row(10, 108, 109), row(20, 112, 113)],
             date_selector: DateSelector::default(),
+            sport: SportId::default(),
             show_win_probability: true,
+            show_recap: false,
             sort_mode: SortMode::Time,
         };
         state.state.select(Some(0));
@@ -504,6 +528,8 @@ mod tests {
             decision_pitchers: None,
             abstract_game_state: state,
             current_inning: inning,
+            context: GameContext::default(),
+            broadcasts: Vec::new(),
         }
     }
 
@@ -577,7 +603,9 @@ mod tests {
             state: TableState::default(),
             schedule: vec![row(30, 114, 115), row(10, 108, 109), row(20, 112, 113)],
             date_selector: DateSelector::default(),
+            sport: SportId::default(),
             show_win_probability: true,
+            show_recap: false,
             sort_mode: SortMode::Time,
         };
         state.state.select(Some(1));
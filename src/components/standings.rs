@@ -1,15 +1,17 @@
-use crate::components::constants::{DIVISION_ORDERS, DIVISIONS, lookup_team, lookup_team_by_id};
+use crate::components::bracket::Bracket;
+use crate::components::constants::{DIVISION_ORDERS, DIVISIONS, lookup_team_by_id, lookup_team_or};
 use crate::components::date_selector::DateSelector;
 use crate::state::team_page::TeamPageState;
 use crate::ui::styling::TEXT_COLOR;
 use crate::ui::styling::win_pct_color;
-use chrono::NaiveDate;
+use chrono::{Datelike, NaiveDate};
 use chrono_tz::Tz;
 use mlbt_api::player::PeopleResponse;
-use mlbt_api::schedule::ScheduleResponse;
+use mlbt_api::schedule::{PostseasonSeriesResponse, ScheduleResponse};
 use mlbt_api::season::GameType;
 use mlbt_api::standings::{RecordElement, StandingsResponse, TeamRecord};
 use mlbt_api::team::{RosterResponse, RosterType, TransactionsResponse};
+use mlbt_api::teams::SportId;
 use std::collections::HashSet;
 use std::string::ToString;
 use std::sync::Arc;
@@ -31,7 +33,12 @@ pub struct StandingsState {
     pub league_standings: Vec<Standing>,
     pub team_ids: Vec<u16>,
     pub date_selector: DateSelector,
+    /// MLB or one of the minor league levels.
+    pub sport: SportId,
     pub view_mode: ViewMode,
+    /// Show the postseason bracket instead of the standings table.
+    pub show_bracket: bool,
+    pub bracket: Option<Bracket>,
     /// Used to skip selecting division names in the table.
     division_row_indices: HashSet<usize>,
     pub team_page: Option<TeamPageState>,
@@ -122,7 +129,10 @@ impl Default for StandingsState {
             league_standings: vec![],
             team_ids: vec![200, 201, 202, 203, 204, 205],
             date_selector: DateSelector::default(),
+            sport: SportId::default(),
             view_mode: ViewMode::ByDivision,
+            show_bracket: false,
+            bracket: None,
             division_row_indices: HashSet::new(),
             favorite_team: None,
             team_page: None,
@@ -184,6 +194,18 @@ impl StandingsState {
         self.date_selector.set_date_with_arrows(forward)
     }
 
+    /// Update the postseason bracket, ignoring responses for a season that is no longer selected.
+    pub fn update_bracket(&mut self, season: i32, series: &PostseasonSeriesResponse) {
+        if season == self.date_selector.date.year() {
+            self.bracket = Some(Bracket::from_response(series));
+        }
+    }
+
+    /// Toggle between the standings table and the postseason bracket.
+    pub fn toggle_bracket(&mut self) {
+        self.show_bracket = !self.show_bracket;
+    }
+
     /// Toggle between division view and overall view
     pub fn toggle_view_mode(&mut self) {
         self.view_mode = match self.view_mode {
@@ -270,12 +292,14 @@ impl StandingsState {
         self.team_page.is_some()
     }
 
-    /// Close the top layer overlay. If the overlay is a player profile, close it. Otherwise,
-    /// close the team page.
+    /// Close the top layer overlay. If the overlay is a player profile or the draft browser, close
+    /// it. Otherwise, close the team page.
     pub fn close_overlay(&mut self) {
         if let Some(tp) = &mut self.team_page {
             if tp.player_profile.is_some() {
                 tp.player_profile = None;
+            } else if tp.draft.is_some() {
+                tp.draft = None;
             } else {
                 self.team_page = None;
             }
@@ -315,6 +339,19 @@ impl StandingsState {
         }
     }
 
+    pub fn update_injured_list(
+        &mut self,
+        team_id: u16,
+        roster: &RosterResponse,
+        transactions: &TransactionsResponse,
+    ) {
+        if let Some(tp) = &mut self.team_page
+            && tp.team.id == team_id
+        {
+            tp.update_injured_list(roster, transactions);
+        }
+    }
+
     pub fn update_team_player_profile(&mut self, data: Arc<PeopleResponse>, game_type: GameType) {
         if let Some(tp) = &mut self.team_page {
             tp.update_player_profile(data, game_type);
@@ -409,12 +446,14 @@ impl Division {
             .iter()
             .map(|r| {
                 // Pre-1969 seasons have no divisions, fall back to league.
-                let group_id = r
-                    .division
-                    .as_ref()
-                    .map(|d| d.id as u16)
-                    .unwrap_or(r.league.id as u16);
-                let group_name = DIVISIONS.get(&group_id).unwrap_or(&"Unknown").to_string();
+                let group = r.division.as_ref().unwrap_or(&r.league);
+                let group_id = group.id;
+                let group_name = DIVISIONS
+                    .get(&group_id)
+                    .copied()
+                    .or(group.name.as_deref())
+                    .unwrap_or("Unknown")
+                    .to_string();
                 Division {
                     name: group_name,
                     id: group_id,
@@ -475,7 +514,7 @@ impl Standing {
             .unwrap_or_else(|| "-".to_string());
 
         Standing {
-            team: lookup_team(&team.team.name),
+            team: lookup_team_or(&team.team.name, || Team::from_schedule(&team.team)),
             wins: team.wins,
             losses: team.losses,
             winning_percentage: team.winning_percentage.clone(),
@@ -561,7 +600,10 @@ mod tests {
             league_standings: vec![],
             team_ids: vec![],
             date_selector: DateSelector::default(),
+            sport: SportId::default(),
             view_mode: ViewMode::ByDivision,
+            show_bracket: false,
+            bracket: None,
             division_row_indices: HashSet::new(),
             team_page: None,
         };
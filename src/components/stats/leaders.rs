@@ -0,0 +1,129 @@
+use crate::components::constants::lookup_team_or;
+use crate::components::standings::Team;
+use mlbt_api::leaders::{LeaderCategory, LeagueLeadersResponse};
+
+/// Number of players shown per category.
+pub const LEADERS_LIMIT: u8 = 5;
+
+/// League leaders for a season, one leaderboard per category in `LeaderCategory::ALL` order.
+#[derive(Debug, Default)]
+pub struct Leaders {
+    pub categories: Vec<CategoryLeaderboard>,
+}
+
+#[derive(Debug)]
+pub struct CategoryLeaderboard {
+    pub category: LeaderCategory,
+    pub leaders: Vec<LeaderRow>,
+}
+
+#[derive(Debug)]
+pub struct LeaderRow {
+    pub rank: u16,
+    pub name: String,
+    /// Team abbreviation, empty if the API didn't include a team.
+    pub team: &'static str,
+    pub value: String,
+}
+
+impl Leaders {
+    pub fn from_response(response: &LeagueLeadersResponse) -> Self {
+        let categories = LeaderCategory::ALL
+            .iter()
+            .filter_map(|category| {
+                let leaders = response.category(*category)?;
+                Some(CategoryLeaderboard {
+                    category: *category,
+                    leaders: leaders
+                        .leaders
+                        .iter()
+                        .take(LEADERS_LIMIT as usize)
+                        .map(|leader| LeaderRow {
+                            rank: leader.rank,
+                            name: leader.person.full_name.clone(),
+                            team: leader
+                                .team
+                                .as_ref()
+                                .map(|t| lookup_team_or(&t.name, || Team::from_schedule(t)))
+                                .map(|t| t.abbreviation)
+                                .unwrap_or_default(),
+                            value: leader.value.clone(),
+                        })
+                        .collect(),
+                })
+            })
+            .collect();
+        Self { categories }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.categories.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mlbt_api::leaders::{CategoryLeaders, Leader};
+    use mlbt_api::schedule::IdNameLink;
+    use mlbt_api::stats::Player;
+
+    fn category(name: &str, group: &str, leaders: &[(&str, u16, &str)]) -> CategoryLeaders {
+        CategoryLeaders {
+            leader_category: name.to_string(),
+            season: Some("2024".to_string()),
+            stat_group: Some(group.to_string()),
+            leaders: leaders
+                .iter()
+                .enumerate()
+                .map(|(i, (player, team_id, value))| Leader {
+                    rank: i as u16 + 1,
+                    value: value.to_string(),
+                    team: Some(IdNameLink {
+                        id: *team_id,
+                        name: "New York Yankees".to_string(),
+                        ..IdNameLink::default()
+                    }),
+                    person: Player {
+                        id: i as u64,
+                        full_name: player.to_string(),
+                        first_name: None,
+                        last_name: None,
+                    },
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn orders_categories_and_matches_stat_group() {
+        let response = LeagueLeadersResponse {
+            league_leaders: vec![
+                category("strikeouts", "pitching", &[("Tarik Skubal", 116, "228")]),
+                category("strikeouts", "hitting", &[("Aaron Judge", 147, "171")]),
+                category("homeRuns", "hitting", &[("Aaron Judge", 147, "58")]),
+            ],
+        };
+        let leaders = Leaders::from_response(&response);
+
+        let categories: Vec<_> = leaders.categories.iter().map(|c| c.category).collect();
+        assert_eq!(
+            categories,
+            [LeaderCategory::HomeRuns, LeaderCategory::Strikeouts]
+        );
+        let strikeouts = &leaders.categories[1].leaders[0];
+        assert_eq!(strikeouts.name, "Tarik Skubal");
+        assert_eq!(strikeouts.value, "228");
+        assert_eq!(leaders.categories[0].leaders[0].team, "NYY");
+    }
+
+    #[test]
+    fn limits_rows_per_category() {
+        let players: Vec<_> = (0..8).map(|_| ("Player", 147, "10")).collect();
+        let response = LeagueLeadersResponse {
+            league_leaders: vec![category("saves", "pitching", &players)],
+        };
+        let leaders = Leaders::from_response(&response);
+        assert_eq!(leaders.categories[0].leaders.len(), LEADERS_LIMIT as usize);
+    }
+}
@@ -1,3 +1,4 @@
+pub mod leaders;
 pub mod player_profile;
 pub mod search;
 mod splits;
@@ -37,6 +37,8 @@ const SPLITS_PITCHING_HEADERS: &[&str] = &[
     "Duration", "W", "L", "ERA", "G", "GS", "SV", "IP", "H", "ER", "BB", "SO", "WHIP",
 ];
 const SPLITS_DURATION_WIDTH: u16 = 16;
+const ARSENAL_HEADERS: &[&str] = &["Pitch", "Usage", "Velo", "Spin"];
+const ARSENAL_PITCH_WIDTH: u16 = 20;
 
 /// Player profile data for display. All fields are resolved from the API's optional fields with
 /// sensible defaults.
@@ -52,18 +54,18 @@ pub struct PlayerProfile {
 }
 
 impl PlayerProfile {
-    pub fn from_person(person: PersonFull) -> Self {
-        let (team, is_minor_league) = Self::resolve_team(&person);
-        let bio = Self::bio_lines(&person);
+    pub fn from_person(person: &PersonFull) -> Self {
+        let (team, is_minor_league) = Self::resolve_team(person);
+        let bio = Self::bio_lines(person);
 
         Self {
             id: person.id,
-            name: person.full_name,
+            name: person.full_name.clone(),
             number: person.primary_number.display_or("--"),
             team,
             is_minor_league,
             bio,
-            splits: StatSplits::from_stats(person.stats),
+            splits: StatSplits::from_stats(&person.stats),
         }
     }
 
@@ -200,6 +202,14 @@ impl PlayerProfile {
                     s.whip.as_str().into(),
                 ]);
             }
+            // profiles are only requested for hitting or pitching, and the arsenal has its own table.
+            // Advanced stat types are only requested for the stats table.
+            StatSplit::Fielding(_)
+            | StatSplit::Catching(_)
+            | StatSplit::Running(_)
+            | StatSplit::PitchArsenal(_)
+            | StatSplit::Sabermetrics(_)
+            | StatSplit::Expected(_) => {}
         }
         cells
     }
@@ -253,6 +263,12 @@ impl PlayerProfile {
                     Cell::from(s.era.as_str()).style(era_style(&s.era)),
                 ]);
             }
+            StatSplit::Fielding(_)
+            | StatSplit::Catching(_)
+            | StatSplit::Running(_)
+            | StatSplit::PitchArsenal(_)
+            | StatSplit::Sabermetrics(_)
+            | StatSplit::Expected(_) => {}
         }
         cells
     }
@@ -321,6 +337,39 @@ impl PlayerProfile {
         Some((header, widths, rows))
     }
 
+    /// Build header row, column widths, and data rows for the pitch arsenal table.
+    pub fn build_arsenal_rows(
+        splits: &[Split],
+    ) -> Option<(Row<'_>, Vec<Constraint>, Vec<Row<'_>>)> {
+        if splits.is_empty() {
+            return None;
+        }
+        let mut widths = vec![Constraint::Length(ARSENAL_PITCH_WIDTH)];
+        widths.resize(
+            ARSENAL_HEADERS.len(),
+            Constraint::Length(STAT_COL_WIDTH + 1),
+        );
+
+        let header = Row::new(ARSENAL_HEADERS.to_vec()).style(header_style());
+
+        let rows = splits
+            .iter()
+            .filter_map(|split| match &split.stat {
+                StatSplit::PitchArsenal(s) => Some(Row::new([
+                    Cell::from(s.pitch_type.description.as_str()),
+                    Cell::from(format!("{:.1}%", s.percentage * 100.0)),
+                    Cell::from(format!("{:.1}", s.average_speed)),
+                    s.average_spin_rate
+                        .map_display_or(|spin| format!("{spin:.0}"), "-")
+                        .into(),
+                ])),
+                _ => None,
+            })
+            .collect();
+
+        Some((header, widths, rows))
+    }
+
     /// Build header row, column widths, and data rows for the recent splits table.
     pub fn build_splits_rows(
         recent_splits: &[RecentSplit],
@@ -7,6 +7,8 @@ pub struct StatSplits {
     pub career: Vec<Split>,
     pub game_log: Vec<Split>,
     pub recent_splits: Vec<RecentSplit>,
+    /// Season pitch mix for pitchers, most used pitch first.
+    pub pitch_arsenal: Vec<Split>,
 }
 
 pub struct RecentSplit {
@@ -57,42 +59,55 @@ const SPLIT_WINDOWS: &[(usize, &str)] = &[
 impl StatSplits {
     const RECENT_GAME_LOG_COUNT: usize = 15;
 
-    pub(crate) fn from_stats(stats: Vec<Stat>) -> Self {
-        let mut season = Vec::new();
-        let mut year_by_year = Vec::new();
-        let mut career = Vec::new();
-        let mut game_log = Vec::new();
+    /// Build the splits from borrowed stats so a shared response can be reused, e.g. from the
+    /// network cache. Only the splits that are shown are cloned.
+    pub(crate) fn from_stats(stats: &[Stat]) -> Self {
+        let mut season: &[Split] = &[];
+        let mut year_by_year: &[Split] = &[];
+        let mut career: &[Split] = &[];
+        let mut game_log: &[Split] = &[];
+        let mut pitch_arsenal: &[Split] = &[];
 
         for stat in stats {
             match stat.stat_type.display_name.as_str() {
-                "season" => season = stat.splits,
-                "yearByYear" => year_by_year = stat.splits,
-                "career" => career = stat.splits,
-                "gameLog" => game_log = stat.splits,
+                "season" => season = &stat.splits,
+                "yearByYear" => year_by_year = &stat.splits,
+                "career" => career = &stat.splits,
+                "gameLog" => game_log = &stat.splits,
+                "pitchArsenal" => pitch_arsenal = &stat.splits,
                 _ => {}
             }
         }
 
+        let mut year_by_year = year_by_year.to_vec();
         filter_combined_splits(&mut year_by_year);
+        let mut pitch_arsenal = pitch_arsenal.to_vec();
+        pitch_arsenal.sort_by(|a, b| usage(b).total_cmp(&usage(a)));
 
-        let recent_splits = compute_recent_splits(&game_log);
+        let recent_splits = compute_recent_splits(game_log);
 
         // only keep the most recent games
-        let total = game_log.len();
-        if total > Self::RECENT_GAME_LOG_COUNT {
-            game_log.drain(..total - Self::RECENT_GAME_LOG_COUNT);
-        }
+        let start = game_log.len().saturating_sub(Self::RECENT_GAME_LOG_COUNT);
+        let game_log = game_log[start..].to_vec();
 
         Self {
-            season,
+            season: season.to_vec(),
             year_by_year,
-            career,
+            career: career.to_vec(),
             game_log,
             recent_splits,
+            pitch_arsenal,
         }
     }
 }
 
+fn usage(split: &Split) -> f64 {
+    match &split.stat {
+        StatSplit::PitchArsenal(s) => s.percentage,
+        _ => 0.0,
+    }
+}
+
 /// Compute the stats for the most recent games in the game log. For the last 7 games if there are
 /// less than 7 games, compute the stats for the available games. If less than 15 or 30, don't show
 /// data.
@@ -117,6 +132,12 @@ fn aggregate_stats(splits: &[Split]) -> Option<RecentStats> {
     match &first.stat {
         StatSplit::Hitting(_) => Some(RecentStats::Hitting(aggregate_hitting(splits))),
         StatSplit::Pitching(_) => Some(RecentStats::Pitching(aggregate_pitching(splits))),
+        StatSplit::Fielding(_)
+        | StatSplit::Catching(_)
+        | StatSplit::Running(_)
+        | StatSplit::PitchArsenal(_)
+        | StatSplit::Sabermetrics(_)
+        | StatSplit::Expected(_) => None,
     }
 }
 
@@ -1,8 +1,12 @@
 use crate::components::constants::lookup_team;
 use indexmap::IndexMap;
 use mlbt_api::client::{Qualification, StatGroup};
-use mlbt_api::stats::{HittingStat, PitchingStat, StatSplit, StatsResponse};
+use mlbt_api::stats::{
+    CatchingStat, ExpectedStat, FieldingStat, HittingStat, PitchingStat, RunningStat,
+    SabermetricsStat, StatSplit, StatsResponse,
+};
 use std::cmp::Ordering;
+use std::collections::HashMap;
 use std::string::ToString;
 use std::sync::Arc;
 
@@ -14,12 +18,15 @@ pub const PLAYER_COLUMN_NAME: &str = "Player";
 pub const TEAM_COLUMN_NAME: &str = "Team";
 const DEFAULT_SORT_COLUMN_PITCHING: &str = "IP";
 const DEFAULT_SORT_COLUMN_HITTING: &str = "AB";
+const DEFAULT_SORT_COLUMN_FIELDING: &str = "INN";
+const DEFAULT_SORT_COLUMN_CATCHING: &str = "G";
+const DEFAULT_SORT_COLUMN_RUNNING: &str = "SB";
 
 /// Table data in row oriented form: (header, ids, rows).
 /// `ids` are the player/team id for each row.
 pub type TableData = (Vec<String>, Vec<u64>, Vec<Vec<String>>);
 
-/// Stores whether a team/player and pitching/hitting stat should be viewed.
+/// Stores whether a team/player and which stat group should be viewed.
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 pub struct StatType {
     pub group: StatGroup,
@@ -44,6 +51,9 @@ impl StatType {
             TeamOrPlayer::Player => match self.group {
                 StatGroup::Hitting => "hitters",
                 StatGroup::Pitching => "pitchers",
+                StatGroup::Fielding => "fielders",
+                StatGroup::Catching => "catchers",
+                StatGroup::Running => "runners",
             },
         }
     }
@@ -96,6 +106,9 @@ impl Sort {
         let column_name = match stat_type.group {
             StatGroup::Hitting => DEFAULT_SORT_COLUMN_HITTING.to_string(),
             StatGroup::Pitching => DEFAULT_SORT_COLUMN_PITCHING.to_string(),
+            StatGroup::Fielding => DEFAULT_SORT_COLUMN_FIELDING.to_string(),
+            StatGroup::Catching => DEFAULT_SORT_COLUMN_CATCHING.to_string(),
+            StatGroup::Running => DEFAULT_SORT_COLUMN_RUNNING.to_string(),
         };
         Sort {
             column_name: Some(column_name),
@@ -104,6 +117,14 @@ impl Sort {
     }
 }
 
+/// Stats from the `sabermetrics` and `expectedStatistics` stat types for one player. These come
+/// back as separate splits, so they are merged into the season rows by player id.
+#[derive(Default)]
+struct AdvancedStats<'a> {
+    sabermetrics: Option<&'a SabermetricsStat>,
+    expected: Option<&'a ExpectedStat>,
+}
+
 /// The information for a stat, including all the data values.
 pub struct TableEntry {
     /// Longer description of the stat to be displayed in the options toggle pane.
@@ -151,8 +172,23 @@ impl StatsTable {
         self.row_ids.clear();
         self.sorting = Sort::new(stat_type);
         self.invalidate_cache();
+        let mut advanced: HashMap<u64, AdvancedStats> = HashMap::new();
         for stat in &stats.stats {
             for split in &stat.splits {
+                match (&split.stat, &split.player) {
+                    // only requested for player profiles
+                    (StatSplit::PitchArsenal(_), _) => continue,
+                    (StatSplit::Sabermetrics(s), Some(p)) => {
+                        advanced.entry(p.id).or_default().sabermetrics = Some(s);
+                        continue;
+                    }
+                    (StatSplit::Expected(s), Some(p)) => {
+                        advanced.entry(p.id).or_default().expected = Some(s);
+                        continue;
+                    }
+                    (StatSplit::Sabermetrics(_) | StatSplit::Expected(_), None) => continue,
+                    _ => {}
+                }
                 let team_name = split
                     .team
                     .as_ref()
@@ -172,9 +208,24 @@ impl StatsTable {
                     StatSplit::Hitting(s) => {
                         self.load_hitting_stats(name, team_abbreviation, s, stat_type.team_player)
                     }
+                    StatSplit::Fielding(s) => {
+                        self.load_fielding_stats(name, team_abbreviation, s, stat_type.team_player)
+                    }
+                    StatSplit::Catching(s) => {
+                        self.load_catching_stats(name, team_abbreviation, s, stat_type.team_player)
+                    }
+                    StatSplit::Running(s) => {
+                        self.load_running_stats(name, team_abbreviation, s, stat_type.team_player)
+                    }
+                    StatSplit::PitchArsenal(_)
+                    | StatSplit::Sabermetrics(_)
+                    | StatSplit::Expected(_) => {}
                 };
             }
         }
+        if stat_type.team_player == TeamOrPlayer::Player {
+            self.load_advanced_stats(&advanced, stat_type.group);
+        }
     }
 
     /// Create the header and the table rows from the table map. Basically transforms from columnar
@@ -320,6 +371,181 @@ impl StatsTable {
         self.table_helper("CS", "caught stealing", true, stat.caught_stealing);
     }
 
+    /// Create the fielding stats table. Player splits are per position, so the position column is
+    /// only added for players. Note that the order of the calls to `table_helper` is the order in
+    /// which the stats will be displayed from left to right.
+    fn load_fielding_stats(
+        &mut self,
+        name: String,
+        team_abbreviation: Option<String>,
+        stat: &FieldingStat,
+        team_player: TeamOrPlayer,
+    ) {
+        self.format_name_columns(name, team_abbreviation, team_player);
+        if team_player == TeamOrPlayer::Player {
+            let position = stat
+                .position
+                .as_ref()
+                .map(|p| p.abbreviation.as_str())
+                .unwrap_or_default();
+            self.table_helper("Pos", "position", true, position);
+        }
+        self.table_helper("G", "games played", true, stat.games_played);
+        self.table_helper("GS", "games started", true, stat.games_started);
+        self.table_helper("INN", "innings", true, &stat.innings);
+        self.table_helper("TC", "total chances", true, stat.chances);
+        self.table_helper("PO", "put outs", true, stat.put_outs);
+        self.table_helper("A", "assists", true, stat.assists);
+        self.table_helper("E", "errors", true, stat.errors);
+        self.table_helper("TE", "throwing errors", false, stat.throwing_errors);
+        self.table_helper("DP", "double plays", true, stat.double_plays);
+        self.table_helper("FPCT", "fielding percent", true, &stat.fielding);
+        self.table_helper(
+            "RF/G",
+            "range factor per game",
+            true,
+            &stat.range_factor_per_game,
+        );
+        self.table_helper(
+            "RF/9",
+            "range factor per 9",
+            false,
+            &stat.range_factor_per9_inn,
+        );
+    }
+
+    /// Create the catching stats table. Note that the order of the calls to `table_helper` is the
+    /// order in which the stats will be displayed from left to right.
+    fn load_catching_stats(
+        &mut self,
+        name: String,
+        team_abbreviation: Option<String>,
+        stat: &CatchingStat,
+        team_player: TeamOrPlayer,
+    ) {
+        self.format_name_columns(name, team_abbreviation, team_player);
+        self.table_helper("G", "games played", true, stat.games_played);
+        self.table_helper("SB", "stolen bases allowed", true, stat.stolen_bases);
+        self.table_helper("CS", "caught stealing", true, stat.caught_stealing);
+        let cs_percentage = stat.caught_stealing_percentage.as_deref().unwrap_or("-.--");
+        self.table_helper("CS%", "caught stealing percent", true, cs_percentage);
+        self.table_helper("PB", "passed balls", true, stat.passed_ball);
+        self.table_helper("WP", "wild pitches", true, stat.wild_pitches);
+        self.table_helper("PK", "pickoffs", true, stat.pickoffs);
+        self.table_helper(
+            "CI",
+            "catcher interference",
+            false,
+            stat.catchers_interference,
+        );
+        let catcher_era = stat.catcher_era.as_deref().unwrap_or("-.--");
+        self.table_helper("CERA", "catcher's ERA", true, catcher_era);
+    }
+
+    /// Create the running stats table. Note that the order of the calls to `table_helper` is the
+    /// order in which the stats will be displayed from left to right.
+    fn load_running_stats(
+        &mut self,
+        name: String,
+        team_abbreviation: Option<String>,
+        stat: &RunningStat,
+        team_player: TeamOrPlayer,
+    ) {
+        self.format_name_columns(name, team_abbreviation, team_player);
+        self.table_helper("G", "games played", true, stat.games_played);
+        self.table_helper("R", "runs", true, stat.runs);
+        self.table_helper("SB", "stolen bases", true, stat.stolen_bases);
+        self.table_helper("CS", "caught stealing", true, stat.caught_stealing);
+        self.table_helper(
+            "SB%",
+            "stolen base percent",
+            true,
+            &stat.stolen_base_percentage,
+        );
+        self.table_helper("PK", "times picked off", true, stat.pickoffs);
+        self.table_helper(
+            "GIDP",
+            "grounded into DP",
+            false,
+            stat.ground_into_double_play,
+        );
+    }
+
+    /// Append the advanced stat columns to the loaded rows. They're hidden by default and can be
+    /// toggled on in the options pane. Players without advanced stats get a "-".
+    fn load_advanced_stats(&mut self, advanced: &HashMap<u64, AdvancedStats>, group: StatGroup) {
+        // e.g. minor league levels don't have them
+        if advanced.is_empty() {
+            return;
+        }
+        let missing = || "-".to_string();
+        for id in self.row_ids.clone() {
+            let (sabermetrics, expected) = advanced
+                .get(&id)
+                .map(|a| (a.sabermetrics, a.expected))
+                .unwrap_or_default();
+            match group {
+                StatGroup::Pitching => {
+                    let fip = sabermetrics.and_then(|s| s.fip);
+                    let xfip = sabermetrics.and_then(|s| s.xfip);
+                    self.table_helper(
+                        "FIP",
+                        "fielding independent pitching",
+                        false,
+                        fip.map_or_else(missing, |v| format!("{v:.2}")),
+                    );
+                    self.table_helper(
+                        "xFIP",
+                        "expected FIP",
+                        false,
+                        xfip.map_or_else(missing, |v| format!("{v:.2}")),
+                    );
+                }
+                _ => {
+                    let woba = sabermetrics.and_then(|s| s.woba);
+                    let wrc_plus = sabermetrics.and_then(|s| s.w_rc_plus);
+                    self.table_helper(
+                        "wOBA",
+                        "weighted on-base avg",
+                        false,
+                        woba.map_or_else(missing, format_rate),
+                    );
+                    self.table_helper(
+                        "wRC+",
+                        "weighted runs created+",
+                        false,
+                        wrc_plus.map_or_else(missing, |v| format!("{v:.0}")),
+                    );
+                }
+            }
+            let war = sabermetrics.map(|s| s.war);
+            self.table_helper(
+                "WAR",
+                "wins above replacement",
+                false,
+                war.map_or_else(missing, |v| format!("{v:.1}")),
+            );
+            self.table_helper(
+                "xBA",
+                "expected batting avg",
+                false,
+                expected.map_or_else(missing, |e| e.avg.clone()),
+            );
+            self.table_helper(
+                "xSLG",
+                "expected slugging",
+                false,
+                expected.map_or_else(missing, |e| e.slg.clone()),
+            );
+            self.table_helper(
+                "xwOBA",
+                "expected wOBA",
+                false,
+                expected.map_or_else(missing, |e| e.woba.clone()),
+            );
+        }
+    }
+
     fn format_name_columns(
         &mut self,
         name: String,
@@ -463,3 +689,119 @@ impl StatsTable {
             .unwrap_or(0)
     }
 }
+
+/// Format a rate stat the way the API does, e.g. 0.3899 as ".390".
+fn format_rate(value: f64) -> String {
+    let formatted = format!("{value:.3}");
+    match formatted.strip_prefix('0') {
+        Some(rest) => rest.to_string(),
+        None => formatted,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mlbt_api::stats::{DisplayName, Player, Split, Stat};
+
+    fn split(player_id: u64, stat: StatSplit) -> Split {
+        Split {
+            season: Some("2026".to_string()),
+            stat,
+            team: None,
+            player: Some(Player {
+                id: player_id,
+                full_name: format!("Player {player_id}"),
+                first_name: None,
+                last_name: None,
+            }),
+            date: None,
+            is_home: None,
+            is_win: None,
+            opponent: None,
+            game: None,
+        }
+    }
+
+    fn stat(stat_type: &str, splits: Vec<Split>) -> Stat {
+        Stat {
+            stat_type: DisplayName {
+                display_name: stat_type.to_string(),
+            },
+            group: DisplayName {
+                display_name: "hitting".to_string(),
+            },
+            total_splits: None,
+            splits,
+        }
+    }
+
+    fn hitting(at_bats: u16) -> StatSplit {
+        StatSplit::Hitting(Box::new(HittingStat {
+            at_bats,
+            ..HittingStat::default()
+        }))
+    }
+
+    fn column<'a>(table: &'a StatsTable, name: &str) -> &'a TableEntry {
+        table.columns.get(name).unwrap()
+    }
+
+    #[test]
+    fn merges_advanced_stats_by_player_id() {
+        let response = StatsResponse {
+            stats: vec![
+                stat(
+                    "season",
+                    vec![split(1, hitting(500)), split(2, hitting(300))],
+                ),
+                // not in the same order as the season splits
+                stat(
+                    "sabermetrics",
+                    vec![split(
+                        2,
+                        StatSplit::Sabermetrics(Box::new(SabermetricsStat {
+                            war: 3.04,
+                            woba: Some(0.3899),
+                            w_rc_plus: Some(131.6),
+                            fip: None,
+                            xfip: None,
+                        })),
+                    )],
+                ),
+                stat(
+                    "expectedStatistics",
+                    vec![split(
+                        1,
+                        StatSplit::Expected(Box::new(ExpectedStat {
+                            avg: ".281".to_string(),
+                            slg: ".502".to_string(),
+                            woba: ".371".to_string(),
+                            woba_con: None,
+                        })),
+                    )],
+                ),
+            ],
+        };
+        let mut table = StatsTable::new(StatType::default());
+        table.load(&response, StatType::default());
+
+        assert_eq!(table.total_row_count(), 2);
+        assert_eq!(column(&table, "wOBA").rows, ["-", ".390"]);
+        assert_eq!(column(&table, "wRC+").rows, ["-", "132"]);
+        assert_eq!(column(&table, "WAR").rows, ["-", "3.0"]);
+        assert_eq!(column(&table, "xBA").rows, [".281", "-"]);
+        assert!(!column(&table, "xwOBA").active);
+        assert!(!table.columns.contains_key("FIP"));
+    }
+
+    #[test]
+    fn skips_advanced_columns_without_data() {
+        let response = StatsResponse {
+            stats: vec![stat("season", vec![split(1, hitting(500))])],
+        };
+        let mut table = StatsTable::new(StatType::default());
+        table.load(&response, StatType::default());
+        assert!(!table.columns.contains_key("WAR"));
+    }
+}
@@ -2,11 +2,12 @@ use crate::components::constants::lookup_team_by_id;
 use crate::components::datetime::{
     format_game_time, format_numeric_date_or, format_short_date, format_short_date_or,
 };
-use crate::components::util::{OptionDisplayExt, OptionMapDisplayExt};
-use chrono::{DateTime, NaiveDate, Utc};
+use crate::components::game_context::GameContext;
+use crate::components::util::{OptionDisplayExt, OptionMapDisplayExt, last_name};
+use chrono::{DateTime, Days, NaiveDate, Utc};
 use chrono_tz::Tz;
 use mlbt_api::schedule::{AbstractGameState, ScheduleResponse};
-use mlbt_api::team::{RosterResponse, TransactionsResponse};
+use mlbt_api::team::{RosterResponse, RosterType, Transaction, TransactionsResponse};
 
 #[derive(Debug, Clone)]
 pub struct TeamGame {
@@ -17,6 +18,9 @@ pub struct TeamGame {
     pub start_time_utc: Option<DateTime<Utc>>,
     pub is_home: bool,
     pub is_past: bool,
+    pub context: GameContext,
+    /// First game of a series against the opponent, used to group the schedule into series.
+    pub series_start: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -32,6 +36,86 @@ pub struct RosterRow {
     pub dob: String,
     pub status: String,
     pub status_code: String,
+    /// Only set in the injured list view.
+    pub injury: Option<Injury>,
+}
+
+/// Which players the roster section lists. The injured list is the 40 man roster filtered down to
+/// the players on the IL.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum RosterView {
+    #[default]
+    Active,
+    FortyMan,
+    FullSeason,
+    Injured,
+}
+
+impl RosterView {
+    pub fn next(self) -> Self {
+        match self {
+            Self::Active => Self::FortyMan,
+            Self::FortyMan => Self::FullSeason,
+            Self::FullSeason => Self::Injured,
+            Self::Injured => Self::Active,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Active => "Roster (active)",
+            Self::FortyMan => "Roster (40 man)",
+            Self::FullSeason => "Roster (full season)",
+            Self::Injured => "Injured list",
+        }
+    }
+
+    /// The roster type to request, `None` for the injured list which has its own request.
+    pub fn roster_type(self) -> Option<RosterType> {
+        match self {
+            Self::Active => Some(RosterType::Active),
+            Self::FortyMan => Some(RosterType::FortyMan),
+            Self::FullSeason => Some(RosterType::FullSeason),
+            Self::Injured => None,
+        }
+    }
+
+    pub fn from_roster_type(roster_type: RosterType) -> Option<Self> {
+        match roster_type {
+            RosterType::Active => Some(Self::Active),
+            RosterType::FortyMan => Some(Self::FortyMan),
+            RosterType::FullSeason => Some(Self::FullSeason),
+            RosterType::DepthChart => None,
+        }
+    }
+}
+
+/// An IL stint, from the transaction that placed the player on the IL.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Injury {
+    pub placed: Option<NaiveDate>,
+    /// The earliest date the player can be activated, based on the length of the IL.
+    pub eligible: Option<NaiveDate>,
+    /// e.g. "Right elbow UCL reconstruction."
+    pub description: String,
+}
+
+/// Number of days on the IL for a roster status code, `None` if the player isn't on the IL.
+pub fn injured_list_days(status_code: &str) -> Option<u64> {
+    match status_code {
+        "D7" => Some(7),
+        "D10" => Some(10),
+        "D15" => Some(15),
+        "D60" => Some(60),
+        _ => None,
+    }
+}
+
+/// A depth chart position and the players at it, in depth order.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DepthChartRow {
+    pub position: String,
+    pub players: Vec<String>,
 }
 
 #[derive(Debug, Clone)]
@@ -47,7 +131,7 @@ impl TeamGame {
         date: NaiveDate,
         tz: Tz,
     ) -> Vec<TeamGame> {
-        let mut games = Vec::new();
+        let mut games: Vec<TeamGame> = Vec::new();
         for date_entry in &response.dates {
             let Some(date_games) = &date_entry.games else {
                 continue;
@@ -111,6 +195,17 @@ impl TeamGame {
                         .unwrap_or_else(|| "TBD".to_string())
                 };
 
+                let context = GameContext::from_game(game);
+                let series_start = match (games.last(), context.series) {
+                    (Some(prev), Some((number, _))) => {
+                        prev.opponent != opponent
+                            || prev
+                                .context
+                                .series
+                                .is_none_or(|(prev_number, _)| number <= prev_number)
+                    }
+                    _ => true,
+                };
                 games.push(TeamGame {
                     date: game_date,
                     date_display,
@@ -119,6 +214,8 @@ impl TeamGame {
                     start_time_utc,
                     is_home,
                     is_past,
+                    context,
+                    series_start,
                 });
             }
         }
@@ -145,7 +242,7 @@ pub enum PositionGroup {
 }
 
 impl PositionGroup {
-    fn from_api(s: &str) -> Self {
+    pub fn from_api(s: &str) -> Self {
         match s {
             "Pitcher" => Self::Pitcher,
             "Catcher" => Self::Catcher,
@@ -197,6 +294,7 @@ impl RosterRow {
                     dob: format_numeric_date_or(person.birth_date, "-"),
                     status: entry.status.description.clone(),
                     status_code: entry.status.code.clone(),
+                    injury: None,
                 }
             })
             .collect();
@@ -212,6 +310,80 @@ impl RosterRow {
 
         rows
     }
+
+    /// The 40 man roster players on the IL, with their stint from the team's transactions.
+    pub fn from_injured_list(
+        roster: &RosterResponse,
+        transactions: &TransactionsResponse,
+    ) -> Vec<RosterRow> {
+        let mut rows = Self::from_roster(roster);
+        rows.retain(|row| injured_list_days(&row.status_code).is_some());
+        for row in &mut rows {
+            let days = injured_list_days(&row.status_code).unwrap_or_default();
+            let placement = transactions
+                .transactions
+                .iter()
+                .rfind(|t| is_injured_list_placement(t, row.player_id));
+            row.injury = Some(match placement {
+                Some(t) => {
+                    let placed = t.effective_date.or(t.date);
+                    let description = t.description.as_deref().unwrap_or_default();
+                    Injury {
+                        placed,
+                        eligible: placed.and_then(|d| d.checked_add_days(Days::new(days))),
+                        description: injury_description(description),
+                    }
+                }
+                None => Injury::default(),
+            });
+        }
+        rows
+    }
+}
+
+/// e.g. "Pittsburgh Pirates placed C Endy Rodríguez on the 60-day injured list."
+fn is_injured_list_placement(transaction: &Transaction, player_id: u64) -> bool {
+    let Some(description) = &transaction.description else {
+        return false;
+    };
+    let description = description.to_lowercase();
+    transaction
+        .person
+        .as_ref()
+        .is_some_and(|p| p.id == player_id)
+        && description.contains(" placed ")
+        && description.contains("injured list")
+}
+
+/// The injury from a placement, which follows the IL in the description.
+fn injury_description(description: &str) -> String {
+    let lower = description.to_lowercase();
+    lower
+        .find("injured list")
+        .map(|i| description[i + "injured list".len()..].trim_start_matches(['.', ' ']))
+        .unwrap_or_default()
+        .trim()
+        .to_string()
+}
+
+impl DepthChartRow {
+    /// Group the depth chart by position, keeping the order of the response which lists positions
+    /// and the players at each in depth order.
+    pub fn from_roster(response: &RosterResponse) -> Vec<DepthChartRow> {
+        let mut rows: Vec<DepthChartRow> = Vec::new();
+        for entry in &response.roster {
+            let position = &entry.position.abbreviation;
+            let name = last_name(&entry.person.full_name).to_string();
+            match rows.iter_mut().find(|row| &row.position == position) {
+                Some(row) => row.players.push(name),
+                None => rows.push(DepthChartRow {
+                    position: position.clone(),
+                    players: vec![name],
+                }),
+            }
+        }
+        rows
+    }
 }
 
 impl TransactionRow {
@@ -230,3 +402,174 @@ impl TransactionRow {
         rows
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mlbt_api::live::{FullPlayer, PrimaryPosition};
+    use mlbt_api::schedule::{Dates, Game, IdNameLink, TeamInfo, Teams};
+    use mlbt_api::team::{RosterEntry, RosterStatus, TransactionEntity};
+
+    fn entry(id: u64, name: &str, position: &str, status: &str) -> RosterEntry {
+        RosterEntry {
+            person: FullPlayer {
+                id,
+                full_name: name.to_string(),
+                ..FullPlayer::default()
+            },
+            jersey_number: None,
+            position: PrimaryPosition {
+                code: String::new(),
+                name: String::new(),
+                r#type: "Pitcher".to_string(),
+                abbreviation: position.to_string(),
+            },
+            status: RosterStatus {
+                code: status.to_string(),
+                description: String::new(),
+            },
+            parent_team_id: None,
+        }
+    }
+
+    fn placement(id: u64, date: NaiveDate, description: &str) -> Transaction {
+        Transaction {
+            id: 0,
+            person: Some(TransactionEntity { id, name: None }),
+            from_team: None,
+            to_team: None,
+            date: Some(date),
+            effective_date: None,
+            resolution_date: None,
+            type_code: Some("SC".to_string()),
+            type_desc: None,
+            description: Some(description.to_string()),
+        }
+    }
+
+    #[test]
+    fn injured_list_uses_latest_placement() {
+        let roster = RosterResponse {
+            roster: vec![
+                entry(1, "Endy Rodriguez", "C", "D60"),
+                entry(2, "Healthy Player", "P", "A"),
+                entry(3, "Unknown Stint", "P", "D15"),
+            ],
+        };
+        let date = |d| NaiveDate::from_ymd_opt(2025, 4, d).unwrap();
+        let transactions = TransactionsResponse {
+            transactions: vec![
+                placement(
+                    1,
+                    date(1),
+                    "Pirates placed C Endy Rodriguez on the 10-day injured list. Sore back.",
+                ),
+                placement(
+                    1,
+                    date(10),
+                    "Pirates activated C Endy Rodriguez from the 10-day injured list.",
+                ),
+                placement(
+                    1,
+                    date(20),
+                    "Pirates placed C Endy Rodriguez on the 60-day injured list. Right elbow UCL reconstruction.",
+                ),
+            ],
+        };
+
+        let rows = RosterRow::from_injured_list(&roster, &transactions);
+        assert_eq!(rows.len(), 2);
+        let injury = rows
+            .iter()
+            .find(|r| r.player_id == 1)
+            .unwrap()
+            .injury
+            .as_ref();
+        assert_eq!(
+            injury,
+            Some(&Injury {
+                placed: Some(date(20)),
+                eligible: NaiveDate::from_ymd_opt(2025, 6, 19),
+                description: "Right elbow UCL reconstruction.".to_string(),
+            })
+        );
+        let unknown = rows.iter().find(|r| r.player_id == 3).unwrap();
+        assert_eq!(unknown.injury, Some(Injury::default()));
+    }
+
+    fn series_game(opponent: u16, date: u32, number: u8, total: u8) -> Game {
+        let team = |id| TeamInfo {
+            team: IdNameLink {
+                id,
+                ..IdNameLink::default()
+            },
+            ..TeamInfo::default()
+        };
+        let official_date = NaiveDate::from_ymd_opt(2025, 6, date).unwrap();
+        Game {
+            official_date,
+            game_date: official_date.and_hms_opt(23, 0, 0).unwrap().and_utc(),
+            teams: Teams {
+                away: team(opponent),
+                home: team(134),
+            },
+            series_game_number: Some(number),
+            games_in_series: Some(total),
+            ..Game::default()
+        }
+    }
+
+    #[test]
+    fn schedule_groups_games_into_series() {
+        let schedule = ScheduleResponse {
+            dates: vec![Dates {
+                games: Some(vec![
+                    series_game(112, 1, 1, 2),
+                    series_game(112, 2, 2, 2),
+                    // a new series against the same opponent
+                    series_game(112, 3, 1, 1),
+                    series_game(113, 4, 1, 3),
+                    series_game(113, 5, 2, 3),
+                ]),
+                ..Dates::default()
+            }],
+            ..ScheduleResponse::default()
+        };
+        let date = NaiveDate::from_ymd_opt(2025, 6, 1).unwrap();
+        let games = TeamGame::from_schedule(&schedule, 134, date, chrono_tz::UTC);
+        let starts: Vec<bool> = games.iter().map(|g| g.series_start).collect();
+        assert_eq!(starts, [true, false, true, true, false]);
+        assert_eq!(games[4].context.series, Some((2, 3)));
+    }
+
+    #[test]
+    fn depth_chart_groups_by_position_in_order() {
+        let roster = RosterResponse {
+            roster: vec![
+                entry(1, "Paul Skenes", "SP", "A"),
+                entry(2, "Mitch Keller", "SP", "A"),
+                entry(3, "Nick Gonzales", "2B", "A"),
+                entry(4, "Jared Triolo", "2B", "A"),
+                entry(4, "Jared Triolo", "3B", "A"),
+            ],
+        };
+        let rows = DepthChartRow::from_roster(&roster);
+        assert_eq!(
+            rows,
+            [
+                DepthChartRow {
+                    position: "SP".to_string(),
+                    players: vec!["Skenes".to_string(), "Keller".to_string()],
+                },
+                DepthChartRow {
+                    position: "2B".to_string(),
+                    players: vec!["Gonzales".to_string(), "Triolo".to_string()],
+                },
+                DepthChartRow {
+                    position: "3B".to_string(),
+                    players: vec!["Triolo".to_string()],
+                },
+            ]
+        );
+    }
+}
@@ -13,6 +13,7 @@ static CONFIG_FILE_LOCATION: OnceLock<Option<PathBuf>> = OnceLock::new();
 pub const DEFAULT_TIMEZONE: Tz = US__Pacific;
 pub const DEFAULT_LOG_LEVEL: LogLevel = LogLevel::Error;
 pub const DEFAULT_AUTO_ADVANCE_DATE: bool = true;
+pub const DEFAULT_BROADCAST_LANGUAGE: BroadcastLanguage = BroadcastLanguage::All;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
 #[serde(rename_all = "lowercase")]
@@ -25,6 +26,26 @@ pub enum LogLevel {
     Error,
 }
 
+/// Language of the broadcasts listed for scheduled games.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BroadcastLanguage {
+    All,
+    English,
+    Spanish,
+}
+
+impl BroadcastLanguage {
+    /// Language code used by the API, `None` for all languages.
+    pub fn code(self) -> Option<&'static str> {
+        match self {
+            BroadcastLanguage::All => None,
+            BroadcastLanguage::English => Some("en"),
+            BroadcastLanguage::Spanish => Some("es"),
+        }
+    }
+}
+
 /// `mlbt.toml` reader/writer.
 ///
 /// - Fields are `Option` so a partial or hand-edited file still parses.
@@ -50,6 +71,14 @@ pub struct ConfigFile {
     /// Automatically advance any date-driven tab to the new day when the system date rolls over,
     /// unless the tab selected a past date. Defaults to true.
     pub auto_advance_date: Option<bool>,
+
+    /// Only list broadcasts in this language for scheduled games: "all", "english" or "spanish".
+    pub broadcast_language: Option<BroadcastLanguage>,
+
+    /// Only list these broadcasters for scheduled games, matched against the broadcast name or
+    /// call sign, e.g. ["Marquee Sports Network", "WSCR"]. Games that none of them carry list
+    /// every broadcast.
+    pub preferred_broadcasters: Option<Vec<String>>,
 }
 
 impl Default for ConfigFile {
@@ -59,6 +88,8 @@ impl Default for ConfigFile {
             timezone: Some(DEFAULT_TIMEZONE),
             log_level: Some(DEFAULT_LOG_LEVEL),
             auto_advance_date: Some(DEFAULT_AUTO_ADVANCE_DATE),
+            broadcast_language: Some(DEFAULT_BROADCAST_LANGUAGE),
+            preferred_broadcasters: None,
         }
     }
 }
@@ -73,6 +104,9 @@ impl From<ConfigFile> for AppSettings {
         let timezone_abbreviation = compute_timezone_abbreviation(timezone);
         let log_level = file.log_level.unwrap_or(DEFAULT_LOG_LEVEL);
         let auto_advance_date = file.auto_advance_date.unwrap_or(DEFAULT_AUTO_ADVANCE_DATE);
+        let broadcast_language = file
+            .broadcast_language
+            .unwrap_or(DEFAULT_BROADCAST_LANGUAGE);
         Self {
             favorite_team,
             full_screen: false,
@@ -80,6 +114,8 @@ impl From<ConfigFile> for AppSettings {
             timezone_abbreviation,
             log_level,
             auto_advance_date,
+            broadcast_language,
+            preferred_broadcasters: file.preferred_broadcasters.unwrap_or_default(),
         }
     }
 }
@@ -91,6 +127,12 @@ impl From<&AppSettings> for ConfigFile {
             timezone: Some(s.timezone),
             log_level: Some(s.log_level),
             auto_advance_date: Some(s.auto_advance_date),
+            broadcast_language: Some(s.broadcast_language),
+            preferred_broadcasters: if s.preferred_broadcasters.is_empty() {
+                None
+            } else {
+                Some(s.preferred_broadcasters.clone())
+            },
         }
     }
 }
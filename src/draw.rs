@@ -1,5 +1,6 @@
+use chrono::Datelike;
 use tui::backend::Backend;
-use tui::layout::{Alignment, Constraint, Direction, Layout, Rect};
+use tui::layout::{Alignment, Constraint, Direction, Flex, Layout, Margin, Rect};
 use tui::style::{Color, Modifier, Style};
 use tui::text::Line;
 use tui::widgets::{Block, BorderType, Borders, Clear, Paragraph, Tabs, Widget};
@@ -7,22 +8,26 @@ use tui::{Frame, Terminal};
 
 use crate::app::{App, DebugState, MenuItem};
 use crate::components::debug::DebugInfo;
-use crate::state::network::{ERROR_CHAR, LoadingState};
+use crate::state::messages::RequestKind;
+use crate::state::network::{Connection, ERROR_CHAR, LoadingState};
 use crate::ui::boxscore::TeamBatterBoxscoreWidget;
 use crate::ui::date_selector::DateSelectorWidget;
 use crate::ui::decision_pitchers::DecisionPitchersWidget;
+use crate::ui::draft::DraftWidget;
 use crate::ui::gameday::gameday_widget::GamedayWidget;
 use crate::ui::gameday::win_probability::WinProbabilityWidget;
 use crate::ui::help::help_widget::HelpWidget;
-use crate::ui::input_popup::{InputPopup, popup_cursor_position};
+use crate::ui::input_popup::{InputPopup, create_popup, popup_cursor_position};
 use crate::ui::layout::LayoutAreas;
 use crate::ui::linescore::LineScoreWidget;
 use crate::ui::logs::LogWidget;
 use crate::ui::player_profile::PlayerProfileWidget;
+use crate::ui::player_search::PlayerSearchWidget;
 use crate::ui::probable_pitchers::ProbablePitchersWidget;
+use crate::ui::recap::RecapWidget;
 use crate::ui::schedule::ScheduleWidget;
-use crate::ui::standings::StandingsWidget;
-use crate::ui::stats::{STATS_OPTIONS_WIDTH, StatsDataWidget, StatsOptionsWidget};
+use crate::ui::standings::{BracketWidget, StandingsWidget};
+use crate::ui::stats::{LeadersWidget, STATS_OPTIONS_WIDTH, StatsDataWidget, StatsOptionsWidget};
 use crate::ui::styling::{TEXT_COLOR, border_style};
 use crate::ui::team_page::TeamPageWidget;
 
@@ -63,12 +68,24 @@ where
                 MenuItem::Standings => draw_standings(f, main_layout.main, app),
                 MenuItem::Help => draw_help(f, f.area(), app),
             }
+            // the scoreboard draws its own since it has a panel per request
+            if matches!(
+                app.state.active_tab,
+                MenuItem::Gameday | MenuItem::Stats | MenuItem::Standings
+            ) {
+                draw_unavailable_offline(f, main_layout.main, app, None);
+            }
+            if app.state.player_search.is_open {
+                draw_player_search(f, main_layout.main, app);
+            }
             if app.state.debug_state == DebugState::On {
                 let mut dbi = DebugInfo::new();
                 dbi.gather_info(f, app);
                 dbi.render(f, main_layout.main, app.state.show_logs);
             }
 
+            draw_request_error(f, main_layout.main, app);
+            draw_connection_status(f, f.area(), app);
             draw_loading_spinner(f, f.area(), app, is_loading);
         })
         .unwrap();
@@ -115,6 +132,64 @@ fn draw_loading_spinner(f: &mut Frame, area: Rect, app: &App, loading: LoadingSt
     f.render_widget(spinner, area);
 }
 
+/// The tab's failed request, drawn over the bottom border of the main area.
+fn draw_request_error(f: &mut Frame, area: Rect, app: &App) {
+    if app.state.active_tab == MenuItem::Help {
+        return;
+    }
+    let Some(failed) = app.state.request_errors.get(app.content_tab()) else {
+        return;
+    };
+    let line = format!(" {} ", failed.status_line());
+    let width = (line.chars().count() as u16).min(area.width.saturating_sub(4));
+    let status = Paragraph::new(line).style(Style::default().fg(Color::Red));
+    let rect = Rect::new(area.x + 2, area.bottom().saturating_sub(1), width, 1);
+    f.render_widget(status, rect.intersection(area));
+}
+
+/// Replaces the contents of a panel whose request couldn't reach the API and had nothing cached to
+/// show instead. `kinds` limits it to the requests shown in the panel, `None` for any request.
+fn draw_unavailable_offline(f: &mut Frame, rect: Rect, app: &App, kinds: Option<&[RequestKind]>) {
+    let Some(failed) = app.state.request_errors.get(app.content_tab()) else {
+        return;
+    };
+    let kind = failed.request.kind();
+    if !failed.error.is_connection_failure() || kinds.is_some_and(|k| !k.contains(&kind)) {
+        return;
+    }
+    let inner = rect.inner(Margin::new(1, 1));
+    f.render_widget(Clear, inner);
+    let message = Paragraph::new(format!("{} unavailable offline", kind.label()))
+        .alignment(Alignment::Center)
+        .style(Style::default().fg(Color::Red));
+    let [middle] = Layout::vertical([Constraint::Length(1)])
+        .flex(Flex::Center)
+        .areas(inner);
+    f.render_widget(message, middle);
+}
+
+/// Shown next to the loading spinner when the API can't be reached.
+fn draw_connection_status(f: &mut Frame, area: Rect, app: &App) {
+    let Some(label) = app.state.connection.label(app.settings.timezone) else {
+        return;
+    };
+    let style = match app.state.connection {
+        Connection::Offline { data_from: None } => Style::default().fg(Color::Red),
+        _ => Style::default().fg(Color::Yellow),
+    };
+    let width = label.chars().count() as u16;
+    let (x, y) = if app.settings.full_screen || app.state.active_tab == MenuItem::Help {
+        (
+            area.width.saturating_sub(width + 5),
+            area.height.saturating_sub(2),
+        )
+    } else {
+        (area.width.saturating_sub(width + 13), 1)
+    };
+    let status = Paragraph::new(label).style(style);
+    f.render_widget(status, Rect::new(x, y, width, 1).intersection(area));
+}
+
 fn draw_tabs(f: &mut Frame, top_bar: &[Rect], app: &App) {
     let border_style = border_style();
     let border_type = BorderType::Rounded;
@@ -170,14 +245,38 @@ fn draw_scoreboard(f: &mut Frame, rect: Rect, app: &mut App) {
     if app.state.schedule.show_win_probability {
         draw_win_probability(f, scoreboard, app);
     }
+    draw_unavailable_offline(f, scoreboard, app, Some(&[RequestKind::Schedule]));
 
-    // display probable pitchers or line score and box score on right
-    if let Some(matchup) = app.state.schedule.get_probable_pitchers_opt() {
-        f.render_widget(ProbablePitchersWidget { matchup }, boxscore);
+    // display the recap, probable pitchers or line score and box score on right
+    if app.state.schedule.show_recap {
+        draw_border(f, boxscore);
+        f.render_widget(
+            RecapWidget {
+                state: &mut app.state.recap,
+                game_id: app.state.gameday.current_game_id(),
+                is_final: app.state.gameday.is_final(),
+            },
+            LayoutAreas::for_recap(boxscore),
+        );
+    } else if let Some(matchup) = app.state.schedule.get_probable_pitchers_opt() {
+        f.render_widget(
+            ProbablePitchersWidget {
+                matchup,
+                broadcast_language: app.settings.broadcast_language,
+                preferred_broadcasters: &app.settings.preferred_broadcasters,
+            },
+            boxscore,
+        );
     } else {
         draw_border(f, boxscore);
         draw_linescore_boxscore(f, boxscore, app);
     }
+    draw_unavailable_offline(
+        f,
+        boxscore,
+        app,
+        Some(&[RequestKind::Game, RequestKind::GameContent]),
+    );
 }
 
 fn draw_linescore_boxscore(f: &mut Frame, rect: Rect, app: &mut App) {
@@ -210,6 +309,7 @@ fn draw_linescore_boxscore(f: &mut Frame, rect: Rect, app: &mut App) {
         TeamBatterBoxscoreWidget {
             active: app.state.box_score.active_team,
             state: &mut app.state.box_score,
+            game_info_shown: false,
         },
         boxscore_chunk,
     );
@@ -223,12 +323,23 @@ fn draw_date_picker(f: &mut Frame, rect: Rect, app: &mut App) {
     f.set_cursor_position((cx, cy));
 }
 
+fn draw_player_search(f: &mut Frame, rect: Rect, app: &App) {
+    let state = &app.state.player_search;
+    let area = create_popup(rect, PlayerSearchWidget::height(state), 50);
+    f.render_widget(PlayerSearchWidget { state }, area);
+
+    let (cx, cy) = popup_cursor_position(area, state.input.len() as u16);
+    f.set_cursor_position((cx, cy));
+}
+
 fn draw_gameday(f: &mut Frame, rect: Rect, app: &mut App) {
     f.render_widget(
         GamedayWidget {
             active: app.state.box_score.active_team,
             state: &mut app.state.gameday,
             boxscore_state: &mut app.state.box_score,
+            recap_state: &mut app.state.recap,
+            timezone: app.settings.timezone,
         },
         rect,
     );
@@ -240,6 +351,10 @@ fn draw_stats(f: &mut Frame, rect: Rect, app: &mut App) {
             PlayerProfileWidget { state: profile }.render(rect, f.buffer_mut());
             return;
         }
+        if let Some(draft) = &mut tp.draft {
+            DraftWidget { state: draft }.render(rect, f.buffer_mut());
+            return;
+        }
         TeamPageWidget { state: tp }.render(rect, f.buffer_mut());
         return;
     }
@@ -247,6 +362,14 @@ fn draw_stats(f: &mut Frame, rect: Rect, app: &mut App) {
         PlayerProfileWidget { state: profile }.render(rect, f.buffer_mut());
         return;
     }
+    if app.state.stats.show_leaders {
+        LeadersWidget {
+            leaders: app.state.stats.leaders.as_ref(),
+            season: app.state.stats.date_selector.date.year(),
+        }
+        .render(rect, f.buffer_mut());
+        return;
+    }
 
     // Split horizontally first: data pane (left) and options pane (right)
     let (data_area, options_area) =
@@ -310,10 +433,23 @@ fn draw_standings(f: &mut Frame, rect: Rect, app: &mut App) {
             PlayerProfileWidget { state: profile }.render(rect, f.buffer_mut());
             return;
         }
+        if let Some(draft) = &mut tp.draft {
+            DraftWidget { state: draft }.render(rect, f.buffer_mut());
+            return;
+        }
         TeamPageWidget { state: tp }.render(rect, f.buffer_mut());
         return;
     }
-    f.render_stateful_widget(StandingsWidget {}, rect, &mut app.state.standings);
+    let standings = &mut app.state.standings;
+    if standings.show_bracket {
+        BracketWidget {
+            bracket: standings.bracket.as_ref(),
+            season: standings.date_selector.date.year(),
+        }
+        .render(rect, f.buffer_mut());
+        return;
+    }
+    f.render_stateful_widget(StandingsWidget {}, rect, standings);
 }
 
 fn draw_win_probability(f: &mut Frame, rect: Rect, app: &mut App) {
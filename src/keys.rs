@@ -4,9 +4,11 @@ use crate::components::stats::table::TeamOrPlayer;
 use crate::state::messages::{NetworkRequest, RefreshableRequest};
 use crate::state::settings_editor::SettingsFocus;
 use crate::state::stats::ActivePane;
+use chrono::Datelike;
 use crossterm::event::KeyCode::Char;
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 use mlbt_api::client::{Qualification, StatGroup};
+use mlbt_api::season::GameType;
 use std::sync::Arc;
 use tokio::sync::{Mutex, MutexGuard, mpsc};
 
@@ -25,6 +27,22 @@ pub async fn handle_key_bindings(
             std::process::exit(0);
         }
 
+        // player search popup captures all keys on any tab
+        (_, _, _) if guard.state.player_search.is_open => {
+            handle_player_search_key(key_event, guard, network_requests).await;
+        }
+
+        // retry with `R` since several tabs already use `r`
+        (_, Char('R'), _)
+            if guard.failed_request().is_some() && !guard.state.stats.search.is_open =>
+        {
+            if let Some(request) = guard.retry_request() {
+                let _ = network_requests
+                    .send(RefreshableRequest::force(request))
+                    .await;
+            }
+        }
+
         // Team page from standings
         (MenuItem::Standings, KeyCode::Esc, _) if guard.state.standings.has_team_page() => {
             guard.close_overlay();
@@ -97,6 +115,16 @@ pub async fn handle_key_bindings(
             guard.state.stats.open_search();
         }
 
+        (MenuItem::Scoreboard, Char('J') | KeyCode::Down, KeyModifiers::SHIFT)
+            if guard.state.schedule.show_recap =>
+        {
+            guard.state.recap.scroll_down()
+        }
+        (MenuItem::Scoreboard, Char('K') | KeyCode::Up, KeyModifiers::SHIFT)
+            if guard.state.schedule.show_recap =>
+        {
+            guard.state.recap.scroll_up()
+        }
         (MenuItem::Scoreboard, Char('J') | KeyCode::Down, KeyModifiers::SHIFT) => {
             guard.state.box_score.scroll_down()
         }
@@ -113,6 +141,14 @@ pub async fn handle_key_bindings(
         }
         (MenuItem::Scoreboard, Char(':'), _) => guard.update_tab(MenuItem::DatePicker),
         (MenuItem::Scoreboard, Char('w'), _) => guard.state.schedule.toggle_win_probability(),
+        (MenuItem::Scoreboard, Char('r'), _) => {
+            guard.state.schedule.toggle_recap();
+            load_game_content(guard, network_requests).await;
+        }
+        (MenuItem::Scoreboard, Char('v'), _) => {
+            guard.state.schedule.sport = guard.state.schedule.sport.next_level();
+            load_scoreboard(guard, network_requests, false).await;
+        }
         (MenuItem::Scoreboard, Char('s'), _) => {
             let favorite_team = guard.settings.favorite_team;
             guard.state.schedule.toggle_sort_mode(favorite_team);
@@ -164,6 +200,18 @@ pub async fn handle_key_bindings(
             guard.state.stats.stat_type.group = StatGroup::Hitting;
             load_stats(guard, network_requests, false).await;
         }
+        (MenuItem::Stats, Char('e'), _) => {
+            guard.state.stats.stat_type.group = StatGroup::Fielding;
+            load_stats(guard, network_requests, false).await;
+        }
+        (MenuItem::Stats, Char('c'), _) => {
+            guard.state.stats.stat_type.group = StatGroup::Catching;
+            load_stats(guard, network_requests, false).await;
+        }
+        (MenuItem::Stats, Char('n'), _) => {
+            guard.state.stats.stat_type.group = StatGroup::Running;
+            load_stats(guard, network_requests, false).await;
+        }
         (MenuItem::Stats, Char('l'), _) => {
             guard.state.stats.stat_type.team_player = TeamOrPlayer::Player;
             load_stats(guard, network_requests, false).await;
@@ -184,7 +232,11 @@ pub async fn handle_key_bindings(
             guard.state.stats.stat_type.qualification = Qualification::Qualified;
             load_stats(guard, network_requests, false).await;
         }
-        (MenuItem::Stats, KeyCode::Enter, _) => {
+        (MenuItem::Stats, Char('L'), _) => {
+            guard.state.stats.toggle_leaders();
+            load_stats(guard, network_requests, false).await;
+        }
+        (MenuItem::Stats, KeyCode::Enter, _) if !guard.state.stats.show_leaders => {
             if guard.state.stats.active_pane == ActivePane::Options {
                 guard.state.stats.toggle_stat();
             } else {
@@ -196,28 +248,77 @@ pub async fn handle_key_bindings(
             guard.state.stats.switch_pane()
         }
         (MenuItem::Stats, Char(':'), _) => guard.update_tab(MenuItem::DatePicker),
+        (MenuItem::Stats, Char('v'), _) => {
+            guard.state.stats.sport = guard.state.stats.sport.next_level();
+            load_stats(guard, network_requests, false).await;
+        }
 
         (MenuItem::Standings, Char('j') | KeyCode::Down, _) => guard.state.standings.next(),
         (MenuItem::Standings, Char('k') | KeyCode::Up, _) => guard.state.standings.previous(),
         (MenuItem::Standings, Char('l'), _) => guard.state.standings.toggle_view_mode(),
-        (MenuItem::Standings, KeyCode::Enter, _) => load_team(guard, network_requests).await,
+        (MenuItem::Standings, Char('b'), _) => {
+            guard.state.standings.toggle_bracket();
+            load_standings(guard, network_requests, false).await;
+        }
+        (MenuItem::Standings, KeyCode::Enter, _) if !guard.state.standings.show_bracket => {
+            load_team(guard, network_requests).await
+        }
         (MenuItem::Standings, Char(':'), _) => guard.update_tab(MenuItem::DatePicker),
+        (MenuItem::Standings, Char('v'), _) if !guard.state.standings.show_bracket => {
+            guard.state.standings.sport = guard.state.standings.sport.next_level();
+            load_standings(guard, network_requests, false).await;
+        }
 
         (MenuItem::Gameday, Char('i'), _) => guard.state.gameday.toggle_info(),
         (MenuItem::Gameday, Char('p'), _) => guard.state.gameday.toggle_at_bat(),
         (MenuItem::Gameday, Char('b'), _) => guard.state.gameday.toggle_boxscore(),
         (MenuItem::Gameday, Char('w'), _) => guard.state.gameday.toggle_win_probability(),
+        (MenuItem::Gameday, Char('g'), _) => guard.state.gameday.toggle_game_info(),
+        (MenuItem::Gameday, Char('c'), _) => guard.state.gameday.toggle_spray_chart(),
+        (MenuItem::Gameday, Char('C'), _) => guard.state.gameday.toggle_spray_chart_scope(),
+        (MenuItem::Gameday, Char(']'), _) => guard.state.gameday.next_pitch(),
+        (MenuItem::Gameday, Char('['), _) => guard.state.gameday.previous_pitch(),
+        (MenuItem::Gameday, Char('r'), _) => {
+            guard.state.gameday.toggle_recap();
+            load_game_content(guard, network_requests).await;
+        }
+        // the box score takes the scroll keys when both panels are shown
+        (MenuItem::Gameday, Char('J') | KeyCode::Down, KeyModifiers::SHIFT)
+            if guard.state.gameday.panels.recap && !guard.state.gameday.panels.boxscore =>
+        {
+            guard.state.recap.scroll_down()
+        }
+        (MenuItem::Gameday, Char('K') | KeyCode::Up, KeyModifiers::SHIFT)
+            if guard.state.gameday.panels.recap && !guard.state.gameday.panels.boxscore =>
+        {
+            guard.state.recap.scroll_up()
+        }
         (MenuItem::Gameday, Char('J') | KeyCode::Down, KeyModifiers::SHIFT) => {
             guard.state.box_score.scroll_down()
         }
         (MenuItem::Gameday, Char('K') | KeyCode::Up, KeyModifiers::SHIFT) => {
             guard.state.box_score.scroll_up()
         }
-        (MenuItem::Gameday, Char('j') | KeyCode::Down, _) => guard.state.gameday.previous_at_bat(),
-        (MenuItem::Gameday, Char('k') | KeyCode::Up, _) => guard.state.gameday.next_at_bat(),
-        (MenuItem::Gameday, Char('l'), _) => guard.state.gameday.live(),
-        (MenuItem::Gameday, Char('s'), _) => guard.state.gameday.start(),
-        (MenuItem::Gameday, Char('!'), _) => guard.state.gameday.toggle_scoring_plays_only(),
+        (MenuItem::Gameday, Char('j') | KeyCode::Down, _) => {
+            guard.state.gameday.previous_at_bat();
+            load_head_to_head(guard, network_requests).await;
+        }
+        (MenuItem::Gameday, Char('k') | KeyCode::Up, _) => {
+            guard.state.gameday.next_at_bat();
+            load_head_to_head(guard, network_requests).await;
+        }
+        (MenuItem::Gameday, Char('l'), _) => {
+            guard.state.gameday.live();
+            load_head_to_head(guard, network_requests).await;
+        }
+        (MenuItem::Gameday, Char('s'), _) => {
+            guard.state.gameday.start();
+            load_head_to_head(guard, network_requests).await;
+        }
+        (MenuItem::Gameday, Char('!'), _) => {
+            guard.state.gameday.toggle_scoring_plays_only();
+            load_head_to_head(guard, network_requests).await;
+        }
 
         (MenuItem::Gameday, Char('h'), _) => guard.state.box_score.set_home_active(),
         (MenuItem::Gameday, Char('a'), _) => guard.state.box_score.set_away_active(),
@@ -270,6 +371,33 @@ async fn load_game_data(
     }
 }
 
+/// Request the head-to-head history for the selected at bat, if it isn't loaded yet.
+async fn load_head_to_head(
+    mut guard: AppGuard<'_>,
+    network_requests: &mpsc::Sender<RefreshableRequest>,
+) {
+    let season = guard.state.schedule.date_selector.date.year();
+    let request = guard.state.gameday.head_to_head_request(season);
+    drop(guard);
+
+    if let Some(request) = request {
+        let _ = network_requests.send(request.into()).await;
+    }
+}
+
+/// Request the selected game's preview or recap, if a Recap panel is shown.
+async fn load_game_content(
+    mut guard: AppGuard<'_>,
+    network_requests: &mpsc::Sender<RefreshableRequest>,
+) {
+    let request = guard.game_content_request();
+    drop(guard);
+
+    if let Some(request) = request {
+        let _ = network_requests.send(request.into()).await;
+    }
+}
+
 async fn load_stats(
     guard: AppGuard<'_>,
     network_requests: &mpsc::Sender<RefreshableRequest>,
@@ -277,13 +405,21 @@ async fn load_stats(
 ) {
     let date = guard.state.stats.date_selector.date;
     let stat_type = guard.state.stats.stat_type;
+    let sport = guard.state.stats.sport;
+    let show_leaders = guard.state.stats.show_leaders;
     drop(guard);
 
+    let request = if show_leaders {
+        NetworkRequest::LeagueLeaders { date, sport }
+    } else {
+        NetworkRequest::Stats {
+            date,
+            stat_type,
+            sport,
+        }
+    };
     let _ = network_requests
-        .send(RefreshableRequest::new(
-            NetworkRequest::Stats { date, stat_type },
-            force,
-        ))
+        .send(RefreshableRequest::new(request, force))
         .await;
 }
 
@@ -303,13 +439,19 @@ async fn load_standings(
     force: bool,
 ) {
     let date = guard.state.standings.date_selector.date;
+    let sport = guard.state.standings.sport;
+    let show_bracket = guard.state.standings.show_bracket;
     drop(guard);
 
+    let request = if show_bracket {
+        NetworkRequest::PostseasonBracket {
+            season: date.year(),
+        }
+    } else {
+        NetworkRequest::Standings { date, sport }
+    };
     let _ = network_requests
-        .send(RefreshableRequest::new(
-            NetworkRequest::Standings { date },
-            force,
-        ))
+        .send(RefreshableRequest::new(request, force))
         .await;
 }
 
@@ -319,12 +461,13 @@ async fn load_scoreboard(
     force: bool,
 ) {
     let date = guard.state.schedule.date_selector.date;
+    let sport = guard.state.schedule.sport;
     let game_id = guard.state.schedule.get_selected_game_opt();
     drop(guard);
 
     let _ = network_requests
         .send(RefreshableRequest::new(
-            NetworkRequest::Schedule { date },
+            NetworkRequest::Schedule { date, sport },
             force,
         ))
         .await;
@@ -367,6 +510,11 @@ async fn handle_profile_key(
             let req = p.game_type_toggle_request(date);
             let _ = network_requests.send(req.into()).await;
         }
+        (Char('c'), _) => {
+            if let Some(req) = p.toggle_spray_chart() {
+                let _ = network_requests.send(req.into()).await;
+            }
+        }
         (Char('J'), _) | (KeyCode::Down, KeyModifiers::SHIFT) => p.page_down(),
         (Char('K'), _) | (KeyCode::Up, KeyModifiers::SHIFT) => p.page_up(),
         (Char('j') | KeyCode::Down, _) => p.scroll_down(),
@@ -411,6 +559,7 @@ async fn handle_global_key(
             load_standings(guard, network_requests, force).await;
         }
         (Char('?'), _) => guard.update_tab(MenuItem::Help),
+        (Char('/'), _) => guard.state.player_search.open(),
         (Char('d'), _) => guard.toggle_debug(),
         (Char('"'), _) if guard.state.debug_state == DebugState::On => {
             guard.toggle_show_logs();
@@ -433,14 +582,61 @@ async fn handle_team_page_key(
             .await;
     }
 
+    if let Some(draft) = tp.draft.as_mut() {
+        let request = match (key_event.code, key_event.modifiers) {
+            (Char('J'), _) | (KeyCode::Down, KeyModifiers::SHIFT) => {
+                draft.page_down();
+                None
+            }
+            (Char('K'), _) | (KeyCode::Up, KeyModifiers::SHIFT) => {
+                draft.page_up();
+                None
+            }
+            (Char('j') | KeyCode::Down, _) => {
+                draft.next();
+                None
+            }
+            (Char('k') | KeyCode::Up, _) => {
+                draft.previous();
+                None
+            }
+            (Char('t'), _) => {
+                draft.toggle_team_only();
+                None
+            }
+            (KeyCode::Left, _) => draft.year_request(-1, tp.date),
+            (KeyCode::Right, _) => draft.year_request(1, tp.date),
+            (KeyCode::Enter, _) => draft.player_profile_request(tp.date),
+            _ => return false,
+        };
+        if let Some(req) = request {
+            let _ = network_requests.send(req.into()).await;
+        }
+        return true;
+    }
+
     match (key_event.code, key_event.modifiers) {
-        (KeyCode::Right | KeyCode::Tab, _) => tp.next_section(),
-        (KeyCode::Left, _) => tp.previous_section(),
+        (KeyCode::Right | KeyCode::Tab, _) => {
+            tp.next_section();
+            if let Some(req) = tp.depth_chart_request() {
+                let _ = network_requests.send(req.into()).await;
+            }
+        }
+        (KeyCode::Left, _) => {
+            tp.previous_section();
+            if let Some(req) = tp.depth_chart_request() {
+                let _ = network_requests.send(req.into()).await;
+            }
+        }
         (Char('J'), _) | (KeyCode::Down, KeyModifiers::SHIFT) => tp.page_down(),
         (Char('K'), _) | (KeyCode::Up, KeyModifiers::SHIFT) => tp.page_up(),
         (Char('j') | KeyCode::Down, _) => tp.next(),
         (Char('k') | KeyCode::Up, _) => tp.previous(),
         (Char('c'), _) => tp.toggle_calendar(),
+        (Char('d'), _) => {
+            let req = tp.draft_request();
+            let _ = network_requests.send(req.into()).await;
+        }
         (Char('r'), _) => {
             let req = tp.roster_toggle_request();
             let _ = network_requests.send(req.into()).await;
@@ -479,6 +675,55 @@ fn handle_search_key(key_event: KeyEvent, guard: &mut AppGuard<'_>) {
     }
 }
 
+async fn handle_player_search_key(
+    key_event: KeyEvent,
+    mut guard: AppGuard<'_>,
+    network_requests: &mpsc::Sender<RefreshableRequest>,
+) {
+    let search = &mut guard.state.player_search;
+    match (key_event.code, key_event.modifiers) {
+        (Char(c), m) if !m.contains(KeyModifiers::CONTROL) => search.handle_char(c),
+        (KeyCode::Backspace, _) => search.handle_backspace(),
+        (KeyCode::Tab, _) => search.toggle_active_only(),
+        (KeyCode::Down, _) => search.next(),
+        (KeyCode::Up, _) => search.previous(),
+        (KeyCode::Esc, _) => search.close(),
+        (KeyCode::Enter, _) => {
+            if let Some(result) = search.selected_result() {
+                let (player_id, group) = (result.id, result.group);
+                search.close();
+                open_searched_player(guard, network_requests, player_id, group).await;
+            } else if let Some(query) = search.submit() {
+                let active_only = search.active_only;
+                let _ = network_requests
+                    .send(NetworkRequest::SearchPeople { query, active_only }.into())
+                    .await;
+            }
+        }
+        _ => {} // swallow all other keys
+    }
+}
+
+/// Open a player from the search popup in the Stats tab, replacing any open team page.
+async fn open_searched_player(
+    mut guard: AppGuard<'_>,
+    network_requests: &mpsc::Sender<RefreshableRequest>,
+    player_id: u64,
+    group: StatGroup,
+) {
+    guard.state.stats.team_page = None;
+    guard.update_tab(MenuItem::Stats);
+    let request = NetworkRequest::PlayerProfile {
+        player_id,
+        group,
+        date: guard.state.stats.date_selector.date,
+        game_type: GameType::RegularSeason,
+    };
+    let _ = network_requests.send(request.into()).await;
+    // load the table too so it's there when the profile is closed
+    load_stats(guard, network_requests, false).await;
+}
+
 /// Handle keys on the Help page.
 ///
 /// Returns `(handled, game_id_changed)`, where:
@@ -10,9 +10,17 @@ use crate::app::App;
 use crate::state::messages::{NetworkRequest, NetworkResponse, RefreshableRequest, UiEvent};
 use crate::state::network::{LoadingState, NetworkWorker};
 use crate::state::refresher::PeriodicRefresher;
+use crate::state::request_errors::{FailedRequest, tab_for};
+use anyhow::Context;
+use chrono::Datelike;
 use crossterm::event::{self as crossterm_event, Event, KeyEventKind};
 use crossterm::{cursor, execute, terminal};
+use directories::ProjectDirs;
 use log::error;
+use mlbt_api::disk_cache::{DEFAULT_MAX_BYTES, DiskCache};
+use mlbt_api::transport::{
+    OfflineTransport, RecordingTransport, ReplayTransport, ReqwestTransport, Transport,
+};
 use std::io::Stdout;
 use std::sync::Arc;
 use std::{io, panic};
@@ -25,6 +33,7 @@ async fn main() -> anyhow::Result<()> {
         println!("mlbt {}", env!("CARGO_PKG_VERSION"));
         return Ok(());
     }
+    let transport = transport_from_args()?;
 
     better_panic::install();
 
@@ -48,7 +57,8 @@ async fn main() -> anyhow::Result<()> {
     let input_handler = tokio::spawn(input_handler_task(ui_event_tx.clone()));
 
     // network thread
-    let network_worker = NetworkWorker::new(network_req_rx, network_resp_tx);
+    let network_worker =
+        NetworkWorker::new(network_req_rx, network_resp_tx, transport, disk_cache());
     let network_task = tokio::spawn(network_worker.run());
 
     // periodic update thread
@@ -68,6 +78,56 @@ async fn main() -> anyhow::Result<()> {
     Ok(())
 }
 
+/// `--record <dir>` saves every API response to `dir` and `--replay <dir>` serves a recorded
+/// session instead of using the network. `--offline` never uses the network, so only cached
+/// responses are shown.
+fn transport_from_args() -> anyhow::Result<Arc<dyn Transport>> {
+    let args: Vec<String> = std::env::args().collect();
+    let dir_for = |flag: &str| {
+        args.iter()
+            .position(|a| a == flag)
+            .map(|i| {
+                args.get(i + 1)
+                    .with_context(|| format!("{flag} needs a directory"))
+            })
+            .transpose()
+    };
+
+    if let Some(dir) = dir_for("--replay")? {
+        let replay = ReplayTransport::new(dir)
+            .with_context(|| format!("failed to read a recording from {dir}"))?;
+        return Ok(Arc::new(replay));
+    }
+    if args.iter().any(|a| a == "--offline") {
+        return Ok(Arc::new(OfflineTransport));
+    }
+    let reqwest = Arc::new(ReqwestTransport::default());
+    if let Some(dir) = dir_for("--record")? {
+        let recording = RecordingTransport::new(reqwest, dir)
+            .with_context(|| format!("failed to record to {dir}"))?;
+        return Ok(Arc::new(recording));
+    }
+    Ok(reqwest)
+}
+
+/// Responses that won't change are kept in the platform cache directory, e.g. `~/.cache/mlbt` on
+/// Linux. Recording and replaying skip it so every request goes through their transport.
+fn disk_cache() -> Option<Arc<DiskCache>> {
+    if std::env::args().any(|a| matches!(a.as_str(), "--record" | "--replay")) {
+        return None;
+    }
+    let dir = ProjectDirs::from("", "", "mlbt")?
+        .cache_dir()
+        .join("responses");
+    match DiskCache::open(&dir, DEFAULT_MAX_BYTES) {
+        Ok(cache) => Some(Arc::new(cache)),
+        Err(e) => {
+            error!("failed to open the disk cache at {}: {e}", dir.display());
+            None
+        }
+    }
+}
+
 async fn main_ui_loop(
     mut terminal: Terminal<CrosstermBackend<Stdout>>,
     app: Arc<Mutex<App>>,
@@ -124,12 +184,19 @@ async fn handle_network_response(
     network_requests: &mpsc::Sender<RefreshableRequest>,
     loading: &mut LoadingState,
 ) -> bool {
+    if let Some(kind) = response.kind() {
+        app.lock().await.state.request_errors.loaded(kind);
+    }
     match response {
         NetworkResponse::LoadingStateChanged { loading_state } => {
             *loading = loading_state;
             // Always redraw for loading changes
             return true;
         }
+        NetworkResponse::ConnectionChanged { connection } => {
+            let mut guard = app.lock().await;
+            guard.state.connection = connection;
+        }
         NetworkResponse::ScheduleLoaded { schedule } => {
             let game_id_to_load = {
                 let mut guard = app.lock().await;
@@ -145,21 +212,71 @@ async fn handle_network_response(
         NetworkResponse::GameDataLoaded {
             game,
             win_probability,
+        } => {
+            let requests = {
+                let mut guard = app.lock().await;
+                guard.update_live_data(&game, &win_probability);
+                let season = guard.state.schedule.date_selector.date.year();
+                [
+                    guard.state.gameday.head_to_head_request(season),
+                    guard.game_content_request(),
+                ]
+            };
+            for request in requests.into_iter().flatten() {
+                let _ = network_requests.send(request.into()).await;
+            }
+        }
+        NetworkResponse::GameContentLoaded { game_id, content } => {
+            let mut guard = app.lock().await;
+            guard.state.recap.update(game_id, &content);
+        }
+        NetworkResponse::VsPlayerLoaded {
+            batter_id,
+            pitcher_id,
+            season,
+            stats,
         } => {
             let mut guard = app.lock().await;
-            guard.update_live_data(&game, &win_probability);
+            guard
+                .state
+                .gameday
+                .update_head_to_head(batter_id, pitcher_id, season, &stats);
         }
         NetworkResponse::StandingsLoaded { standings } => {
             let mut guard = app.lock().await;
             guard.state.standings.update(&standings);
         }
+        NetworkResponse::PostseasonBracketLoaded { season, series } => {
+            let mut guard = app.lock().await;
+            guard.state.standings.update_bracket(season, &series);
+        }
         NetworkResponse::StatsLoaded { stats } => {
             let mut guard = app.lock().await;
             guard.state.stats.update(&stats);
         }
-        NetworkResponse::PlayerProfileLoaded { data, game_type } => {
+        NetworkResponse::LeagueLeadersLoaded {
+            season,
+            sport,
+            leaders,
+        } => {
+            let mut guard = app.lock().await;
+            guard.state.stats.update_leaders(season, sport, &leaders);
+        }
+        NetworkResponse::PlayerProfileLoaded {
+            data,
+            group,
+            game_type,
+        } => {
+            let mut guard = app.lock().await;
+            guard.update_player_profile(data, group, game_type);
+        }
+        NetworkResponse::SprayChartLoaded { player_id, balls } => {
+            let mut guard = app.lock().await;
+            guard.update_spray_chart(player_id, balls);
+        }
+        NetworkResponse::PeopleSearchLoaded { query, people } => {
             let mut guard = app.lock().await;
-            guard.update_player_profile(data, game_type);
+            guard.state.player_search.update(&query, &people);
         }
         NetworkResponse::TeamPageLoaded {
             team_id,
@@ -179,18 +296,40 @@ async fn handle_network_response(
             let mut guard = app.lock().await;
             guard.update_team_roster(team_id, &roster, roster_type);
         }
+        NetworkResponse::DraftLoaded { year, draft } => {
+            let mut guard = app.lock().await;
+            guard.update_draft(year, &draft);
+        }
+        NetworkResponse::InjuredListLoaded {
+            team_id,
+            roster,
+            transactions,
+        } => {
+            let mut guard = app.lock().await;
+            guard.update_injured_list(team_id, &roster, &transactions);
+        }
         NetworkResponse::Initialized => {
             // Teams must be loaded before the schedule so international team names resolve.
-            let date = {
+            let (date, sport) = {
                 let guard = app.lock().await;
-                guard.state.schedule.date_selector.date
+                (
+                    guard.state.schedule.date_selector.date,
+                    guard.state.schedule.sport,
+                )
             };
             let _ = network_requests
-                .send(NetworkRequest::Schedule { date }.into())
+                .send(NetworkRequest::Schedule { date, sport }.into())
                 .await;
         }
-        NetworkResponse::Error { message } => {
-            error!("Network error: {message}");
+        NetworkResponse::Error { request, error } => {
+            error!("Network error: {}", error.log());
+            let mut guard = app.lock().await;
+            guard.request_failed(&request);
+            let tab = tab_for(&request, guard.content_tab());
+            guard
+                .state
+                .request_errors
+                .record(tab, FailedRequest { request, error });
         }
     }
     // Only redraw if not loading
@@ -1,5 +1,5 @@
 use crate::components::standings::Team;
-use crate::config::{ConfigFile, LogLevel, TomlFileStore};
+use crate::config::{BroadcastLanguage, ConfigFile, LogLevel, TomlFileStore};
 use chrono::{TimeZone, Utc};
 use chrono_tz::{OffsetName, Tz};
 use log::error;
@@ -12,6 +12,9 @@ pub struct AppSettings {
     pub timezone_abbreviation: String,
     pub log_level: LogLevel,
     pub auto_advance_date: bool,
+    pub broadcast_language: BroadcastLanguage,
+    /// Broadcast names or call signs to list instead of the others when they carry a game.
+    pub preferred_broadcasters: Vec<String>,
 }
 
 impl AppSettings {
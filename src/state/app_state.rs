@@ -5,6 +5,10 @@ use crate::state::boxscore::BoxscoreState;
 use crate::state::date_input::DateInput;
 use crate::state::gameday::GamedayState;
 use crate::state::help::HelpState;
+use crate::state::network::Connection;
+use crate::state::player_search::PlayerSearchState;
+use crate::state::recap::RecapState;
+use crate::state::request_errors::RequestErrors;
 use crate::state::settings_editor::SettingsEditorState;
 use crate::state::stats::StatsState;
 
@@ -26,8 +30,13 @@ pub struct AppState {
     pub schedule: ScheduleState,
     pub gameday: GamedayState,
     pub box_score: BoxscoreState,
+    pub recap: RecapState,
     pub standings: StandingsState,
     pub stats: StatsState,
     pub help: HelpState,
     pub settings_editor: SettingsEditorState,
+    pub player_search: PlayerSearchState,
+    /// Whether the data shown came from the API or the cache because it couldn't be reached.
+    pub connection: Connection,
+    pub request_errors: RequestErrors,
 }
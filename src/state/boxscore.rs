@@ -19,6 +19,8 @@ pub struct BoxscoreState {
     pub scroll_state: ScrollbarState,
     pub cache: RenderCache,
     pub max_scroll: usize,
+    /// Leave out the game notes shown in Gameday's Game Info section.
+    pub skip_game_info: bool,
 }
 
 #[derive(Default)]
@@ -77,6 +79,14 @@ impl BoxscoreState {
         self.update_static_cache();
     }
 
+    /// Skip the game notes that are in the Game Info section, which is only drawn in Gameday.
+    pub fn set_skip_game_info(&mut self, skip_game_info: bool) {
+        if self.skip_game_info != skip_game_info {
+            self.skip_game_info = skip_game_info;
+            self.update_static_cache();
+        }
+    }
+
     pub fn reset(&mut self, game_id: Option<u64>) {
         let new_id = game_id.unwrap_or(0);
         if self.game_id != new_id {
@@ -98,7 +108,8 @@ impl BoxscoreState {
     fn update_static_cache(&mut self) {
         self.cache.home_team_cache = self.build_team_cache(Home);
         self.cache.away_team_cache = self.build_team_cache(Away);
-        self.cache.game_notes_paragraph = Self::build_paragraph(self.boxscore.get_game_notes());
+        self.cache.game_notes_paragraph =
+            Self::build_paragraph(self.boxscore.get_game_notes(self.skip_game_info));
         // Reset viewport width to force recalculation of wrapped content heights
         self.cache.last_viewport_width = 0;
     }
@@ -121,8 +132,10 @@ impl BoxscoreState {
         }
     }
 
-    fn build_paragraph(notes: &[Note]) -> Option<Paragraph<'static>> {
-        let lines: Vec<Line<'static>> = notes.iter().filter_map(|n| n.to_line()).collect();
+    fn build_paragraph<'a>(
+        notes: impl IntoIterator<Item = &'a Note>,
+    ) -> Option<Paragraph<'static>> {
+        let lines: Vec<Line<'static>> = notes.into_iter().filter_map(|n| n.to_line()).collect();
         if lines.is_empty() {
             None
         } else {
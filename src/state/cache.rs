@@ -1,32 +1,55 @@
-use crate::components::stats::table::StatType;
+use crate::components::stats::table::{StatType, TeamOrPlayer};
 use crate::state::messages::{NetworkRequest, NetworkResponse};
-use chrono::NaiveDate;
+use chrono::{DateTime, Datelike, NaiveDate, Utc};
 use log::debug;
+use mlbt_api::client::{Qualification, StatGroup};
 use mlbt_api::schedule::{AbstractGameState, ScheduleResponse};
+use mlbt_api::season::GameType;
 use mlbt_api::team::RosterType;
+use mlbt_api::teams::SportId;
 use std::collections::HashMap;
 use std::time::{Duration, Instant};
 
 const FINAL_GAME_TTL: Duration = Duration::from_hours(1);
+/// Days after which a date's schedule, standings and stats are considered settled. Games can end
+/// after midnight, and the local date can be ahead of the ballpark's, so yesterday isn't enough.
+const SETTLED_AFTER_DAYS: i64 = 2;
 const PRUNE_AGE: Duration = Duration::from_hours(48);
 pub const PRUNE_INTERVAL: Duration = Duration::from_hours(2);
 
-/// Cache key identifying a unique API request. PlayerProfile is excluded because it requires
-/// owned data for transformation (StatSplits stores Vec<Split> directly).
+/// Cache key identifying a unique API request.
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
 pub enum CacheKey {
     Schedule {
         date: NaiveDate,
+        sport: SportId,
     },
     GameData {
         game_id: u64,
     },
+    GameContent {
+        game_id: u64,
+    },
+    VsPlayer {
+        batter_id: u64,
+        pitcher_id: u64,
+        season: i32,
+    },
     Standings {
         date: NaiveDate,
+        sport: SportId,
+    },
+    PostseasonBracket {
+        season: i32,
     },
     Stats {
         date: NaiveDate,
         stat_type: StatType,
+        sport: SportId,
+    },
+    LeagueLeaders {
+        date: NaiveDate,
+        sport: SportId,
     },
     TeamPage {
         team_id: u16,
@@ -37,6 +60,20 @@ pub enum CacheKey {
         season: i32,
         roster_type: RosterType,
     },
+    InjuredList {
+        team_id: u16,
+        date: NaiveDate,
+    },
+    Draft {
+        year: i32,
+    },
+    /// The profile request only uses the year of its date.
+    PlayerProfile {
+        player_id: u64,
+        group: StatGroup,
+        season: i32,
+        game_type: GameType,
+    },
 }
 
 struct CacheEntry {
@@ -73,15 +110,45 @@ impl NetworkCache {
         }
     }
 
-    /// Map a network request to a cache key. Returns `None` for uncacheable requests (Initialize, PlayerProfile).
+    /// Map a network request to a cache key. Returns `None` for uncacheable requests (Initialize).
     pub fn key_for(request: &NetworkRequest) -> Option<CacheKey> {
         match request {
-            NetworkRequest::Schedule { date } => Some(CacheKey::Schedule { date: *date }),
+            NetworkRequest::Schedule { date, sport } => Some(CacheKey::Schedule {
+                date: *date,
+                sport: *sport,
+            }),
             NetworkRequest::GameData { game_id } => Some(CacheKey::GameData { game_id: *game_id }),
-            NetworkRequest::Standings { date } => Some(CacheKey::Standings { date: *date }),
-            NetworkRequest::Stats { date, stat_type } => Some(CacheKey::Stats {
+            NetworkRequest::GameContent { game_id } => {
+                Some(CacheKey::GameContent { game_id: *game_id })
+            }
+            NetworkRequest::VsPlayer {
+                batter_id,
+                pitcher_id,
+                season,
+            } => Some(CacheKey::VsPlayer {
+                batter_id: *batter_id,
+                pitcher_id: *pitcher_id,
+                season: *season,
+            }),
+            NetworkRequest::Standings { date, sport } => Some(CacheKey::Standings {
+                date: *date,
+                sport: *sport,
+            }),
+            NetworkRequest::PostseasonBracket { season } => {
+                Some(CacheKey::PostseasonBracket { season: *season })
+            }
+            NetworkRequest::Stats {
+                date,
+                stat_type,
+                sport,
+            } => Some(CacheKey::Stats {
                 date: *date,
                 stat_type: *stat_type,
+                sport: *sport,
+            }),
+            NetworkRequest::LeagueLeaders { date, sport } => Some(CacheKey::LeagueLeaders {
+                date: *date,
+                sport: *sport,
             }),
             NetworkRequest::TeamPage { team_id, date } => Some(CacheKey::TeamPage {
                 team_id: *team_id,
@@ -96,8 +163,27 @@ impl NetworkCache {
                 season: *season,
                 roster_type: *roster_type,
             }),
-            // Not cached: Initialize is one-shot, PlayerProfile consumes owned data
-            NetworkRequest::Initialize | NetworkRequest::PlayerProfile { .. } => None,
+            NetworkRequest::Draft { year } => Some(CacheKey::Draft { year: *year }),
+            NetworkRequest::InjuredList { team_id, date } => Some(CacheKey::InjuredList {
+                team_id: *team_id,
+                date: *date,
+            }),
+            NetworkRequest::PlayerProfile {
+                player_id,
+                group,
+                date,
+                game_type,
+            } => Some(CacheKey::PlayerProfile {
+                player_id: *player_id,
+                group: *group,
+                season: date.year(),
+                game_type: *game_type,
+            }),
+            // Not cached: Initialize is one-shot, and search results and spray charts are only
+            // shown once
+            NetworkRequest::Initialize
+            | NetworkRequest::SprayChart { .. }
+            | NetworkRequest::SearchPeople { .. } => None,
         }
     }
 
@@ -111,11 +197,70 @@ impl NetworkCache {
                     Duration::from_secs(10)
                 }
             }
+            CacheKey::GameContent { game_id } => {
+                if self.is_final_game(*game_id) {
+                    FINAL_GAME_TTL
+                } else {
+                    Duration::from_secs(600)
+                }
+            }
+            CacheKey::VsPlayer { .. } => Duration::from_secs(1800),
             CacheKey::Schedule { .. } => Duration::from_secs(30),
             CacheKey::Standings { .. } => Duration::from_secs(1800),
+            CacheKey::PostseasonBracket { .. } => Duration::from_secs(600),
             CacheKey::Stats { .. } => Duration::from_secs(1800),
+            CacheKey::LeagueLeaders { .. } => Duration::from_secs(1800),
             CacheKey::TeamPage { .. } => Duration::from_secs(600),
             CacheKey::TeamRoster { .. } => Duration::from_secs(1800),
+            CacheKey::InjuredList { .. } => Duration::from_secs(1800),
+            CacheKey::Draft { .. } => Duration::from_secs(3600),
+            CacheKey::PlayerProfile { .. } => Duration::from_secs(1800),
+        }
+    }
+
+    /// True if the response for the key won't change anymore, so it can be kept in the disk cache
+    /// indefinitely: final games, dates that are settled, and past seasons. Everything else only
+    /// lives in memory with its TTL.
+    ///
+    /// `game_type` is the game type on the key's date, or None if it isn't known yet. Stats and
+    /// standings for some game types are requested for the whole season rather than the date, so
+    /// those are only archived once the season is over.
+    pub fn is_archivable(
+        &self,
+        key: &CacheKey,
+        today: NaiveDate,
+        game_type: Option<GameType>,
+    ) -> bool {
+        let settled = |date: &NaiveDate| (today - *date).num_days() >= SETTLED_AFTER_DAYS;
+        let past_season = |season: &i32| *season < today.year();
+        match key {
+            CacheKey::GameData { game_id } => self.is_final_game(*game_id),
+            CacheKey::Schedule { date, .. } => settled(date),
+            CacheKey::Standings { date, .. } => {
+                let by_date = game_type
+                    .is_some_and(|t| !matches!(t, GameType::SpringTraining | GameType::Exhibition));
+                past_season(&date.year()) || (by_date && settled(date))
+            }
+            CacheKey::Stats {
+                date, stat_type, ..
+            } => {
+                let by_date = stat_type.team_player == TeamOrPlayer::Team
+                    || (game_type == Some(GameType::RegularSeason)
+                        && stat_type.qualification != Qualification::Qualified);
+                past_season(&date.year()) || (by_date && settled(date))
+            }
+            CacheKey::VsPlayer { season, .. }
+            | CacheKey::PostseasonBracket { season }
+            | CacheKey::TeamRoster { season, .. }
+            | CacheKey::Draft { year: season } => past_season(season),
+            CacheKey::TeamPage { date, .. } | CacheKey::LeagueLeaders { date, .. } => {
+                past_season(&date.year())
+            }
+            // recaps are published a while after the game ends, the injured list is only shown
+            // for today, and profiles include career totals that change with every game
+            CacheKey::GameContent { .. }
+            | CacheKey::InjuredList { .. }
+            | CacheKey::PlayerProfile { .. } => false,
         }
     }
 
@@ -142,7 +287,7 @@ impl NetworkCache {
     /// automatically updates game state tracking and invalidates affected caches.
     pub fn insert(&mut self, key: CacheKey, response: NetworkResponse) {
         let date = match &key {
-            CacheKey::Schedule { date } => Some(*date),
+            CacheKey::Schedule { date, .. } => Some(*date),
             _ => None,
         };
         let ttl = self.ttl_for(&key);
@@ -161,9 +306,20 @@ impl NetworkCache {
         }
     }
 
-    /// Remove a cache entry, forcing the next request to fetch from the API.
-    pub fn invalidate(&mut self, key: &CacheKey) {
-        self.entries.remove(key);
+    /// Expire a cache entry, forcing the next request to fetch from the API. The response is
+    /// kept so it can still be shown if the API can't be reached, see `get_stale`.
+    pub fn expire(&mut self, key: &CacheKey) {
+        if let Some(entry) = self.entries.get_mut(key) {
+            entry.ttl = Duration::ZERO;
+        }
+    }
+
+    /// The last response for a key even if it has expired, along with when it was fetched. Used
+    /// when the API can't be reached.
+    pub fn get_stale(&self, key: &CacheKey) -> Option<(NetworkResponse, DateTime<Utc>)> {
+        let entry = self.entries.get(key)?;
+        let fetched_at = Utc::now() - entry.fetched_at.elapsed();
+        Some((entry.response.clone(), fetched_at))
     }
 
     /// Remove cache entries and game states older than `PRUNE_AGE`.
@@ -197,7 +353,9 @@ impl NetworkCache {
     /// transitions to Final:
     /// - Extends that game's cached data TTL (data is now static)
     /// - Invalidates standings/stats caches for dates >= schedule_date (cumulative data stale)
+    /// - Invalidates the postseason bracket, league leaders and head-to-head totals for that season
     /// - Invalidates team page caches for the teams involved (season wide schedules stale)
+    /// - Invalidates profiles of players on the teams involved (game logs and totals stale)
     ///
     /// If we've never observed the game or the schedule_date in a non-final state (e.g. user
     /// browses an old date for the first time and all games there are already Final), we record
@@ -230,6 +388,8 @@ impl NetworkCache {
                         entry.ttl = FINAL_GAME_TTL;
                         entry.fetched_at = Instant::now();
                     }
+                    // the preview is replaced by a recap
+                    self.entries.remove(&CacheKey::GameContent { game_id });
                     // Only trust this transition as "live" if we previously saw the game
                     // non-final OR we've observed this date as mutable in this process.
                     let prior_non_final = matches!(
@@ -254,25 +414,37 @@ impl NetworkCache {
 
         if invalidate {
             debug!(
-                "invalidating standings/stats (>= {schedule_date}) and team pages for {affected_team_ids:?}"
+                "invalidating standings/stats (>= {schedule_date}) and team pages/profiles for {affected_team_ids:?}"
             );
-            self.entries.retain(|key, _| match key {
-                CacheKey::Standings { date: d } | CacheKey::Stats { date: d, .. } => {
+            self.entries.retain(|key, entry| match key {
+                CacheKey::Standings { date: d, .. } | CacheKey::Stats { date: d, .. } => {
                     *d < schedule_date
                 }
                 CacheKey::TeamPage { team_id, .. } => !affected_team_ids.contains(team_id),
+                CacheKey::PlayerProfile { .. } => profile_team_id(&entry.response)
+                    .is_none_or(|team_id| !affected_team_ids.contains(&team_id)),
+                CacheKey::PostseasonBracket { season } | CacheKey::VsPlayer { season, .. } => {
+                    *season != schedule_date.year()
+                }
+                CacheKey::LeagueLeaders { date, .. } => date.year() != schedule_date.year(),
                 _ => true,
             });
         }
     }
 }
 
+/// The current team of the player in a profile response.
+fn profile_team_id(response: &NetworkResponse) -> Option<u16> {
+    let NetworkResponse::PlayerProfileLoaded { data, .. } = response else {
+        return None;
+    };
+    Some(data.people.first()?.current_team.as_ref()?.id)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use mlbt_api::client::StatGroup;
     use mlbt_api::schedule::ScheduleResponse;
-    use mlbt_api::season::GameType;
     use std::sync::Arc;
 
     fn test_date() -> NaiveDate {
@@ -280,7 +452,17 @@ mod tests {
     }
 
     fn schedule_key() -> CacheKey {
-        CacheKey::Schedule { date: test_date() }
+        CacheKey::Schedule {
+            date: test_date(),
+            sport: SportId::Mlb,
+        }
+    }
+
+    fn standings_key(date: NaiveDate) -> CacheKey {
+        CacheKey::Standings {
+            date,
+            sport: SportId::Mlb,
+        }
     }
 
     fn schedule_response() -> NetworkResponse {
@@ -324,14 +506,43 @@ mod tests {
     }
 
     #[test]
-    fn key_for_returns_none_for_player_profile() {
-        let req = NetworkRequest::PlayerProfile {
+    fn key_for_player_profile_uses_the_season() {
+        let req = |date| NetworkRequest::PlayerProfile {
             player_id: 1,
             group: StatGroup::Hitting,
-            date: NaiveDate::from_ymd_opt(2026, 4, 13).unwrap(),
+            date,
             game_type: GameType::RegularSeason,
         };
-        assert!(NetworkCache::key_for(&req).is_none());
+        let key = NetworkCache::key_for(&req(test_date()));
+        assert_eq!(
+            key,
+            Some(CacheKey::PlayerProfile {
+                player_id: 1,
+                group: StatGroup::Hitting,
+                season: 2026,
+                game_type: GameType::RegularSeason,
+            })
+        );
+        let later = NaiveDate::from_ymd_opt(2026, 6, 1).unwrap();
+        assert_eq!(NetworkCache::key_for(&req(later)), key);
+    }
+
+    #[test]
+    fn key_for_league_leaders_uses_the_date_and_level() {
+        // the date decides the game type, e.g. spring training or the postseason
+        let req = |date, sport| NetworkRequest::LeagueLeaders { date, sport };
+        let key = NetworkCache::key_for(&req(test_date(), SportId::Mlb));
+        assert_eq!(
+            key,
+            Some(CacheKey::LeagueLeaders {
+                date: test_date(),
+                sport: SportId::Mlb,
+            })
+        );
+        assert_ne!(
+            NetworkCache::key_for(&req(test_date(), SportId::TripleA)),
+            key
+        );
     }
 
     fn make_schedule(date: NaiveDate, games: Vec<(u64, AbstractGameState)>) -> ScheduleResponse {
@@ -403,7 +614,7 @@ mod tests {
 
         // Cache standings and stats
         cache.insert(
-            CacheKey::Standings { date },
+            standings_key(date),
             NetworkResponse::StandingsLoaded {
                 standings: Arc::new(mlbt_api::standings::StandingsResponse::default()),
             },
@@ -412,12 +623,13 @@ mod tests {
             CacheKey::Stats {
                 date,
                 stat_type: StatType::default(),
+                sport: SportId::Mlb,
             },
             NetworkResponse::StatsLoaded {
                 stats: Arc::new(mlbt_api::stats::StatsResponse::default()),
             },
         );
-        assert!(cache.get(&CacheKey::Standings { date }).is_some());
+        assert!(cache.get(&standings_key(date)).is_some());
 
         // Game goes from Live to Final
         let schedule = make_schedule(test_date(), vec![(123, AbstractGameState::Live)]);
@@ -426,17 +638,121 @@ mod tests {
         cache.update_game_states(test_date(), &schedule);
 
         // Standings and stats should be invalidated
-        assert!(cache.get(&CacheKey::Standings { date }).is_none());
+        assert!(cache.get(&standings_key(date)).is_none());
         assert!(
             cache
                 .get(&CacheKey::Stats {
                     date,
                     stat_type: StatType::default(),
+                    sport: SportId::Mlb,
                 })
                 .is_none()
         );
     }
 
+    #[test]
+    fn final_game_invalidates_head_to_head_for_season() {
+        let mut cache = NetworkCache::new();
+        let key = |season| CacheKey::VsPlayer {
+            batter_id: 592450,
+            pitcher_id: 605483,
+            season,
+        };
+        let season = test_date().year();
+        for s in [season, season - 1] {
+            cache.insert(
+                key(s),
+                NetworkResponse::VsPlayerLoaded {
+                    batter_id: 592450,
+                    pitcher_id: 605483,
+                    season: s,
+                    stats: Arc::new(mlbt_api::vs_player::VsPlayerResponse::default()),
+                },
+            );
+        }
+
+        let schedule = make_schedule(test_date(), vec![(123, AbstractGameState::Live)]);
+        cache.update_game_states(test_date(), &schedule);
+        let schedule = make_schedule(test_date(), vec![(123, AbstractGameState::Final)]);
+        cache.update_game_states(test_date(), &schedule);
+
+        assert!(cache.get(&key(season)).is_none());
+        assert!(cache.get(&key(season - 1)).is_some());
+    }
+
+    #[test]
+    fn final_game_invalidates_profiles_of_its_teams() {
+        use mlbt_api::player::{PeopleResponse, PersonFull};
+        use mlbt_api::schedule::IdNameLink;
+
+        let mut cache = NetworkCache::new();
+        let key = |player_id| CacheKey::PlayerProfile {
+            player_id,
+            group: StatGroup::Hitting,
+            season: 2026,
+            game_type: GameType::RegularSeason,
+        };
+        for (player_id, team_id) in [(1, 147), (2, 121)] {
+            let person = PersonFull {
+                id: player_id,
+                current_team: Some(IdNameLink {
+                    id: team_id,
+                    ..Default::default()
+                }),
+                ..Default::default()
+            };
+            cache.insert(
+                key(player_id),
+                NetworkResponse::PlayerProfileLoaded {
+                    data: Arc::new(PeopleResponse {
+                        people: vec![person],
+                    }),
+                    group: StatGroup::Hitting,
+                    game_type: GameType::RegularSeason,
+                },
+            );
+        }
+
+        let schedule = |state| {
+            let mut schedule = make_schedule(test_date(), vec![(123, state)]);
+            let game = &mut schedule.dates[0].games.as_mut().unwrap()[0];
+            game.teams.home.team.id = 147;
+            game.teams.away.team.id = 111;
+            schedule
+        };
+        cache.update_game_states(test_date(), &schedule(AbstractGameState::Live));
+        assert!(cache.get(&key(1)).is_some());
+        cache.update_game_states(test_date(), &schedule(AbstractGameState::Final));
+
+        assert!(cache.get(&key(1)).is_none());
+        assert!(cache.get(&key(2)).is_some());
+    }
+
+    #[test]
+    fn final_game_invalidates_its_content() {
+        let mut cache = NetworkCache::new();
+        let key = |game_id| CacheKey::GameContent { game_id };
+        for game_id in [123, 456] {
+            cache.insert(
+                key(game_id),
+                NetworkResponse::GameContentLoaded {
+                    game_id,
+                    content: Arc::new(mlbt_api::content::ContentResponse::default()),
+                },
+            );
+        }
+
+        let games = |state| vec![(123, state), (456, AbstractGameState::Live)];
+        let schedule = make_schedule(test_date(), games(AbstractGameState::Live));
+        cache.update_game_states(test_date(), &schedule);
+        let schedule = make_schedule(test_date(), games(AbstractGameState::Final));
+        cache.update_game_states(test_date(), &schedule);
+
+        // the preview is dropped so the recap gets loaded
+        assert!(cache.get(&key(123)).is_none());
+        assert!(cache.get(&key(456)).is_some());
+    }
+
     #[test]
     fn already_final_game_does_not_re_invalidate() {
         let mut cache = NetworkCache::new();
@@ -448,7 +764,7 @@ mod tests {
 
         // Cache standings after the game went Final
         cache.insert(
-            CacheKey::Standings { date },
+            standings_key(date),
             NetworkResponse::StandingsLoaded {
                 standings: Arc::new(mlbt_api::standings::StandingsResponse::default()),
             },
@@ -456,7 +772,7 @@ mod tests {
 
         // Same schedule again, game is still Final, not a new transition
         cache.update_game_states(test_date(), &schedule);
-        assert!(cache.get(&CacheKey::Standings { date }).is_some());
+        assert!(cache.get(&standings_key(date)).is_some());
     }
 
     #[test]
@@ -465,7 +781,7 @@ mod tests {
         let date = NaiveDate::from_ymd_opt(2026, 4, 13).unwrap();
 
         cache.insert(
-            CacheKey::Standings { date },
+            standings_key(date),
             NetworkResponse::StandingsLoaded {
                 standings: Arc::new(mlbt_api::standings::StandingsResponse::default()),
             },
@@ -476,7 +792,7 @@ mod tests {
         let schedule = make_schedule(test_date(), vec![(456, AbstractGameState::Final)]);
         cache.update_game_states(test_date(), &schedule);
 
-        assert!(cache.get(&CacheKey::Standings { date }).is_some());
+        assert!(cache.get(&standings_key(date)).is_some());
     }
 
     #[test]
@@ -587,7 +903,7 @@ mod tests {
         let today = NaiveDate::from_ymd_opt(2026, 4, 13).unwrap();
 
         cache.insert(
-            CacheKey::Standings { date: today },
+            standings_key(today),
             NetworkResponse::StandingsLoaded {
                 standings: Arc::new(mlbt_api::standings::StandingsResponse::default()),
             },
@@ -599,7 +915,7 @@ mod tests {
         cache.update_game_states(past, &schedule);
 
         // No evidence the cached standings are stale.
-        assert!(cache.get(&CacheKey::Standings { date: today }).is_some());
+        assert!(cache.get(&standings_key(today)).is_some());
     }
 
     #[test]
@@ -609,7 +925,7 @@ mod tests {
         let today = NaiveDate::from_ymd_opt(2026, 4, 13).unwrap();
 
         cache.insert(
-            CacheKey::Standings { date: today },
+            standings_key(today),
             NetworkResponse::StandingsLoaded {
                 standings: Arc::new(mlbt_api::standings::StandingsResponse::default()),
             },
@@ -622,7 +938,7 @@ mod tests {
         let schedule = make_schedule(past, vec![(123, AbstractGameState::Final)]);
         cache.update_game_states(past, &schedule);
 
-        assert!(cache.get(&CacheKey::Standings { date: today }).is_some());
+        assert!(cache.get(&standings_key(today)).is_some());
     }
 
     #[test]
@@ -631,7 +947,7 @@ mod tests {
         let date = test_date();
 
         cache.insert(
-            CacheKey::Standings { date },
+            standings_key(date),
             NetworkResponse::StandingsLoaded {
                 standings: Arc::new(mlbt_api::standings::StandingsResponse::default()),
             },
@@ -645,7 +961,7 @@ mod tests {
         let schedule = make_schedule(test_date(), vec![(123, AbstractGameState::Final)]);
         cache.update_game_states(date, &schedule);
 
-        assert!(cache.get(&CacheKey::Standings { date }).is_none());
+        assert!(cache.get(&standings_key(date)).is_none());
     }
 
     #[test]
@@ -657,7 +973,7 @@ mod tests {
 
         for date in [apr10, apr13, apr14] {
             cache.insert(
-                CacheKey::Standings { date },
+                standings_key(date),
                 NetworkResponse::StandingsLoaded {
                     standings: Arc::new(mlbt_api::standings::StandingsResponse::default()),
                 },
@@ -671,9 +987,9 @@ mod tests {
         cache.update_game_states(test_date(), &schedule);
 
         // Earlier date preserved, transition date and later invalidated
-        assert!(cache.get(&CacheKey::Standings { date: apr10 }).is_some());
-        assert!(cache.get(&CacheKey::Standings { date: apr13 }).is_none());
-        assert!(cache.get(&CacheKey::Standings { date: apr14 }).is_none());
+        assert!(cache.get(&standings_key(apr10)).is_some());
+        assert!(cache.get(&standings_key(apr13)).is_none());
+        assert!(cache.get(&standings_key(apr14)).is_none());
     }
 
     #[test]
@@ -773,4 +1089,88 @@ mod tests {
         cache.update_game_states(test_date(), &schedule);
         assert!(cache.is_final_game(42));
     }
+
+    #[test]
+    fn only_settled_data_is_archivable() {
+        let mut cache = NetworkCache::new();
+        let today = test_date();
+        let regular = Some(GameType::RegularSeason);
+        let days_ago = |days| today - chrono::Duration::days(days);
+        assert!(!cache.is_archivable(&standings_key(days_ago(1)), today, regular));
+        assert!(cache.is_archivable(&standings_key(days_ago(2)), today, regular));
+        assert!(!cache.is_archivable(&CacheKey::Draft { year: 2026 }, today, None));
+        assert!(cache.is_archivable(&CacheKey::Draft { year: 2025 }, today, None));
+
+        let game = CacheKey::GameData { game_id: 42 };
+        let schedule = make_schedule(today, vec![(42, AbstractGameState::Live)]);
+        cache.update_game_states(today, &schedule);
+        assert!(!cache.is_archivable(&game, today, None));
+        let schedule = make_schedule(today, vec![(42, AbstractGameState::Final)]);
+        cache.update_game_states(today, &schedule);
+        assert!(cache.is_archivable(&game, today, None));
+        assert!(!cache.is_archivable(&CacheKey::GameContent { game_id: 42 }, today, None));
+    }
+
+    #[test]
+    fn season_to_date_standings_are_archived_after_the_season() {
+        let cache = NetworkCache::new();
+        let today = test_date();
+        let settled = today - chrono::Duration::days(7);
+        for game_type in [GameType::SpringTraining, GameType::Exhibition] {
+            assert!(!cache.is_archivable(&standings_key(settled), today, Some(game_type)));
+        }
+        // not known until the season info loads
+        assert!(!cache.is_archivable(&standings_key(settled), today, None));
+        let last_season = NaiveDate::from_ymd_opt(2025, 3, 10).unwrap();
+        assert!(cache.is_archivable(
+            &standings_key(last_season),
+            today,
+            Some(GameType::SpringTraining)
+        ));
+    }
+
+    #[test]
+    fn season_to_date_stats_are_archived_after_the_season() {
+        let cache = NetworkCache::new();
+        let today = test_date();
+        let settled = today - chrono::Duration::days(7);
+        let key = |date, team_player, qualification| CacheKey::Stats {
+            date,
+            stat_type: StatType {
+                group: StatGroup::Hitting,
+                team_player,
+                qualification,
+            },
+            sport: SportId::Mlb,
+        };
+        let player = key(settled, TeamOrPlayer::Player, Qualification::All);
+        let qualified = key(settled, TeamOrPlayer::Player, Qualification::Qualified);
+        let team = key(settled, TeamOrPlayer::Team, Qualification::All);
+
+        let regular = Some(GameType::RegularSeason);
+        assert!(cache.is_archivable(&player, today, regular));
+        assert!(!cache.is_archivable(&qualified, today, regular));
+        assert!(cache.is_archivable(&team, today, regular));
+        for game_type in [GameType::SpringTraining, GameType::WildCard] {
+            assert!(!cache.is_archivable(&player, today, Some(game_type)));
+        }
+        assert!(!cache.is_archivable(&player, today, None));
+
+        let last_season = NaiveDate::from_ymd_opt(2025, 10, 1).unwrap();
+        let qualified = key(last_season, TeamOrPlayer::Player, Qualification::Qualified);
+        assert!(cache.is_archivable(&qualified, today, Some(GameType::WildCard)));
+    }
+
+    #[test]
+    fn expired_entries_are_still_available_stale() {
+        let mut cache = NetworkCache::new();
+        cache.insert(schedule_key(), schedule_response());
+        cache.expire(&schedule_key());
+        assert!(cache.get(&schedule_key()).is_none());
+
+        let (response, fetched_at) = cache.get_stale(&schedule_key()).unwrap();
+        assert!(matches!(response, NetworkResponse::ScheduleLoaded { .. }));
+        assert!(Utc::now() - fetched_at < chrono::Duration::seconds(5));
+        assert!(cache.get_stale(&standings_key(test_date())).is_none());
+    }
 }
@@ -0,0 +1,276 @@
+use crate::components::constants::lookup_team_by_id;
+use crate::components::team_page::PositionGroup;
+use crate::state::messages::NetworkRequest;
+use chrono::{Datelike, NaiveDate};
+use mlbt_api::client::StatGroup;
+use mlbt_api::draft::DraftResponse;
+use mlbt_api::season::GameType;
+use tui::widgets::TableState;
+
+/// The first year of the amateur draft.
+const FIRST_DRAFT_YEAR: i32 = 1965;
+
+/// The picks of an amateur draft, opened from the team page. Starts filtered to the team.
+pub struct DraftState {
+    pub year: i32,
+    pub team_id: u16,
+    /// Only show the team's picks.
+    pub team_only: bool,
+    pub picks: Vec<DraftPickRow>,
+    /// Indices into `picks` that pass the team filter.
+    pub visible: Vec<usize>,
+    pub selection: TableState,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct DraftPickRow {
+    pub round: String,
+    pub pick_number: u16,
+    pub round_pick_number: u16,
+    pub team_id: u16,
+    /// Abbreviation for current teams, the full name for teams that no longer exist.
+    pub team: String,
+    /// None for picks that haven't been made.
+    pub player_id: Option<u64>,
+    pub name: String,
+    pub position: String,
+    pub school: String,
+    /// Profile layout to open, pitchers use pitching and everyone else hitting.
+    pub group: StatGroup,
+}
+
+impl DraftPickRow {
+    fn from_response(response: &DraftResponse) -> Vec<DraftPickRow> {
+        let mut picks: Vec<DraftPickRow> = response
+            .drafts
+            .rounds
+            .iter()
+            .flat_map(|round| &round.picks)
+            .map(|pick| {
+                let person = pick.person.as_ref();
+                let position = person.and_then(|p| p.primary_position.as_ref());
+                let team = lookup_team_by_id(pick.team.id)
+                    .map(|t| t.abbreviation.to_string())
+                    .unwrap_or_else(|| pick.team.name.clone());
+                DraftPickRow {
+                    round: pick.pick_round.clone(),
+                    pick_number: pick.pick_number,
+                    round_pick_number: pick.round_pick_number,
+                    team_id: pick.team.id,
+                    team,
+                    player_id: person.map(|p| p.id),
+                    name: person.map(|p| p.full_name.clone()).unwrap_or_default(),
+                    position: position.map(|p| p.abbreviation.clone()).unwrap_or_default(),
+                    school: pick
+                        .school
+                        .as_ref()
+                        .map(|s| s.name.clone())
+                        .unwrap_or_default(),
+                    group: position
+                        .map(|p| PositionGroup::from_api(&p.r#type).stat_group())
+                        .unwrap_or(StatGroup::Hitting),
+                }
+            })
+            .collect();
+        picks.sort_by_key(|p| p.pick_number);
+        picks
+    }
+}
+
+impl DraftState {
+    const PAGE_SIZE: usize = 10;
+
+    pub fn from_response(year: i32, team_id: u16, response: &DraftResponse) -> Self {
+        let mut state = Self {
+            year,
+            team_id,
+            team_only: true,
+            picks: DraftPickRow::from_response(response),
+            visible: Vec::new(),
+            selection: TableState::default(),
+        };
+        state.apply_filter();
+        state
+    }
+
+    /// Replace the picks with another year's draft, keeping the team filter.
+    pub fn update(&mut self, year: i32, response: &DraftResponse) {
+        self.year = year;
+        self.picks = DraftPickRow::from_response(response);
+        self.apply_filter();
+    }
+
+    pub fn toggle_team_only(&mut self) {
+        self.team_only = !self.team_only;
+        self.apply_filter();
+    }
+
+    fn apply_filter(&mut self) {
+        self.visible = self
+            .picks
+            .iter()
+            .enumerate()
+            .filter(|(_, p)| !self.team_only || p.team_id == self.team_id)
+            .map(|(i, _)| i)
+            .collect();
+        self.selection = TableState::default();
+        if !self.visible.is_empty() {
+            self.selection.select(Some(0));
+        }
+    }
+
+    pub fn selected(&self) -> Option<&DraftPickRow> {
+        let idx = self.selection.selected()?;
+        self.visible.get(idx).map(|&i| &self.picks[i])
+    }
+
+    pub fn next(&mut self) {
+        let len = self.visible.len();
+        if len == 0 {
+            return;
+        }
+        let i = self.selection.selected().map_or(0, |i| (i + 1) % len);
+        self.selection.select(Some(i));
+    }
+
+    pub fn previous(&mut self) {
+        let len = self.visible.len();
+        if len == 0 {
+            return;
+        }
+        let i = self
+            .selection
+            .selected()
+            .map_or(0, |i| if i == 0 { len - 1 } else { i - 1 });
+        self.selection.select(Some(i));
+    }
+
+    pub fn page_down(&mut self) {
+        let last = self.visible.len().saturating_sub(1);
+        let i = self.selection.selected().unwrap_or(0) + Self::PAGE_SIZE;
+        self.selection.select(Some(i.min(last)));
+    }
+
+    pub fn page_up(&mut self) {
+        let i = self.selection.selected().unwrap_or(0);
+        self.selection
+            .select(Some(i.saturating_sub(Self::PAGE_SIZE)));
+    }
+
+    /// Request the draft `delta` years from the current one, if that draft has been held.
+    pub fn year_request(&self, delta: i32, today: NaiveDate) -> Option<NetworkRequest> {
+        let year = self.year + delta;
+        if !(FIRST_DRAFT_YEAR..=today.year()).contains(&year) {
+            return None;
+        }
+        Some(NetworkRequest::Draft { year })
+    }
+
+    /// Open the selected pick's profile. Picks that haven't been made have no player.
+    pub fn player_profile_request(&self, date: NaiveDate) -> Option<NetworkRequest> {
+        let pick = self.selected()?;
+        Some(NetworkRequest::PlayerProfile {
+            player_id: pick.player_id?,
+            group: pick.group,
+            date,
+            game_type: GameType::RegularSeason,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mlbt_api::draft::{Draft, DraftPerson, DraftPick, DraftRound};
+    use mlbt_api::live::PrimaryPosition;
+    use mlbt_api::schedule::IdNameLink;
+
+    fn pick(number: u16, team_id: u16, player: Option<(u64, &str)>) -> DraftPick {
+        DraftPick {
+            pick_round: "1".to_string(),
+            pick_number: number,
+            round_pick_number: number,
+            person: player.map(|(id, position)| DraftPerson {
+                id,
+                full_name: format!("Player {id}"),
+                primary_position: Some(PrimaryPosition {
+                    code: String::new(),
+                    name: String::new(),
+                    r#type: position.to_string(),
+                    abbreviation: String::new(),
+                }),
+            }),
+            team: IdNameLink {
+                id: team_id,
+                name: String::new(),
+                link: String::new(),
+                parent_org_id: None,
+            },
+            school: None,
+            signing_bonus: None,
+            pick_value: None,
+            is_drafted: player.is_some(),
+            is_pass: false,
+        }
+    }
+
+    fn draft() -> DraftResponse {
+        DraftResponse {
+            drafts: Draft {
+                draft_year: 2024,
+                rounds: vec![DraftRound {
+                    round: "1".to_string(),
+                    picks: vec![
+                        pick(2, 113, Some((2, "Pitcher"))),
+                        pick(1, 134, Some((1, "Infielder"))),
+                        pick(3, 134, None),
+                    ],
+                }],
+            },
+        }
+    }
+
+    #[test]
+    fn team_filter_toggles() {
+        let mut s = DraftState::from_response(2024, 134, &draft());
+        let numbers = |s: &DraftState| {
+            s.visible
+                .iter()
+                .map(|&i| s.picks[i].pick_number)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(numbers(&s), [1, 3]);
+        s.toggle_team_only();
+        assert_eq!(numbers(&s), [1, 2, 3]);
+        assert_eq!(s.selection.selected(), Some(0));
+    }
+
+    #[test]
+    fn profile_only_for_made_picks() {
+        let date = NaiveDate::from_ymd_opt(2025, 4, 1).unwrap();
+        let mut s = DraftState::from_response(2024, 134, &draft());
+        s.toggle_team_only();
+        s.next();
+        let Some(NetworkRequest::PlayerProfile {
+            player_id, group, ..
+        }) = s.player_profile_request(date)
+        else {
+            panic!("expected a profile request");
+        };
+        assert_eq!((player_id, group), (2, StatGroup::Pitching));
+
+        s.next();
+        assert!(s.player_profile_request(date).is_none());
+    }
+
+    #[test]
+    fn year_request_stays_within_held_drafts() {
+        let today = NaiveDate::from_ymd_opt(2024, 8, 1).unwrap();
+        let s = DraftState::from_response(2024, 134, &draft());
+        assert!(s.year_request(1, today).is_none());
+        assert!(matches!(
+            s.year_request(-1, today),
+            Some(NetworkRequest::Draft { year: 2023 })
+        ));
+    }
+}
@@ -1,6 +1,10 @@
+use crate::components::game::head_to_head::HeadToHead;
 use crate::components::game::live_game::GameState;
+use crate::components::game::spray_chart::BattedBall;
+use crate::state::messages::NetworkRequest;
 use mlbt_api::live::LiveResponse;
 use mlbt_api::schedule::AbstractGameState;
+use mlbt_api::vs_player::VsPlayerResponse;
 use mlbt_api::win_probability::WinProbabilityResponse;
 use tui::widgets::ScrollbarState;
 
@@ -9,11 +13,16 @@ pub struct GamedayState {
     pub panels: GamedayPanels,
     pub game: GameState,
     pub scoring_plays_only: bool,
+    /// Plot every ball in play in the spray chart instead of only the selected batter's.
+    pub spray_chart_whole_game: bool,
     /// The at bat index (map key) that is currently selected, or `None` when live.
     selected_at_bat: Option<u8>,
     /// Snap to a scoring play when the next update arrives. Set when switching games with the
     /// scoring play filter on, since the new game's at bats load asynchronously.
     snap_pending: bool,
+    /// The selected pitch as (at bat index, pitch number). Only shown while that at bat is the one
+    /// displayed, so moving between at bats doesn't need to clear it.
+    selected_pitch: Option<(u8, u8)>,
     pub plays_scroll_offset: u16,
     pub plays_scroll_state: ScrollbarState,
 }
@@ -23,6 +32,16 @@ impl GamedayState {
         self.selected_at_bat
     }
 
+    /// The pitch number selected in the displayed at bat, if any.
+    pub fn selected_pitch(&self) -> Option<u8> {
+        let (at_bat, _) = self
+            .game
+            .get_at_bat_by_index_or_current(self.selected_at_bat);
+        self.selected_pitch
+            .filter(|(index, _)| *index == at_bat.index)
+            .map(|(_, pitch)| pitch)
+    }
+
     pub fn current_game_id(&self) -> u64 {
         self.game.game_id
     }
@@ -55,10 +74,78 @@ impl GamedayState {
         self.snap_if_pending();
     }
 
+    /// The request for the selected at bat's head-to-head history, if it hasn't already been
+    /// requested for this game.
+    pub fn head_to_head_request(&mut self, season: i32) -> Option<NetworkRequest> {
+        let (at_bat, _) = self
+            .game
+            .get_at_bat_by_index_or_current(self.selected_at_bat);
+        let (batter_id, pitcher_id) = (at_bat.matchup.batter_id, at_bat.matchup.pitcher_id);
+        if batter_id == 0 || pitcher_id == 0 {
+            return None;
+        }
+        if self
+            .game
+            .head_to_head
+            .contains_key(&(batter_id, pitcher_id))
+        {
+            return None;
+        }
+        self.game.head_to_head.insert((batter_id, pitcher_id), None);
+        Some(NetworkRequest::VsPlayer {
+            batter_id,
+            pitcher_id,
+            season,
+        })
+    }
+
+    pub fn update_head_to_head(
+        &mut self,
+        batter_id: u64,
+        pitcher_id: u64,
+        season: i32,
+        stats: &VsPlayerResponse,
+    ) {
+        self.game.head_to_head.insert(
+            (batter_id, pitcher_id),
+            Some(HeadToHead::from_response(stats, season)),
+        );
+    }
+
+    /// Remove the in flight marker of a failed request so the matchup is requested again.
+    pub fn head_to_head_failed(&mut self, batter_id: u64, pitcher_id: u64) {
+        let key = (batter_id, pitcher_id);
+        if matches!(self.game.head_to_head.get(&key), Some(None)) {
+            self.game.head_to_head.remove(&key);
+        }
+    }
+
+    /// The balls in play to plot in the spray chart, up to and including the selected at bat.
+    /// Returns the batter they're filtered on, or `None` when showing the whole game.
+    pub fn spray_chart_balls(&self) -> (Option<u64>, Vec<&BattedBall>) {
+        let (selected, _) = self
+            .game
+            .get_at_bat_by_index_or_current(self.selected_at_bat);
+        let batter_id = match self.spray_chart_whole_game {
+            true => None,
+            false => Some(selected.matchup.batter_id),
+        };
+        let balls = self
+            .game
+            .at_bats
+            .values()
+            .filter(|at_bat| at_bat.index <= selected.index)
+            .filter_map(|at_bat| at_bat.batted_ball.as_ref())
+            .filter(|ball| batter_id.is_none_or(|id| ball.batter_id == id))
+            .collect();
+        (batter_id, balls)
+    }
+
     /// Clear the selection and, if the scoring play filter is on, mark a snap as pending once the
     /// new game's at bats load.
     fn on_game_changing(&mut self) {
         self.selected_at_bat = None;
+        self.selected_pitch = None;
         self.snap_pending = self.scoring_plays_only;
     }
 
@@ -115,6 +202,41 @@ impl GamedayState {
         self.selected_at_bat = Some(indexes[prev]);
     }
 
+    /// The pitch numbers in the displayed at bat and its index.
+    fn displayed_pitches(&self) -> (u8, Vec<u8>) {
+        let (at_bat, _) = self
+            .game
+            .get_at_bat_by_index_or_current(self.selected_at_bat);
+        let pitches = at_bat
+            .pitches
+            .pitches
+            .pitch_events
+            .iter()
+            .filter_map(|event| event.pitch.as_ref().map(|pitch| pitch.index))
+            .collect();
+        (at_bat.index, pitches)
+    }
+
+    /// Select the next pitch in the displayed at bat, wrapping around to the first.
+    pub fn next_pitch(&mut self) {
+        let (at_bat, pitches) = self.displayed_pitches();
+        let next = match self.selected_pitch() {
+            Some(selected) => pitches.iter().find(|&&p| p > selected).or(pitches.first()),
+            None => pitches.first(),
+        };
+        self.selected_pitch = next.map(|&pitch| (at_bat, pitch));
+    }
+
+    /// Select the previous pitch in the displayed at bat, wrapping around to the last.
+    pub fn previous_pitch(&mut self) {
+        let (at_bat, pitches) = self.displayed_pitches();
+        let prev = match self.selected_pitch() {
+            Some(selected) => pitches.iter().rfind(|&&p| p < selected).or(pitches.last()),
+            None => pitches.last(),
+        };
+        self.selected_pitch = prev.map(|&pitch| (at_bat, pitch));
+    }
+
     /// Go to "live" at bat by deselecting the current at bat. When the scoring play filter is
     /// enabled, "live" means the most recent scoring play instead, matching what enabling the
     /// filter while live already selects.
@@ -148,6 +270,22 @@ impl GamedayState {
         self.panels.win_probability = !self.panels.win_probability;
     }
 
+    pub fn toggle_spray_chart(&mut self) {
+        self.panels.spray_chart = !self.panels.spray_chart;
+    }
+
+    pub fn toggle_game_info(&mut self) {
+        self.panels.game_info = !self.panels.game_info;
+    }
+
+    pub fn toggle_recap(&mut self) {
+        self.panels.recap = !self.panels.recap;
+    }
+
+    pub fn toggle_spray_chart_scope(&mut self) {
+        self.spray_chart_whole_game = !self.spray_chart_whole_game;
+    }
+
     pub fn toggle_scoring_plays_only(&mut self) {
         self.scoring_plays_only = !self.scoring_plays_only;
         if self.scoring_plays_only {
@@ -181,12 +319,20 @@ pub struct GamedayPanels {
     pub at_bat: bool,
     pub boxscore: bool,
     pub win_probability: bool,
+    /// Venue, weather and umpires at the top of the info panel.
+    pub game_info: bool,
+    pub spray_chart: bool,
+    pub recap: bool,
 }
 
 impl GamedayPanels {
     /// Return the number of panels that are active.
     pub fn count(&self) -> usize {
-        self.info as usize + self.at_bat as usize + self.boxscore as usize
+        self.info as usize
+            + self.at_bat as usize
+            + self.spray_chart as usize
+            + self.recap as usize
+            + self.boxscore as usize
     }
 }
 
@@ -197,6 +343,9 @@ impl Default for GamedayPanels {
             at_bat: true,
             boxscore: false,
             win_probability: true,
+            game_info: true,
+            spray_chart: false,
+            recap: false,
         }
     }
 }
@@ -227,6 +376,49 @@ mod tests {
         }
     }
 
+    #[test]
+    fn failed_head_to_head_is_requested_again() {
+        let mut state = state_with(&[0], &[]);
+        let at_bat = state.game.at_bats.get_mut(&0).unwrap();
+        at_bat.matchup.batter_id = 10;
+        at_bat.matchup.pitcher_id = 20;
+        state.selected_at_bat = Some(0);
+
+        assert!(state.head_to_head_request(2025).is_some());
+        assert!(state.head_to_head_request(2025).is_none(), "in flight");
+        state.head_to_head_failed(10, 20);
+        assert!(state.head_to_head_request(2025).is_some());
+    }
+
+    #[test]
+    fn spray_chart_filters_by_batter_up_to_selection() {
+        use crate::components::game::spray_chart::{BattedBall, HitOutcome};
+
+        let mut state = state_with(&[0, 1, 2, 3], &[]);
+        for (index, batter_id) in [(0, 10), (1, 20), (2, 10), (3, 10)] {
+            let at_bat = state.game.at_bats.get_mut(&index).unwrap();
+            at_bat.matchup.batter_id = batter_id;
+            at_bat.batted_ball = Some(BattedBall {
+                batter_id,
+                pitcher_id: 1,
+                x: 0.0,
+                y: f64::from(index) * 100.0,
+                outcome: HitOutcome::Single,
+            });
+        }
+        state.selected_at_bat = Some(2);
+
+        let (batter, balls) = state.spray_chart_balls();
+        assert_eq!(batter, Some(10));
+        let ys: Vec<f64> = balls.iter().map(|b| b.y).collect();
+        assert_eq!(ys, [0.0, 200.0]);
+
+        state.toggle_spray_chart_scope();
+        let (batter, balls) = state.spray_chart_balls();
+        assert_eq!(batter, None);
+        assert_eq!(balls.len(), 3);
+    }
+
     #[test]
     fn navigation_walks_every_play_normally() {
         let mut state = state_with(&[0, 1, 2], &[1]);
@@ -244,6 +436,48 @@ mod tests {
         assert_eq!(state.selected_at_bat(), Some(0));
     }
 
+    #[test]
+    fn pitch_selection_cycles_within_displayed_at_bat() {
+        use crate::components::game::pitch_event::{PitchEvent, PitchEventType};
+        use crate::components::game::pitches::Pitch;
+
+        let mut state = state_with(&[0, 1], &[]);
+        // a mound visit between pitches 1 and 2 isn't selectable
+        let events = [Some(1), None, Some(2), Some(3)].map(|index| PitchEvent {
+            event_type: PitchEventType::Pitch,
+            description: String::new(),
+            pitch: index.map(|index| Pitch {
+                index,
+                ..Pitch::default()
+            }),
+            hit_data: None,
+            is_scoring: None,
+            away_score: None,
+            home_score: None,
+        });
+        let at_bat = state.game.at_bats.get_mut(&1).unwrap();
+        at_bat.pitches.pitches.pitch_events = events.into();
+        state.selected_at_bat = Some(1);
+
+        assert_eq!(state.selected_pitch(), None);
+        state.next_pitch();
+        assert_eq!(state.selected_pitch(), Some(1));
+        state.next_pitch();
+        assert_eq!(state.selected_pitch(), Some(2));
+        state.previous_pitch();
+        state.previous_pitch();
+        // wraps to the last pitch
+        assert_eq!(state.selected_pitch(), Some(3));
+
+        // the selection belongs to its at bat
+        state.previous_at_bat();
+        assert_eq!(state.selected_pitch(), None);
+        state.next_pitch();
+        assert_eq!(state.selected_pitch(), None);
+        state.next_at_bat();
+        assert_eq!(state.selected_pitch(), None);
+    }
+
     #[test]
     fn navigation_skips_non_scoring_plays_when_filtered() {
         let mut state = state_with(&[0, 1, 2, 3, 4], &[1, 3]);
@@ -1,33 +1,58 @@
+use crate::components::game::spray_chart::BattedBall;
 use crate::components::stats::table::StatType;
-use crate::state::network::LoadingState;
+use crate::state::network::{Connection, LoadingState};
 use chrono::NaiveDate;
 use crossterm::event::KeyEvent;
-use mlbt_api::client::StatGroup;
+use mlbt_api::client::{ApiError, StatGroup};
+use mlbt_api::content::ContentResponse;
+use mlbt_api::draft::DraftResponse;
+use mlbt_api::leaders::LeagueLeadersResponse;
 use mlbt_api::live::LiveResponse;
 use mlbt_api::player::PeopleResponse;
-use mlbt_api::schedule::ScheduleResponse;
+use mlbt_api::schedule::{PostseasonSeriesResponse, ScheduleResponse};
 use mlbt_api::season::GameType;
 use mlbt_api::standings::StandingsResponse;
 use mlbt_api::stats::StatsResponse;
 use mlbt_api::team::{RosterResponse, RosterType, TransactionsResponse};
+use mlbt_api::teams::SportId;
+use mlbt_api::vs_player::VsPlayerResponse;
 use mlbt_api::win_probability::WinProbabilityResponse;
 use std::sync::Arc;
 
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Clone)]
 pub enum NetworkRequest {
     Initialize,
     Schedule {
         date: NaiveDate,
+        sport: SportId,
     },
     GameData {
         game_id: u64,
     },
+    /// Preview and recap articles for the Recap panel.
+    GameContent {
+        game_id: u64,
+    },
+    VsPlayer {
+        batter_id: u64,
+        pitcher_id: u64,
+        season: i32,
+    },
     Standings {
         date: NaiveDate,
+        sport: SportId,
+    },
+    PostseasonBracket {
+        season: i32,
     },
     Stats {
         date: NaiveDate,
         stat_type: StatType,
+        sport: SportId,
+    },
+    LeagueLeaders {
+        date: NaiveDate,
+        sport: SportId,
     },
     PlayerProfile {
         player_id: u64,
@@ -35,19 +60,38 @@ pub enum NetworkRequest {
         date: NaiveDate,
         game_type: GameType,
     },
+    /// Balls in play from a player's recent games, for the player profile spray chart.
+    SprayChart {
+        player_id: u64,
+        group: StatGroup,
+        game_ids: Vec<u64>,
+    },
     TeamPage {
         team_id: u16,
         date: NaiveDate,
     },
+    SearchPeople {
+        query: String,
+        active_only: bool,
+    },
     TeamRoster {
         team_id: u16,
         season: i32,
         roster_type: RosterType,
     },
+    Draft {
+        year: i32,
+    },
+    /// The 40 man roster and the season's transactions, to find when injured players were placed
+    /// on the IL.
+    InjuredList {
+        team_id: u16,
+        date: NaiveDate,
+    },
 }
 
 /// Wrapper that pairs a request with a force_refresh flag for the cache layer.
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Clone)]
 pub struct RefreshableRequest {
     pub request: NetworkRequest,
     pub force_refresh: bool,
@@ -83,6 +127,9 @@ pub enum NetworkResponse {
     LoadingStateChanged {
         loading_state: LoadingState,
     },
+    ConnectionChanged {
+        connection: Connection,
+    },
     ScheduleLoaded {
         schedule: Arc<ScheduleResponse>,
     },
@@ -90,16 +137,44 @@ pub enum NetworkResponse {
         game: Arc<LiveResponse>,
         win_probability: Arc<WinProbabilityResponse>,
     },
+    GameContentLoaded {
+        game_id: u64,
+        content: Arc<ContentResponse>,
+    },
+    VsPlayerLoaded {
+        batter_id: u64,
+        pitcher_id: u64,
+        season: i32,
+        stats: Arc<VsPlayerResponse>,
+    },
     StandingsLoaded {
         standings: Arc<StandingsResponse>,
     },
+    PostseasonBracketLoaded {
+        season: i32,
+        series: Arc<PostseasonSeriesResponse>,
+    },
     StatsLoaded {
         stats: Arc<StatsResponse>,
     },
+    LeagueLeadersLoaded {
+        season: i32,
+        sport: SportId,
+        leaders: Arc<LeagueLeadersResponse>,
+    },
     PlayerProfileLoaded {
         data: Arc<PeopleResponse>,
+        group: StatGroup,
         game_type: GameType,
     },
+    SprayChartLoaded {
+        player_id: u64,
+        balls: Arc<Vec<BattedBall>>,
+    },
+    PeopleSearchLoaded {
+        query: String,
+        people: Arc<PeopleResponse>,
+    },
     TeamPageLoaded {
         team_id: u16,
         date: NaiveDate,
@@ -112,14 +187,118 @@ pub enum NetworkResponse {
         roster: Arc<RosterResponse>,
         roster_type: RosterType,
     },
+    DraftLoaded {
+        year: i32,
+        draft: Arc<DraftResponse>,
+    },
+    InjuredListLoaded {
+        team_id: u16,
+        roster: Arc<RosterResponse>,
+        transactions: Arc<TransactionsResponse>,
+    },
     Initialized,
-    // TODO pass through errors from API
-    #[allow(dead_code)]
+    /// The request failed and there was no cached response to show instead.
     Error {
-        message: String,
+        request: NetworkRequest,
+        error: Arc<ApiError>,
     },
 }
 
+/// The kind of data a request loads, used to label a failed request and to clear the failure once
+/// the same kind of data loads.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum RequestKind {
+    Teams,
+    Schedule,
+    Game,
+    GameContent,
+    VsPlayer,
+    Standings,
+    PostseasonBracket,
+    Stats,
+    LeagueLeaders,
+    PlayerProfile,
+    SprayChart,
+    TeamPage,
+    SearchPeople,
+    TeamRoster,
+    Draft,
+    InjuredList,
+}
+
+impl RequestKind {
+    pub fn label(&self) -> &'static str {
+        match self {
+            RequestKind::Teams => "Teams",
+            RequestKind::Schedule => "Schedule",
+            RequestKind::Game => "Game",
+            RequestKind::GameContent => "Recap",
+            RequestKind::VsPlayer => "Head-to-head",
+            RequestKind::Standings => "Standings",
+            RequestKind::PostseasonBracket => "Bracket",
+            RequestKind::Stats => "Stats",
+            RequestKind::LeagueLeaders => "Leaders",
+            RequestKind::PlayerProfile => "Player profile",
+            RequestKind::SprayChart => "Spray chart",
+            RequestKind::TeamPage => "Team page",
+            RequestKind::SearchPeople => "Player search",
+            RequestKind::TeamRoster => "Roster",
+            RequestKind::Draft => "Draft",
+            RequestKind::InjuredList => "Injured list",
+        }
+    }
+}
+
+impl NetworkRequest {
+    pub fn kind(&self) -> RequestKind {
+        match self {
+            NetworkRequest::Initialize => RequestKind::Teams,
+            NetworkRequest::Schedule { .. } => RequestKind::Schedule,
+            NetworkRequest::GameData { .. } => RequestKind::Game,
+            NetworkRequest::GameContent { .. } => RequestKind::GameContent,
+            NetworkRequest::VsPlayer { .. } => RequestKind::VsPlayer,
+            NetworkRequest::Standings { .. } => RequestKind::Standings,
+            NetworkRequest::PostseasonBracket { .. } => RequestKind::PostseasonBracket,
+            NetworkRequest::Stats { .. } => RequestKind::Stats,
+            NetworkRequest::LeagueLeaders { .. } => RequestKind::LeagueLeaders,
+            NetworkRequest::PlayerProfile { .. } => RequestKind::PlayerProfile,
+            NetworkRequest::SprayChart { .. } => RequestKind::SprayChart,
+            NetworkRequest::TeamPage { .. } => RequestKind::TeamPage,
+            NetworkRequest::SearchPeople { .. } => RequestKind::SearchPeople,
+            NetworkRequest::TeamRoster { .. } => RequestKind::TeamRoster,
+            NetworkRequest::Draft { .. } => RequestKind::Draft,
+            NetworkRequest::InjuredList { .. } => RequestKind::InjuredList,
+        }
+    }
+}
+
+impl NetworkResponse {
+    /// The kind of data loaded, `None` for responses that don't carry data.
+    pub fn kind(&self) -> Option<RequestKind> {
+        Some(match self {
+            NetworkResponse::LoadingStateChanged { .. }
+            | NetworkResponse::ConnectionChanged { .. }
+            | NetworkResponse::Error { .. } => return None,
+            NetworkResponse::Initialized => RequestKind::Teams,
+            NetworkResponse::ScheduleLoaded { .. } => RequestKind::Schedule,
+            NetworkResponse::GameDataLoaded { .. } => RequestKind::Game,
+            NetworkResponse::GameContentLoaded { .. } => RequestKind::GameContent,
+            NetworkResponse::VsPlayerLoaded { .. } => RequestKind::VsPlayer,
+            NetworkResponse::StandingsLoaded { .. } => RequestKind::Standings,
+            NetworkResponse::PostseasonBracketLoaded { .. } => RequestKind::PostseasonBracket,
+            NetworkResponse::StatsLoaded { .. } => RequestKind::Stats,
+            NetworkResponse::LeagueLeadersLoaded { .. } => RequestKind::LeagueLeaders,
+            NetworkResponse::PlayerProfileLoaded { .. } => RequestKind::PlayerProfile,
+            NetworkResponse::SprayChartLoaded { .. } => RequestKind::SprayChart,
+            NetworkResponse::PeopleSearchLoaded { .. } => RequestKind::SearchPeople,
+            NetworkResponse::TeamPageLoaded { .. } => RequestKind::TeamPage,
+            NetworkResponse::TeamRosterLoaded { .. } => RequestKind::TeamRoster,
+            NetworkResponse::DraftLoaded { .. } => RequestKind::Draft,
+            NetworkResponse::InjuredListLoaded { .. } => RequestKind::InjuredList,
+        })
+    }
+}
+
 #[derive(Debug, Clone)]
 pub enum UiEvent {
     KeyPressed(KeyEvent),
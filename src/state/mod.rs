@@ -3,12 +3,16 @@ pub mod app_state;
 pub mod boxscore;
 pub mod cache;
 pub mod date_input;
+pub mod draft;
 pub mod gameday;
 pub mod help;
 pub mod messages;
 pub mod network;
 pub mod player_profile;
+pub mod player_search;
+pub mod recap;
 pub mod refresher;
+pub mod request_errors;
 pub mod settings_editor;
 pub mod stats;
 pub mod team_page;
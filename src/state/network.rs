@@ -1,20 +1,37 @@
 use crate::components::constants::register_teams;
+use crate::components::game::spray_chart::BattedBall;
+use crate::components::stats::leaders::LEADERS_LIMIT;
 use crate::components::stats::table::{StatType, TeamOrPlayer};
-use crate::state::cache::{NetworkCache, PRUNE_INTERVAL};
+use crate::state::cache::{CacheKey, NetworkCache, PRUNE_INTERVAL};
 use crate::state::messages::{NetworkRequest, NetworkResponse, RefreshableRequest};
-use chrono::{Datelike, NaiveDate};
+use chrono::{DateTime, Datelike, NaiveDate, Utc};
+use chrono_tz::Tz;
+use indexmap::IndexMap;
 use log::{debug, error, warn};
-use mlbt_api::client::{ApiResult, MLBApi, MLBApiBuilder, StatGroup};
-use mlbt_api::season::{SeasonInfo, game_type_for_date};
+use mlbt_api::client::{ApiError, ApiResult, MLBApi, MLBApiBuilder, StatGroup};
+use mlbt_api::disk_cache::{DiskCache, DiskCacheTransport};
+use mlbt_api::leaders::LeaderCategory;
+use mlbt_api::live::LiveFeed;
+use mlbt_api::retry::RetryPolicy;
+use mlbt_api::schedule::AbstractGameState;
+use mlbt_api::season::{GameType, SeasonInfo, game_type_for_date};
 use mlbt_api::team::RosterType;
 use mlbt_api::teams::SportId;
+use mlbt_api::transport::{OfflineTransport, Transport};
+use mlbt_api::win_probability::WinProbabilityResponse;
+use std::collections::{HashMap, VecDeque};
 use std::pin::Pin;
-use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard};
 use std::time::Duration;
 use tokio::sync::mpsc;
+use tokio::task::JoinSet;
 
 const DEBOUNCE_DELAY: Duration = Duration::from_millis(250);
+/// Number of live feeds kept around to request diffs against.
+const MAX_LIVE_FEEDS: usize = 4;
+/// Number of requests fetched at the same time, the rest wait in a queue.
+const MAX_CONCURRENT_REQUESTS: usize = 4;
 const SPINNER_CHARS: [char; 10] = ['⠋', '⠙', '⠹', '⠸', '⠼', '⠴', '⠦', '⠧', '⠇', '⠏'];
 pub const ERROR_CHAR: char = '!';
 
@@ -33,28 +50,195 @@ impl Default for LoadingState {
     }
 }
 
-pub struct NetworkWorker {
+/// Whether the API could be reached for the last request.
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+pub enum Connection {
+    #[default]
+    Online,
+    /// The API couldn't be reached. `data_from` is when the cached response that is shown instead
+    /// was fetched, or `None` if there was nothing cached.
+    Offline { data_from: Option<DateTime<Utc>> },
+    /// The API couldn't be reached and the response was read from the disk cache, e.g. one saved
+    /// by an earlier session.
+    OfflineArchived,
+}
+
+impl Connection {
+    /// Status line text, e.g. "offline – data from 14:02". Data from an earlier day includes the
+    /// date.
+    pub fn label(&self, tz: Tz) -> Option<String> {
+        let data_from = match self {
+            Connection::Online => return None,
+            Connection::OfflineArchived => return Some("offline – saved data".to_string()),
+            Connection::Offline { data_from } => data_from,
+        };
+        let Some(data_from) = data_from else {
+            return Some("unavailable offline".to_string());
+        };
+        let data_from = data_from.with_timezone(&tz);
+        let format = if data_from.date_naive() == Utc::now().with_timezone(&tz).date_naive() {
+            "%H:%M"
+        } else {
+            "%b %-d %H:%M"
+        };
+        Some(format!("offline – data from {}", data_from.format(format)))
+    }
+}
+
+/// What a response is shown as. A response is dropped if a newer request for the same selection
+/// was made while it was loading, e.g. the schedule of a date the user has already moved past.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+enum Selection {
+    Schedule,
+    Game,
+    GameContent,
+    Standings,
+    PostseasonBracket,
+    Stats,
+    LeagueLeaders,
+    PlayerProfile,
+    SprayChart,
+    TeamPage,
+    TeamRoster,
+    /// The depth chart is shown next to the roster, so it doesn't replace a roster request.
+    DepthChart,
+    SearchPeople,
+    Draft,
+    InjuredList,
+}
+
+impl Selection {
+    /// `None` for requests that are never superseded. Head-to-head totals are loaded for every
+    /// matchup the user moves through and kept per matchup, so all of them are needed.
+    fn for_request(request: &NetworkRequest) -> Option<Self> {
+        Some(match request {
+            NetworkRequest::Initialize | NetworkRequest::VsPlayer { .. } => return None,
+            NetworkRequest::Schedule { .. } => Selection::Schedule,
+            NetworkRequest::GameData { .. } => Selection::Game,
+            NetworkRequest::GameContent { .. } => Selection::GameContent,
+            NetworkRequest::Standings { .. } => Selection::Standings,
+            NetworkRequest::PostseasonBracket { .. } => Selection::PostseasonBracket,
+            NetworkRequest::Stats { .. } => Selection::Stats,
+            NetworkRequest::LeagueLeaders { .. } => Selection::LeagueLeaders,
+            NetworkRequest::PlayerProfile { .. } => Selection::PlayerProfile,
+            NetworkRequest::SprayChart { .. } => Selection::SprayChart,
+            NetworkRequest::TeamPage { .. } => Selection::TeamPage,
+            NetworkRequest::TeamRoster {
+                roster_type: RosterType::DepthChart,
+                ..
+            } => Selection::DepthChart,
+            NetworkRequest::TeamRoster { .. } => Selection::TeamRoster,
+            NetworkRequest::SearchPeople { .. } => Selection::SearchPeople,
+            NetworkRequest::Draft { .. } => Selection::Draft,
+            NetworkRequest::InjuredList { .. } => Selection::InjuredList,
+        })
+    }
+}
+
+/// A request waiting for a free slot.
+struct Queued {
+    request: NetworkRequest,
+    cache_key: Option<CacheKey>,
+    generation: u64,
+}
+
+/// The result of a request, sent back from its task.
+struct Fetched {
+    request: NetworkRequest,
+    cache_key: Option<CacheKey>,
+    selection: Option<Selection>,
+    generation: u64,
+    result: ApiResult<NetworkResponse>,
+    /// The response from the disk cache when the API couldn't be reached.
+    archived: Option<NetworkResponse>,
+    /// The feed of a game data request, kept to diff against on the next poll.
+    live_feed: Option<(u64, LiveFeed)>,
+}
+
+/// Makes the API calls for a request. Each request runs on its own clone, so it only holds what
+/// can be shared between concurrent requests.
+#[derive(Clone)]
+struct Fetcher {
     client: MLBApi,
+    /// Same as `client` but backed by the disk cache, used for responses that won't change.
+    /// `None` when the disk cache couldn't be opened or a recording is being made or replayed.
+    archive_client: Option<MLBApi>,
+    /// Only reads the disk cache, for any request, when the API can't be reached.
+    disk_client: Option<MLBApi>,
+    /// Set for requests for archivable data, see `api`.
+    use_archive: bool,
+    /// Cached season info, keyed by year.
+    season_info: Arc<Mutex<Option<(i32, SeasonInfo)>>>,
+}
+
+pub struct NetworkWorker {
+    fetcher: Fetcher,
+    connection: Connection,
     requests: mpsc::Receiver<RefreshableRequest>,
     responses: mpsc::Sender<NetworkResponse>,
     is_loading: Arc<AtomicBool>,
-    /// Cached season info, keyed by year.
-    season_info: Option<(i32, SeasonInfo)>,
     cache: NetworkCache,
+    /// The last live feed of recently viewed games that aren't over, oldest first. Polls for these
+    /// only download the changes since the feed's timestamp.
+    live_feeds: IndexMap<u64, LiveFeed>,
+    /// Requests being fetched, at most `MAX_CONCURRENT_REQUESTS`.
+    tasks: JoinSet<Fetched>,
+    /// Requests waiting for one of `tasks` to finish.
+    queued: VecDeque<Queued>,
+    /// Cache keys of the requests in `tasks` and `queued`, with the generation of the latest
+    /// request for the key. An identical request shares the fetch instead of starting another.
+    in_flight: HashMap<CacheKey, u64>,
+    /// The generation of the latest request for each selection.
+    selections: HashMap<Selection, u64>,
+    generation: u64,
+    /// Whether the last finished request succeeded, shown once everything has loaded.
+    last_ok: bool,
 }
 
 impl NetworkWorker {
     pub fn new(
         requests: mpsc::Receiver<RefreshableRequest>,
         responses: mpsc::Sender<NetworkResponse>,
+        transport: Arc<dyn Transport>,
+        disk_cache: Option<Arc<DiskCache>>,
     ) -> Self {
+        let client_for = |transport| {
+            MLBApiBuilder::default()
+                .transport(transport)
+                .retry(RetryPolicy::default())
+                .build()
+                .unwrap()
+        };
+        let archive_client = disk_cache.as_ref().map(|cache| {
+            let transport: Arc<dyn Transport> =
+                Arc::new(DiskCacheTransport::new(transport.clone(), cache.clone()));
+            client_for(transport)
+        });
+        let disk_client = disk_cache.map(|cache| {
+            let transport: Arc<dyn Transport> =
+                Arc::new(DiskCacheTransport::new(Arc::new(OfflineTransport), cache));
+            client_for(transport)
+        });
         Self {
-            client: MLBApiBuilder::default().build().unwrap(),
+            fetcher: Fetcher {
+                client: client_for(transport),
+                archive_client,
+                disk_client,
+                use_archive: false,
+                season_info: Arc::new(Mutex::new(None)),
+            },
+            connection: Connection::Online,
             requests,
             responses,
             is_loading: Arc::new(AtomicBool::new(false)),
-            season_info: None,
             cache: NetworkCache::new(),
+            live_feeds: IndexMap::new(),
+            tasks: JoinSet::new(),
+            queued: VecDeque::new(),
+            in_flight: HashMap::new(),
+            selections: HashMap::new(),
+            generation: 0,
+            last_ok: true,
         }
     }
 
@@ -100,12 +284,20 @@ impl NetworkWorker {
                 () = &mut debounce_sleep, if debounce_game_id.is_some() => {
                     // Drain any last-moment queued GameData requests
                     let game_id = self.drain_game_data(debounce_game_id.take().unwrap(), &mut overflow);
-                    self.fetch_and_send(NetworkRequest::GameData { game_id }).await;
+                    let request = NetworkRequest::GameData { game_id };
+                    let generation = self.select(&request);
+                    self.start(request, generation).await;
 
                     for req in overflow.drain(..) {
                         self.handle_request(req, &mut debounce_game_id, debounce_sleep.as_mut()).await;
                     }
                 }
+                Some(joined) = self.tasks.join_next(), if !self.tasks.is_empty() => {
+                    match joined {
+                        Ok(fetched) => self.finish(fetched).await,
+                        Err(e) => error!("request task failed: {e}"),
+                    }
+                }
                 _ = prune_interval.tick() => {
                     self.cache.prune();
                 }
@@ -138,12 +330,19 @@ impl NetworkWorker {
     ) {
         let request = refreshable.request;
         let cache_key = NetworkCache::key_for(&request);
+        let generation = self.select(&request);
 
-        // Force refresh invalidates so the cache check below misses
+        // Force refresh expires the entry so the cache check below misses
         if refreshable.force_refresh
             && let Some(key) = cache_key.as_ref()
         {
-            self.cache.invalidate(key);
+            self.cache.expire(key);
+        }
+        // and a forced game refresh downloads the full feed instead of a diff
+        if refreshable.force_refresh
+            && let NetworkRequest::GameData { game_id } = request
+        {
+            self.live_feeds.shift_remove(&game_id);
         }
 
         // Check cache before making API calls
@@ -166,20 +365,256 @@ impl NetworkWorker {
             return;
         }
 
-        self.fetch_and_send(request).await;
+        self.start(request, generation).await;
+    }
+
+    /// Record the request as the latest for its selection, so responses to earlier requests for
+    /// it are dropped. Returns the request's generation.
+    fn select(&mut self, request: &NetworkRequest) -> u64 {
+        self.generation += 1;
+        if let Some(selection) = Selection::for_request(request) {
+            self.selections.insert(selection, self.generation);
+        }
+        self.generation
+    }
+
+    fn is_current(&self, selection: Option<Selection>, generation: u64) -> bool {
+        selection.is_none_or(|s| self.selections.get(&s) == Some(&generation))
     }
 
-    async fn fetch_and_send(&mut self, request: NetworkRequest) {
+    /// Fetch the request in the background, sharing the fetch of an identical request that is
+    /// already in flight. Requests past `MAX_CONCURRENT_REQUESTS` wait in a queue.
+    async fn start(&mut self, request: NetworkRequest, generation: u64) {
         let cache_key = NetworkCache::key_for(&request);
+        if let Some(key) = cache_key.as_ref() {
+            if let Some(latest) = self.in_flight.get_mut(key) {
+                debug!("sharing the in-flight request for {key:?}");
+                *latest = generation;
+                return;
+            }
+            self.in_flight.insert(key.clone(), generation);
+        }
+
+        if self.tasks.is_empty() && self.queued.is_empty() {
+            self.start_loading_animation().await;
+        }
+        let queued = Queued {
+            request,
+            cache_key,
+            generation,
+        };
+        if self.tasks.len() < MAX_CONCURRENT_REQUESTS {
+            self.spawn(queued);
+        } else {
+            self.queued.push_back(queued);
+        }
+    }
+
+    /// Start fetching a request. A queued request that was superseded while it waited is skipped.
+    fn spawn(&mut self, queued: Queued) {
+        let Queued {
+            request,
+            cache_key,
+            generation,
+        } = queued;
+        let selection = Selection::for_request(&request);
+        // an identical request made while this one waited is the latest for the key
+        let latest = cache_key
+            .as_ref()
+            .and_then(|key| self.in_flight.get(key))
+            .copied()
+            .unwrap_or(generation);
+        if !self.is_current(selection, latest) {
+            debug!("skipping a superseded {selection:?} request");
+            if let Some(key) = cache_key.as_ref() {
+                self.in_flight.remove(key);
+            }
+            return;
+        }
+        let today = Utc::now().date_naive();
+        let game_type = match &cache_key {
+            Some(CacheKey::Standings { date, .. } | CacheKey::Stats { date, .. }) => {
+                self.fetcher.known_game_type(*date)
+            }
+            _ => None,
+        };
+        let mut fetcher = self.fetcher.clone();
+        fetcher.use_archive = fetcher.archive_client.is_some()
+            && cache_key
+                .as_ref()
+                .is_some_and(|key| self.cache.is_archivable(key, today, game_type));
+        // the request owns the feed to diff against until it finishes, and a final game's full
+        // feed is archived since a diff against an earlier poll wouldn't be reused
+        let live_feed = match request {
+            NetworkRequest::GameData { game_id } => self
+                .live_feeds
+                .shift_remove(&game_id)
+                .filter(|_| !fetcher.use_archive),
+            _ => None,
+        };
+        self.tasks.spawn(async move {
+            let (result, live_feed) = fetcher.fetch(request.clone(), live_feed).await;
+            let archived = match &result {
+                Err(err) if err.is_connection_failure() => fetcher.fetch_archived(&request).await,
+                _ => None,
+            };
+            Fetched {
+                request,
+                cache_key,
+                selection,
+                generation,
+                result,
+                archived,
+                live_feed,
+            }
+        });
+    }
 
-        self.start_loading_animation().await;
+    /// Cache and send a finished request, then start the next queued one. The response is dropped
+    /// if a newer request for the same selection was made in the meantime.
+    async fn finish(&mut self, fetched: Fetched) {
+        let Fetched {
+            request,
+            cache_key,
+            selection,
+            mut generation,
+            result,
+            archived,
+            live_feed,
+        } = fetched;
+        debug!("request complete");
+        // an identical request may have been made while this one was loading
+        if let Some(latest) = cache_key
+            .as_ref()
+            .and_then(|key| self.in_flight.remove(key))
+        {
+            generation = latest;
+        }
+        if let Some((game_id, feed)) = live_feed {
+            self.store_live_feed(game_id, feed);
+        }
+
+        let (result, connection) = match result {
+            Ok(response) => {
+                if let Some(key) = cache_key {
+                    self.cache.insert(key, response.clone());
+                }
+                (Ok(response), Connection::Online)
+            }
+            // show the last response we have, even if it's stale, when the API can't be reached.
+            // the disk cache is only used when nothing is in memory since it has no fetch time
+            Err(err) if err.is_connection_failure() => {
+                warn!("serving cached data: {}", err.log());
+                let stale = cache_key.as_ref().and_then(|key| self.cache.get_stale(key));
+                match (stale, archived) {
+                    (Some((response, fetched_at)), _) => (
+                        Ok(response),
+                        Connection::Offline {
+                            data_from: Some(fetched_at),
+                        },
+                    ),
+                    (None, Some(response)) => (Ok(response), Connection::OfflineArchived),
+                    (None, None) => (Err(err), Connection::Offline { data_from: None }),
+                }
+            }
+            // the API answered, so the connection is fine
+            Err(err) => (Err(err), Connection::Online),
+        };
+
+        while self.tasks.len() < MAX_CONCURRENT_REQUESTS
+            && let Some(queued) = self.queued.pop_front()
+        {
+            self.spawn(queued);
+        }
+        self.last_ok = result.is_ok();
+        if self.tasks.is_empty() {
+            self.stop_loading_animation(self.last_ok).await;
+        }
+        self.set_connection(connection).await;
+
+        if !self.is_current(selection, generation) {
+            debug!("dropping the response to a superseded {selection:?} request");
+            return;
+        }
+        let response = result.unwrap_or_else(|err| NetworkResponse::Error {
+            request,
+            error: Arc::new(err),
+        });
+        if let Err(e) = self.responses.send(response).await {
+            error!("Failed to send network response: {e}");
+        }
+    }
+
+    async fn set_connection(&mut self, connection: Connection) {
+        if self.connection == connection {
+            return;
+        }
+        self.connection = connection;
+        let _ = self
+            .responses
+            .send(NetworkResponse::ConnectionChanged { connection })
+            .await;
+    }
+
+    /// Keep the feed to diff against on the next poll. Final games don't change anymore so they
+    /// are dropped, and only the most recent `MAX_LIVE_FEEDS` games are kept.
+    fn store_live_feed(&mut self, game_id: u64, feed: LiveFeed) {
+        self.live_feeds.shift_remove(&game_id);
+        let is_final = matches!(
+            feed.response.game_data.status.abstract_game_state,
+            Some(AbstractGameState::Final)
+        );
+        if is_final || feed.time_stamp().is_empty() {
+            return;
+        }
+        self.live_feeds.insert(game_id, feed);
+        if self.live_feeds.len() > MAX_LIVE_FEEDS {
+            self.live_feeds.shift_remove_index(0);
+        }
+    }
+}
+
+impl Fetcher {
+    /// Make the API calls for a request. A game data request returns its live feed along with the
+    /// response, `live_feed` is the previous one to diff against.
+    async fn fetch(
+        &self,
+        request: NetworkRequest,
+        live_feed: Option<LiveFeed>,
+    ) -> (ApiResult<NetworkResponse>, Option<(u64, LiveFeed)>) {
         let result = match request {
             NetworkRequest::Initialize => self.handle_initialize().await,
-            NetworkRequest::Schedule { date } => self.handle_load_schedule(date).await,
-            NetworkRequest::GameData { game_id } => self.handle_load_game_data(game_id).await,
-            NetworkRequest::Standings { date } => self.handle_load_standings(date).await,
-            NetworkRequest::Stats { date, stat_type } => {
-                self.handle_load_stats(date, stat_type).await
+            NetworkRequest::Schedule { date, sport } => {
+                self.handle_load_schedule(date, sport).await
+            }
+            NetworkRequest::GameData { game_id } => {
+                return match self.handle_load_game_data(game_id, live_feed).await {
+                    Ok((response, feed)) => (Ok(response), Some((game_id, feed))),
+                    Err(err) => (Err(err), None),
+                };
+            }
+            NetworkRequest::GameContent { game_id } => self.handle_load_game_content(game_id).await,
+            NetworkRequest::VsPlayer {
+                batter_id,
+                pitcher_id,
+                season,
+            } => {
+                self.handle_load_vs_player(batter_id, pitcher_id, season)
+                    .await
+            }
+            NetworkRequest::Standings { date, sport } => {
+                self.handle_load_standings(date, sport).await
+            }
+            NetworkRequest::PostseasonBracket { season } => {
+                self.handle_load_postseason_bracket(season).await
+            }
+            NetworkRequest::Stats {
+                date,
+                stat_type,
+                sport,
+            } => self.handle_load_stats(date, stat_type, sport).await,
+            NetworkRequest::LeagueLeaders { date, sport } => {
+                self.handle_load_league_leaders(date, sport).await
             }
             NetworkRequest::PlayerProfile {
                 player_id,
@@ -190,9 +625,20 @@ impl NetworkWorker {
                 self.handle_load_player_profile(player_id, group, date, game_type)
                     .await
             }
+            NetworkRequest::SprayChart {
+                player_id,
+                group,
+                game_ids,
+            } => {
+                self.handle_load_spray_chart(player_id, group, game_ids)
+                    .await
+            }
             NetworkRequest::TeamPage { team_id, date } => {
                 self.handle_load_team_page(team_id, date).await
             }
+            NetworkRequest::SearchPeople { query, active_only } => {
+                self.handle_search_people(query, active_only).await
+            }
             NetworkRequest::TeamRoster {
                 team_id,
                 season,
@@ -201,61 +647,131 @@ impl NetworkWorker {
                 self.handle_load_team_roster(team_id, season, roster_type)
                     .await
             }
+            NetworkRequest::Draft { year } => self.handle_load_draft(year).await,
+            NetworkRequest::InjuredList { team_id, date } => {
+                self.handle_load_injured_list(team_id, date).await
+            }
         };
-        debug!("request complete");
-        self.stop_loading_animation(result.is_ok()).await;
+        (result, None)
+    }
 
-        // Cache successful responses
-        if let Ok(ref response) = result
-            && let Some(key) = cache_key
-        {
-            self.cache.insert(key, response.clone());
-        }
+    /// The response to the request from the disk cache alone, whether or not the request is
+    /// archivable, e.g. a game that was saved before the last restart. `None` if anything it
+    /// needs isn't on disk.
+    async fn fetch_archived(&self, request: &NetworkRequest) -> Option<NetworkResponse> {
+        let disk_client = self.disk_client.clone()?;
+        let fetcher = Fetcher {
+            archive_client: Some(disk_client),
+            use_archive: true,
+            ..self.clone()
+        };
+        fetcher.fetch(request.clone(), None).await.0.ok()
+    }
 
-        let response = result.unwrap_or_else(|err| NetworkResponse::Error { message: err.log() });
-        if let Err(e) = self.responses.send(response).await {
-            error!("Failed to send network response: {e}");
+    /// The client for the request's own endpoints. Archivable requests go through the disk cache so
+    /// they survive restarts, everything else only uses the in memory `NetworkCache`. Lookups a
+    /// request makes along the way, like the season info, always use `client`.
+    fn api(&self) -> &MLBApi {
+        match &self.archive_client {
+            Some(archive) if self.use_archive => archive,
+            _ => &self.client,
         }
     }
 
-    async fn handle_load_schedule(&self, date: NaiveDate) -> ApiResult<NetworkResponse> {
-        debug!("loading schedule for {date}");
-        let schedule = self.client.get_schedule_date(date).await?;
+    async fn handle_load_schedule(
+        &self,
+        date: NaiveDate,
+        sport: SportId,
+    ) -> ApiResult<NetworkResponse> {
+        debug!("loading {} schedule for {date}", sport.abbreviation());
+        let schedule = self.api().get_schedule_date(date, sport).await?;
         Ok(NetworkResponse::ScheduleLoaded {
             schedule: Arc::new(schedule),
         })
     }
 
-    async fn handle_load_game_data(&self, game_id: u64) -> ApiResult<NetworkResponse> {
+    async fn handle_load_game_data(
+        &self,
+        game_id: u64,
+        live_feed: Option<LiveFeed>,
+    ) -> ApiResult<(NetworkResponse, LiveFeed)> {
         debug!("loading game data for {game_id}");
-        let (game, wp) = tokio::join!(
-            self.client.get_live_data(game_id),
-            self.client.get_win_probability(game_id),
+        let (feed, wp) = tokio::join!(
+            self.load_live_feed(game_id, live_feed),
+            self.api().get_win_probability(game_id),
         );
-        Ok(NetworkResponse::GameDataLoaded {
-            game: Arc::new(game?),
+        // win probability isn't available for every game, so a 404 shouldn't hide the game itself
+        let wp = match wp {
+            Err(ApiError::NotFound { .. }) => Ok(WinProbabilityResponse::default()),
+            wp => wp,
+        };
+        let feed = feed?;
+        let response = NetworkResponse::GameDataLoaded {
+            game: feed.response.clone(),
             win_probability: Arc::new(wp?),
-        })
+        };
+        Ok((response, feed))
     }
 
-    async fn handle_load_standings(&mut self, date: NaiveDate) -> ApiResult<NetworkResponse> {
-        debug!("loading standings for {date}");
-        self.ensure_season_info(date).await;
-        let game_type = game_type_for_date(date, self.cached_season_info());
-        let standings = self.client.get_standings(date, game_type).await?;
+    /// Apply the changes since the last poll if there is a prior feed for the game, otherwise (or
+    /// if the patch doesn't apply) download the full feed.
+    async fn load_live_feed(&self, game_id: u64, prior: Option<LiveFeed>) -> ApiResult<LiveFeed> {
+        if let Some(prior) = prior {
+            match self
+                .api()
+                .get_live_diff(game_id, prior.time_stamp(), &prior)
+                .await
+            {
+                Ok(feed) => return Ok(feed),
+                // a replayed session won't have the diff recorded
+                Err(err @ (ApiError::Decode { .. } | ApiError::NotFound { .. })) => {
+                    warn!("falling back to the full live feed: {}", err.log());
+                }
+                Err(err) => return Err(err),
+            }
+        }
+        self.api().get_live_feed(game_id).await
+    }
+
+    async fn handle_load_standings(
+        &self,
+        date: NaiveDate,
+        sport: SportId,
+    ) -> ApiResult<NetworkResponse> {
+        debug!("loading {} standings for {date}", sport.abbreviation());
+        let game_type = self.game_type_for_date(date).await;
+        let standings = self.api().get_standings(date, game_type, sport).await?;
         Ok(NetworkResponse::StandingsLoaded {
             standings: Arc::new(standings),
         })
     }
 
+    async fn handle_load_postseason_bracket(&self, season: i32) -> ApiResult<NetworkResponse> {
+        debug!("loading {season} postseason bracket");
+        let series = self.api().get_postseason_series(season).await?;
+        // the round start dates let stats and standings request the right game type
+        if let Some((year, info)) = &mut *self.season_info()
+            && *year == season
+        {
+            info.set_postseason_rounds(&series);
+        }
+        Ok(NetworkResponse::PostseasonBracketLoaded {
+            season,
+            series: Arc::new(series),
+        })
+    }
+
     async fn handle_load_stats(
-        &mut self,
+        &self,
         date: NaiveDate,
         stat_type: StatType,
+        sport: SportId,
     ) -> ApiResult<NetworkResponse> {
-        debug!("loading {stat_type:?} stats for {date}");
-        self.ensure_season_info(date).await;
-        let game_type = game_type_for_date(date, self.cached_season_info());
+        debug!(
+            "loading {} {stat_type:?} stats for {date}",
+            sport.abbreviation()
+        );
+        let game_type = self.game_type_for_date(date).await;
         let StatType {
             team_player,
             group,
@@ -263,13 +779,13 @@ impl NetworkWorker {
         } = stat_type;
         let stats = match team_player {
             TeamOrPlayer::Team => {
-                self.client
-                    .get_team_stats_on_date(group, date, game_type)
+                self.api()
+                    .get_team_stats_on_date(group, date, game_type, sport)
                     .await
             }
             TeamOrPlayer::Player => {
-                self.client
-                    .get_player_stats_on_date(group, qualification, date, game_type)
+                self.api()
+                    .get_player_stats_on_date(group, qualification, date, game_type, sport)
                     .await
             }
         }?;
@@ -278,6 +794,60 @@ impl NetworkWorker {
         })
     }
 
+    async fn handle_load_game_content(&self, game_id: u64) -> ApiResult<NetworkResponse> {
+        debug!("loading content for game {game_id}");
+        let content = self.api().get_game_content(game_id).await?;
+        Ok(NetworkResponse::GameContentLoaded {
+            game_id,
+            content: Arc::new(content),
+        })
+    }
+
+    async fn handle_load_vs_player(
+        &self,
+        batter_id: u64,
+        pitcher_id: u64,
+        season: i32,
+    ) -> ApiResult<NetworkResponse> {
+        debug!("loading {season} head-to-head for batter {batter_id} vs pitcher {pitcher_id}");
+        let stats = self
+            .api()
+            .get_vs_player(batter_id, pitcher_id, season)
+            .await?;
+        Ok(NetworkResponse::VsPlayerLoaded {
+            batter_id,
+            pitcher_id,
+            season,
+            stats: Arc::new(stats),
+        })
+    }
+
+    async fn handle_load_league_leaders(
+        &self,
+        date: NaiveDate,
+        sport: SportId,
+    ) -> ApiResult<NetworkResponse> {
+        let season = date.year();
+        debug!("loading {season} {} league leaders", sport.abbreviation());
+        let game_type = self.game_type_for_date(date).await;
+        let leaders = self
+            .api()
+            .get_league_leaders(
+                &LeaderCategory::ALL,
+                season,
+                game_type,
+                sport,
+                None,
+                LEADERS_LIMIT,
+            )
+            .await?;
+        Ok(NetworkResponse::LeagueLeadersLoaded {
+            season,
+            sport,
+            leaders: Arc::new(leaders),
+        })
+    }
+
     async fn handle_load_player_profile(
         &self,
         player_id: u64,
@@ -287,15 +857,86 @@ impl NetworkWorker {
     ) -> ApiResult<NetworkResponse> {
         debug!("loading player profile for {player_id} ({group}) on {date}");
         let data = self
-            .client
+            .api()
             .get_player_profile(player_id, group, date.year(), game_type)
             .await?;
         Ok(NetworkResponse::PlayerProfileLoaded {
             data: Arc::new(data),
+            group,
             game_type,
         })
     }
 
+    async fn handle_search_people(
+        &self,
+        query: String,
+        active_only: bool,
+    ) -> ApiResult<NetworkResponse> {
+        debug!("searching people for {query:?} (active only: {active_only})");
+        let people = self.api().search_people(&query, active_only).await?;
+        Ok(NetworkResponse::PeopleSearchLoaded {
+            query,
+            people: Arc::new(people),
+        })
+    }
+
+    /// Fetch the plays of each game and keep the player's balls in play, the ones they hit or, for
+    /// pitchers, the ones they allowed.
+    async fn handle_load_spray_chart(
+        &self,
+        player_id: u64,
+        group: StatGroup,
+        game_ids: Vec<u64>,
+    ) -> ApiResult<NetworkResponse> {
+        debug!(
+            "loading spray chart for {player_id} from {} games",
+            game_ids.len()
+        );
+        // the games are fetched at once, and a game that fails to load is left out of the chart.
+        // it only fails when none of them load
+        let mut games = JoinSet::new();
+        for game_id in game_ids {
+            let api = self.api().clone();
+            games.spawn(async move { api.get_play_by_play(game_id).await });
+        }
+        let mut balls = Vec::new();
+        let mut loaded_any = false;
+        let mut last_error = None;
+        while let Some(joined) = games.join_next().await {
+            let plays = match joined {
+                Ok(Ok(plays)) => plays,
+                Ok(Err(err)) => {
+                    warn!("skipping a spray chart game: {}", err.log());
+                    last_error = Some(err);
+                    continue;
+                }
+                Err(e) => {
+                    error!("spray chart task failed: {e}");
+                    continue;
+                }
+            };
+            loaded_any = true;
+            balls.extend(
+                plays
+                    .all_plays
+                    .iter()
+                    .flatten()
+                    .filter_map(BattedBall::from_play)
+                    .filter(|ball| match group {
+                        StatGroup::Pitching => ball.pitcher_id == player_id,
+                        _ => ball.batter_id == player_id,
+                    }),
+            );
+        }
+        if !loaded_any && let Some(err) = last_error {
+            return Err(err);
+        }
+        Ok(NetworkResponse::SprayChartLoaded {
+            player_id,
+            balls: Arc::new(balls),
+        })
+    }
+
     async fn handle_load_team_page(
         &self,
         team_id: u16,
@@ -303,10 +944,10 @@ impl NetworkWorker {
     ) -> ApiResult<NetworkResponse> {
         debug!("loading team page for team {team_id} on {date}");
         let (schedule, roster, transactions) = tokio::try_join!(
-            self.client.get_team_schedule(team_id, date.year()),
-            self.client
+            self.api().get_team_schedule(team_id, date.year()),
+            self.api()
                 .get_team_roster(team_id, date.year(), RosterType::Active),
-            self.client
+            self.api()
                 .get_team_transactions(team_id, date - chrono::Duration::days(30), date),
         )?;
         Ok(NetworkResponse::TeamPageLoaded {
@@ -326,7 +967,7 @@ impl NetworkWorker {
     ) -> ApiResult<NetworkResponse> {
         debug!("loading {roster_type} roster for team {team_id} in {season} season");
         let roster = self
-            .client
+            .api()
             .get_team_roster(team_id, season, roster_type)
             .await?;
         Ok(NetworkResponse::TeamRosterLoaded {
@@ -336,13 +977,50 @@ impl NetworkWorker {
         })
     }
 
+    async fn handle_load_draft(&self, year: i32) -> ApiResult<NetworkResponse> {
+        debug!("loading {year} draft");
+        let draft = self.api().get_draft(year).await?;
+        Ok(NetworkResponse::DraftLoaded {
+            year,
+            draft: Arc::new(draft),
+        })
+    }
+
+    async fn handle_load_injured_list(
+        &self,
+        team_id: u16,
+        date: NaiveDate,
+    ) -> ApiResult<NetworkResponse> {
+        debug!("loading injured list for team {team_id} on {date}");
+        let season_start = NaiveDate::from_ymd_opt(date.year(), 1, 1).unwrap_or(date);
+        let (roster, transactions) = tokio::try_join!(
+            self.api()
+                .get_team_roster(team_id, date.year(), RosterType::FortyMan),
+            self.api()
+                .get_team_transactions(team_id, season_start, date),
+        )?;
+        Ok(NetworkResponse::InjuredListLoaded {
+            team_id,
+            roster: Arc::new(roster),
+            transactions: Arc::new(transactions),
+        })
+    }
+
     /// Best-effort initialization that always returns Ok so the app can proceed even if the API
     /// calls fails.
     /// - Fetch teams from the API to populate the dynamic team cache.
     async fn handle_initialize(&self) -> ApiResult<NetworkResponse> {
         match self
-            .client
-            .get_teams(&[SportId::Mlb, SportId::International])
+            .api()
+            .get_teams(&[
+                SportId::Mlb,
+                SportId::International,
+                SportId::TripleA,
+                SportId::DoubleA,
+                SportId::HighA,
+                SportId::SingleA,
+                SportId::Rookie,
+            ])
             .await
         {
             Ok(response) => {
@@ -354,28 +1032,57 @@ impl NetworkWorker {
         Ok(NetworkResponse::Initialized)
     }
 
-    fn cached_season_info(&self) -> Option<&SeasonInfo> {
-        self.season_info.as_ref().map(|(_, info)| info)
+    fn season_info(&self) -> MutexGuard<'_, Option<(i32, SeasonInfo)>> {
+        self.season_info.lock().unwrap_or_else(|e| e.into_inner())
     }
 
-    /// Lazily fetch and cache season info, re-fetching if the year changes.
-    async fn ensure_season_info(&mut self, date: NaiveDate) {
+    /// The game type on a date if that season's info is already loaded.
+    fn known_game_type(&self, date: NaiveDate) -> Option<GameType> {
+        match &*self.season_info() {
+            Some((year, info)) if *year == date.year() => {
+                Some(game_type_for_date(date, Some(info)))
+            }
+            _ => None,
+        }
+    }
+
+    /// The game type on a date, fetching the season info if the year changed.
+    async fn game_type_for_date(&self, date: NaiveDate) -> GameType {
+        self.ensure_season_info(date).await;
+        let season_info = self.season_info();
+        game_type_for_date(date, season_info.as_ref().map(|(_, info)| info))
+    }
+
+    /// Lazily fetch and cache season info, re-fetching if the year changes. Never archived, since
+    /// a settled date doesn't mean its season is over. Once the postseason has started, the round
+    /// start dates are fetched along with it.
+    async fn ensure_season_info(&self, date: NaiveDate) {
         let year = date.year();
-        if let Some((cached_year, _)) = &self.season_info
-            && *cached_year == year
-        {
+        if matches!(*self.season_info(), Some((cached_year, _)) if cached_year == year) {
             return;
         }
-        match self.client.get_season_info(year).await {
-            Ok(Some(info)) => self.season_info = Some((year, info)),
-            Ok(None) => self.season_info = None,
+        let mut season_info = match self.client.get_season_info(year).await {
+            Ok(info) => info,
             Err(e) => {
                 warn!("Failed to fetch season info, using fallback: {}", e.log());
-                self.season_info = None;
+                None
+            }
+        };
+        if let Some(info) = &mut season_info
+            && info
+                .post_season_start_date
+                .is_some_and(|start| start <= Utc::now().date_naive())
+        {
+            match self.client.get_postseason_series(year).await {
+                Ok(series) => info.set_postseason_rounds(&series),
+                Err(e) => warn!("Failed to fetch postseason rounds: {}", e.log()),
             }
         }
+        *self.season_info() = season_info.map(|info| (year, info));
     }
+}
 
+impl NetworkWorker {
     async fn start_loading_animation(&self) {
         self.is_loading.store(true, Ordering::Relaxed);
 
@@ -446,12 +1153,266 @@ impl NetworkWorker {
 mod tests {
     use super::*;
     use chrono::NaiveDate;
+    use mlbt_api::live::LiveResponse;
+    use mlbt_api::transport::{ReplayTransport, ReqwestTransport};
     use std::assert_matches;
+    use std::path::{Path, PathBuf};
 
     fn worker() -> NetworkWorker {
+        test_worker(Arc::new(ReqwestTransport::default()), None).0
+    }
+
+    /// A worker for the transport, along with its responses.
+    fn test_worker(
+        transport: Arc<dyn Transport>,
+        disk_cache: Option<Arc<DiskCache>>,
+    ) -> (NetworkWorker, mpsc::Receiver<NetworkResponse>) {
         let (_request_tx, request_rx) = mpsc::channel(32);
-        let (response_tx, _response_rx) = mpsc::channel(32);
-        NetworkWorker::new(request_rx, response_tx)
+        let (response_tx, response_rx) = mpsc::channel(1024);
+        let worker = NetworkWorker::new(request_rx, response_tx, transport, disk_cache);
+        (worker, response_rx)
+    }
+
+    /// A worker whose requests fail right away, along with its responses.
+    fn offline_worker() -> (NetworkWorker, mpsc::Receiver<NetworkResponse>) {
+        test_worker(Arc::new(OfflineTransport), None)
+    }
+
+    /// An empty temp dir for a test, removed again with `remove_dir_all` at the end.
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("mlbt-{name}-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    /// A worker that replays the given bodies for their urls, e.g. `/api/v1/draft/2025`, from a
+    /// recording written to `dir`.
+    fn replay_worker(
+        dir: &Path,
+        responses: &[(&str, &str)],
+        disk_cache: Option<Arc<DiskCache>>,
+    ) -> (NetworkWorker, mpsc::Receiver<NetworkResponse>) {
+        let recording = dir.join("recording");
+        std::fs::create_dir_all(&recording).unwrap();
+        let mut index = Vec::new();
+        for (i, (url, body)) in responses.iter().enumerate() {
+            std::fs::write(recording.join(format!("{i}.json")), body).unwrap();
+            index.push(format!(r#""{url}":{{"file":"{i}.json"}}"#));
+        }
+        std::fs::write(
+            recording.join("index.json"),
+            format!("{{{}}}", index.join(",")),
+        )
+        .unwrap();
+        let transport = ReplayTransport::new(&recording).unwrap();
+        test_worker(Arc::new(transport), disk_cache)
+    }
+
+    /// Start a request the way `handle_request` does on a cache miss.
+    async fn start(worker: &mut NetworkWorker, request: NetworkRequest) {
+        let generation = worker.select(&request);
+        worker.start(request, generation).await;
+    }
+
+    async fn finish_all(worker: &mut NetworkWorker) {
+        while let Some(fetched) = worker.tasks.join_next().await {
+            worker.finish(fetched.unwrap()).await;
+        }
+    }
+
+    /// The responses that would update the ui, skipping loading and connection changes.
+    fn sent(responses: &mut mpsc::Receiver<NetworkResponse>) -> Vec<NetworkResponse> {
+        std::iter::from_fn(|| responses.try_recv().ok())
+            .filter(|r| {
+                !matches!(
+                    r,
+                    NetworkResponse::LoadingStateChanged { .. }
+                        | NetworkResponse::ConnectionChanged { .. }
+                )
+            })
+            .collect()
+    }
+
+    fn standings(date: NaiveDate) -> NetworkRequest {
+        NetworkRequest::Standings {
+            date,
+            sport: SportId::Mlb,
+        }
+    }
+
+    #[tokio::test]
+    async fn identical_requests_share_one_fetch() {
+        let (mut worker, mut responses) = offline_worker();
+        let date = NaiveDate::from_ymd_opt(2025, 4, 13).unwrap();
+        start(&mut worker, standings(date)).await;
+        start(&mut worker, standings(date)).await;
+        assert_eq!(worker.tasks.len(), 1);
+
+        finish_all(&mut worker).await;
+        assert_eq!(sent(&mut responses).len(), 1);
+        assert!(worker.in_flight.is_empty());
+    }
+
+    #[tokio::test]
+    async fn responses_for_a_superseded_selection_are_dropped() {
+        let (mut worker, mut responses) = offline_worker();
+        let date = NaiveDate::from_ymd_opt(2025, 4, 13).unwrap();
+        start(&mut worker, standings(date)).await;
+        start(&mut worker, standings(date.succ_opt().unwrap())).await;
+        let leaders = NetworkRequest::LeagueLeaders {
+            date,
+            sport: SportId::Mlb,
+        };
+        start(&mut worker, leaders).await;
+        assert_eq!(worker.tasks.len(), 3);
+
+        finish_all(&mut worker).await;
+        // the second standings date and the leaders
+        assert_eq!(sent(&mut responses).len(), 2);
+    }
+
+    #[tokio::test]
+    async fn requests_past_the_limit_are_queued() {
+        let (mut worker, mut responses) = offline_worker();
+        for season in 0..=MAX_CONCURRENT_REQUESTS as i32 {
+            start(
+                &mut worker,
+                NetworkRequest::Draft {
+                    year: 2000 + season,
+                },
+            )
+            .await;
+        }
+        assert_eq!(worker.tasks.len(), MAX_CONCURRENT_REQUESTS);
+        assert_eq!(worker.queued.len(), 1);
+
+        finish_all(&mut worker).await;
+        assert!(worker.queued.is_empty());
+        // only the last draft year is still selected
+        assert_eq!(sent(&mut responses).len(), 1);
+    }
+
+    #[tokio::test]
+    async fn superseded_queued_requests_are_skipped() {
+        let (mut worker, mut responses) = offline_worker();
+        for season in 0..MAX_CONCURRENT_REQUESTS as i32 {
+            start(
+                &mut worker,
+                NetworkRequest::Draft {
+                    year: 2000 + season,
+                },
+            )
+            .await;
+        }
+        let date = NaiveDate::from_ymd_opt(2025, 4, 13).unwrap();
+        start(&mut worker, standings(date)).await;
+        start(&mut worker, standings(date.succ_opt().unwrap())).await;
+        assert_eq!(worker.queued.len(), 2);
+
+        let fetched = worker.tasks.join_next().await.unwrap().unwrap();
+        worker.finish(fetched).await;
+        // the first standings date is skipped and the second one takes the free slot
+        assert!(worker.queued.is_empty());
+        assert_eq!(worker.tasks.len(), MAX_CONCURRENT_REQUESTS);
+        assert_eq!(worker.in_flight.len(), MAX_CONCURRENT_REQUESTS);
+
+        finish_all(&mut worker).await;
+        // the last draft year and the second standings date
+        assert_eq!(sent(&mut responses).len(), 2);
+    }
+
+    #[tokio::test]
+    async fn head_to_head_requests_are_never_superseded() {
+        let (mut worker, mut responses) = offline_worker();
+        for pitcher_id in [1, 2, 3] {
+            start(
+                &mut worker,
+                NetworkRequest::VsPlayer {
+                    batter_id: 10,
+                    pitcher_id,
+                    season: 2025,
+                },
+            )
+            .await;
+        }
+        finish_all(&mut worker).await;
+        assert_eq!(sent(&mut responses).len(), 3);
+    }
+
+    #[tokio::test]
+    async fn season_info_is_never_archived() {
+        let dir = temp_dir("season-info");
+        let disk_cache = Arc::new(DiskCache::open(dir.join("cache"), 1024 * 1024).unwrap());
+        let responses = [("/api/v1/seasons/2025?sportId=1", r#"{"seasons":[]}"#)];
+        let (worker, _responses) = replay_worker(&dir, &responses, Some(disk_cache.clone()));
+
+        // as for standings on a settled date
+        let mut fetcher = worker.fetcher.clone();
+        fetcher.use_archive = true;
+        fetcher
+            .ensure_season_info(NaiveDate::from_ymd_opt(2025, 4, 13).unwrap())
+            .await;
+        assert!(disk_cache.is_empty());
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[tokio::test]
+    async fn postseason_rounds_load_with_the_season_info() {
+        let dir = temp_dir("rounds");
+        let series = std::fs::read_to_string("api/tests/responses/postseason-series.json").unwrap();
+        let responses = [
+            (
+                "/api/v1/seasons/2024?sportId=1",
+                r#"{"seasons":[{"regularSeasonStartDate":"2024-03-20","postSeasonStartDate":"2024-10-01","postSeasonEndDate":"2024-10-31"}]}"#,
+            ),
+            (
+                "/api/v1/schedule/postseason/series?sportId=1&season=2024",
+                series.as_str(),
+            ),
+        ];
+        let (worker, _responses) = replay_worker(&dir, &responses, None);
+
+        // without the bracket ever loading
+        let game_type = worker
+            .fetcher
+            .game_type_for_date(NaiveDate::from_ymd_opt(2024, 10, 27).unwrap())
+            .await;
+        assert_eq!(game_type, GameType::WorldSeries);
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[tokio::test]
+    async fn offline_requests_fall_back_to_the_disk_cache() {
+        let dir = temp_dir("offline-disk");
+        let disk_cache = Arc::new(DiskCache::open(&dir, 1024 * 1024).unwrap());
+        // the current draft isn't archivable, but an earlier session may have saved it
+        let year = Utc::now().year();
+        disk_cache
+            .insert(&format!("https://host/api/v1/draft/{year}"), b"{}")
+            .unwrap();
+        let (mut worker, mut responses) = test_worker(Arc::new(OfflineTransport), Some(disk_cache));
+
+        start(&mut worker, NetworkRequest::Draft { year }).await;
+        let leaders = NetworkRequest::LeagueLeaders {
+            date: Utc::now().date_naive(),
+            sport: SportId::Mlb,
+        };
+        start(&mut worker, leaders).await;
+        finish_all(&mut worker).await;
+
+        // the leaders were never saved
+        let sent = sent(&mut responses);
+        assert_eq!(sent.len(), 2);
+        assert!(
+            sent.iter()
+                .any(|r| matches!(r, NetworkResponse::DraftLoaded { .. }))
+        );
+        assert!(
+            sent.iter()
+                .any(|r| matches!(r, NetworkResponse::Error { .. }))
+        );
+        let _ = std::fs::remove_dir_all(&dir);
     }
 
     fn game_request(game_id: u64) -> RefreshableRequest {
@@ -470,7 +1431,10 @@ mod tests {
 
     fn schedule_request(date: NaiveDate) -> RefreshableRequest {
         RefreshableRequest {
-            request: NetworkRequest::Schedule { date },
+            request: NetworkRequest::Schedule {
+                date,
+                sport: SportId::Mlb,
+            },
             force_refresh: false,
         }
     }
@@ -509,7 +1473,7 @@ mod tests {
         assert_eq!(overflow.len(), 1);
         assert_matches!(
             overflow[0].request,
-            NetworkRequest::Schedule { date: d } if d == date
+            NetworkRequest::Schedule { date: d, .. } if d == date
         );
     }
 
@@ -535,4 +1499,63 @@ mod tests {
             }
         );
     }
+
+    fn live_feed(state: AbstractGameState) -> LiveFeed {
+        let mut response = LiveResponse::default();
+        response.meta_data.time_stamp = "20250601_210028".to_string();
+        response.game_data.status.abstract_game_state = Some(state);
+        LiveFeed {
+            response: Arc::new(response),
+            ..LiveFeed::default()
+        }
+    }
+
+    #[test]
+    fn store_live_feed_skips_final_games() {
+        let mut worker = worker();
+        worker.store_live_feed(1, live_feed(AbstractGameState::Live));
+        assert!(worker.live_feeds.contains_key(&1));
+
+        worker.store_live_feed(1, live_feed(AbstractGameState::Final));
+        assert!(worker.live_feeds.is_empty());
+    }
+
+    #[test]
+    fn store_live_feed_evicts_least_recent_game() {
+        let mut worker = worker();
+        for game_id in 0..MAX_LIVE_FEEDS as u64 {
+            worker.store_live_feed(game_id, live_feed(AbstractGameState::Live));
+        }
+        // refreshing game 0 makes game 1 the oldest
+        worker.store_live_feed(0, live_feed(AbstractGameState::Live));
+        worker.store_live_feed(99, live_feed(AbstractGameState::Live));
+
+        assert_eq!(worker.live_feeds.len(), MAX_LIVE_FEEDS);
+        assert!(worker.live_feeds.contains_key(&0));
+        assert!(!worker.live_feeds.contains_key(&1));
+        assert!(worker.live_feeds.contains_key(&99));
+    }
+
+    #[test]
+    fn connection_label() {
+        let tz = chrono_tz::America::New_York;
+        assert_eq!(Connection::Online.label(tz), None);
+        assert_eq!(
+            Connection::Offline { data_from: None }.label(tz).as_deref(),
+            Some("unavailable offline")
+        );
+        assert_eq!(
+            Connection::OfflineArchived.label(tz).as_deref(),
+            Some("offline – saved data")
+        );
+        let data_from = Utc::now() - chrono::Duration::days(3);
+        let expected = data_from.with_timezone(&tz).format("%b %-d %H:%M");
+        assert_eq!(
+            Connection::Offline {
+                data_from: Some(data_from)
+            }
+            .label(tz),
+            Some(format!("offline – data from {expected}"))
+        );
+    }
 }
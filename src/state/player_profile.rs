@@ -1,3 +1,4 @@
+use crate::components::game::spray_chart::BattedBall;
 use crate::components::stats::player_profile::PlayerProfile;
 use crate::state::messages::NetworkRequest;
 use mlbt_api::client::StatGroup;
@@ -6,6 +7,9 @@ use mlbt_api::season::GameType;
 use std::sync::Arc;
 use tui::widgets::ScrollbarState;
 
+/// Number of recent games the spray chart is built from.
+const SPRAY_CHART_GAMES: usize = 10;
+
 /// State for a single Player Profile view.
 pub struct PlayerProfileState {
     pub profile: PlayerProfile,
@@ -16,22 +20,26 @@ pub struct PlayerProfileState {
     pub scroll_state: ScrollbarState,
     pub content_height: u16,
     pub viewport_height: u16,
+    /// Show the spray chart instead of the stat sections.
+    pub show_spray_chart: bool,
+    /// Number of games the spray chart was requested for.
+    pub spray_chart_games: usize,
+    /// `None` until the spray chart has loaded.
+    pub spray_chart: Option<Arc<Vec<BattedBall>>>,
 }
 
 impl PlayerProfileState {
     /// Create from an api response. Returns None if the response has no player data.
     ///
-    /// Takes `Arc<PeopleResponse>` and unwraps to get ownership. Player profiles are not cached,
-    /// so the Arc refcount should always be 1 here.
+    /// The response is borrowed because it's shared with the network cache.
     pub fn from_response(
-        data: Arc<PeopleResponse>,
+        data: &PeopleResponse,
         stat_group: StatGroup,
         game_type: GameType,
         season_year: i32,
     ) -> Option<Self> {
         // only one player was requested so there should only be one person in the response vec.
-        let data = Arc::try_unwrap(data).ok()?;
-        let person = data.people.into_iter().next()?;
+        let person = data.people.first()?;
         Some(Self {
             profile: PlayerProfile::from_person(person),
             stat_group,
@@ -41,13 +49,24 @@ impl PlayerProfileState {
             scroll_state: ScrollbarState::default(),
             content_height: 0,
             viewport_height: 0,
+            show_spray_chart: false,
+            spray_chart_games: 0,
+            spray_chart: None,
         })
     }
 
+    /// Cycle through the regular season, spring training and the postseason. Any postseason round
+    /// requests the stats of the whole postseason, see `GameType::stats_param`.
     pub fn game_type_toggle_request(&self, date: chrono::NaiveDate) -> NetworkRequest {
         let game_type = match self.game_type {
             GameType::RegularSeason => GameType::SpringTraining,
-            GameType::SpringTraining => GameType::RegularSeason,
+            GameType::SpringTraining => GameType::WildCard,
+            GameType::WildCard
+            | GameType::DivisionSeries
+            | GameType::LeagueChampionshipSeries
+            | GameType::WorldSeries
+            | GameType::Exhibition
+            | GameType::AllStar => GameType::RegularSeason,
         };
         NetworkRequest::PlayerProfile {
             player_id: self.profile.id,
@@ -57,6 +76,49 @@ impl PlayerProfileState {
         }
     }
 
+    /// Toggle the spray chart. The first time it's shown this returns the request for the balls in
+    /// play from the most recent games in the game log.
+    pub fn toggle_spray_chart(&mut self) -> Option<NetworkRequest> {
+        self.show_spray_chart = !self.show_spray_chart;
+        if !self.show_spray_chart || self.spray_chart_games > 0 || self.spray_chart.is_some() {
+            return None;
+        }
+        let game_ids: Vec<u64> = self
+            .profile
+            .splits
+            .game_log
+            .iter()
+            .rev()
+            .filter_map(|split| split.game.as_ref().map(|g| g.game_pk))
+            .take(SPRAY_CHART_GAMES)
+            .collect();
+        if game_ids.is_empty() {
+            self.spray_chart = Some(Arc::default());
+            return None;
+        }
+        self.spray_chart_games = game_ids.len();
+        Some(NetworkRequest::SprayChart {
+            player_id: self.profile.id,
+            group: self.stat_group,
+            game_ids,
+        })
+    }
+
+    pub fn update_spray_chart(&mut self, player_id: u64, balls: Arc<Vec<BattedBall>>) {
+        if self.profile.id == player_id {
+            self.spray_chart = Some(balls);
+        }
+    }
+
+    /// Go back to the stat sections and forget the failed request, so toggling the chart requests
+    /// it again instead of showing it as loading.
+    pub fn spray_chart_failed(&mut self, player_id: u64) {
+        if self.profile.id == player_id && self.spray_chart.is_none() {
+            self.spray_chart_games = 0;
+            self.show_spray_chart = false;
+        }
+    }
+
     pub fn scroll_down(&mut self) {
         let max = self.content_height.saturating_sub(self.viewport_height);
         if self.scroll_offset < max {
@@ -82,7 +144,7 @@ impl PlayerProfileState {
     }
 
     /// Calculate the height of each section for layout.
-    pub fn section_heights(&self) -> [u16; 5] {
+    pub fn section_heights(&self) -> [u16; 6] {
         let bio_height = self.profile.bio.len() as u16;
         let splits = &self.profile.splits;
 
@@ -92,6 +154,12 @@ impl PlayerProfileState {
             2 // title + "No data"
         };
 
+        let arsenal_height = if !splits.pitch_arsenal.is_empty() {
+            splits.pitch_arsenal.len() as u16 + 3 // title + header + rows + blank line
+        } else {
+            0
+        };
+
         let recent_splits_height = if splits.recent_splits.iter().any(|s| s.stat.is_some()) {
             5 // title + header + 3 rows
         } else {
@@ -114,6 +182,7 @@ impl PlayerProfileState {
         [
             bio_height + 1, // +1 for blank line below section
             season_height + 1,
+            arsenal_height,
             recent_splits_height + 1,
             career_height + 1,
             game_log_height,
@@ -129,3 +198,45 @@ impl PlayerProfileState {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mlbt_api::player::PersonFull;
+
+    fn profile(game_type: GameType) -> PlayerProfileState {
+        let data = PeopleResponse {
+            people: vec![PersonFull {
+                id: 1,
+                ..Default::default()
+            }],
+        };
+        PlayerProfileState::from_response(&data, StatGroup::Hitting, game_type, 2025).unwrap()
+    }
+
+    #[test]
+    fn game_type_cycles_through_the_postseason() {
+        let date = chrono::NaiveDate::from_ymd_opt(2025, 10, 20).unwrap();
+        let next = |game_type| match profile(game_type).game_type_toggle_request(date) {
+            NetworkRequest::PlayerProfile { game_type, .. } => game_type,
+            request => panic!("unexpected {request:?}"),
+        };
+        assert_eq!(next(GameType::RegularSeason), GameType::SpringTraining);
+        assert_eq!(next(GameType::SpringTraining), GameType::WildCard);
+        assert_eq!(next(GameType::WorldSeries), GameType::RegularSeason);
+        assert_eq!(next(GameType::AllStar), GameType::RegularSeason);
+    }
+
+    #[test]
+    fn failed_spray_chart_can_be_requested_again() {
+        let mut state = profile(GameType::RegularSeason);
+        state.show_spray_chart = true;
+        state.spray_chart_games = SPRAY_CHART_GAMES;
+
+        state.spray_chart_failed(2);
+        assert_eq!(state.spray_chart_games, SPRAY_CHART_GAMES);
+        state.spray_chart_failed(1);
+        assert_eq!(state.spray_chart_games, 0);
+        assert!(!state.show_spray_chart);
+    }
+}
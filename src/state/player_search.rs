@@ -0,0 +1,212 @@
+use mlbt_api::client::StatGroup;
+use mlbt_api::player::PeopleResponse;
+
+/// Minimum number of characters before a search is sent.
+const MIN_QUERY_LEN: usize = 2;
+
+/// Global player search. Unlike the Stats search, which filters the loaded table, this queries the
+/// API so any player can be found, including retired players.
+#[derive(Default)]
+pub struct PlayerSearchState {
+    pub is_open: bool,
+    pub input: String,
+    /// Only return players that are currently active.
+    pub active_only: bool,
+    /// None until a response for `searched_query` arrives.
+    pub results: Option<Vec<SearchResult>>,
+    pub selected: usize,
+    /// The query of the request in flight or of the current results.
+    pub searched_query: Option<String>,
+}
+
+#[derive(Debug)]
+pub struct SearchResult {
+    pub id: u64,
+    pub name: String,
+    /// Primary position abbreviation, e.g. "RF".
+    pub position: String,
+    pub team: Option<String>,
+    pub active: bool,
+    /// Profile layout to open, pitchers use pitching and everyone else hitting.
+    pub group: StatGroup,
+}
+
+impl PlayerSearchState {
+    pub fn open(&mut self) {
+        self.is_open = true;
+    }
+
+    pub fn close(&mut self) {
+        self.is_open = false;
+        self.input.clear();
+        self.clear_results();
+    }
+
+    pub fn handle_char(&mut self, c: char) {
+        self.input.push(c);
+        self.clear_results();
+    }
+
+    pub fn handle_backspace(&mut self) {
+        self.input.pop();
+        self.clear_results();
+    }
+
+    pub fn toggle_active_only(&mut self) {
+        self.active_only = !self.active_only;
+        self.clear_results();
+    }
+
+    /// Returns the query to send, or None if the input is too short.
+    pub fn submit(&mut self) -> Option<String> {
+        let query = self.input.trim();
+        if query.chars().count() < MIN_QUERY_LEN {
+            return None;
+        }
+        self.searched_query = Some(query.to_string());
+        self.searched_query.clone()
+    }
+
+    /// Store the results if they're for the current query. A response for an older query is
+    /// dropped since the input has changed since it was sent.
+    pub fn update(&mut self, query: &str, response: &PeopleResponse) {
+        if self.searched_query.as_deref() != Some(query) {
+            return;
+        }
+        let results = response
+            .people
+            .iter()
+            .map(|person| {
+                let position = person
+                    .primary_position
+                    .as_ref()
+                    .map(|p| p.abbreviation.clone())
+                    .unwrap_or_default();
+                let group = match position.as_str() {
+                    "P" => StatGroup::Pitching,
+                    _ => StatGroup::Hitting,
+                };
+                SearchResult {
+                    id: person.id,
+                    name: person.full_name.clone(),
+                    position,
+                    team: person.current_team.as_ref().map(|t| t.name.clone()),
+                    active: person.active.unwrap_or(false),
+                    group,
+                }
+            })
+            .collect();
+        self.results = Some(results);
+        self.selected = 0;
+    }
+
+    /// True while waiting on the response for the current query.
+    pub fn is_searching(&self) -> bool {
+        self.searched_query.is_some() && self.results.is_none()
+    }
+
+    pub fn selected_result(&self) -> Option<&SearchResult> {
+        self.results.as_ref()?.get(self.selected)
+    }
+
+    pub fn next(&mut self) {
+        let len = self.result_count();
+        if len > 0 {
+            self.selected = (self.selected + 1) % len;
+        }
+    }
+
+    pub fn previous(&mut self) {
+        let len = self.result_count();
+        if len > 0 {
+            self.selected = (self.selected + len - 1) % len;
+        }
+    }
+
+    pub fn result_count(&self) -> usize {
+        self.results.as_ref().map_or(0, Vec::len)
+    }
+
+    fn clear_results(&mut self) {
+        self.results = None;
+        self.selected = 0;
+        self.searched_query = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mlbt_api::live::PrimaryPosition;
+    use mlbt_api::player::PersonFull;
+
+    fn person(id: u64, name: &str, position: &str) -> PersonFull {
+        PersonFull {
+            id,
+            full_name: name.to_string(),
+            active: Some(true),
+            primary_position: Some(PrimaryPosition {
+                code: String::new(),
+                name: String::new(),
+                r#type: String::new(),
+                abbreviation: position.to_string(),
+            }),
+            ..PersonFull::default()
+        }
+    }
+
+    fn search(input: &str) -> PlayerSearchState {
+        let mut state = PlayerSearchState::default();
+        state.open();
+        input.chars().for_each(|c| state.handle_char(c));
+        state
+    }
+
+    #[test]
+    fn requires_minimum_query_length() {
+        let mut state = search(" j ");
+        assert_eq!(state.submit(), None);
+
+        let mut state = search(" judge ");
+        assert_eq!(state.submit().as_deref(), Some("judge"));
+        assert!(state.is_searching());
+    }
+
+    #[test]
+    fn maps_results_and_groups() {
+        let mut state = search("cole");
+        let query = state.submit().unwrap();
+        let response = PeopleResponse {
+            people: vec![
+                person(543037, "Gerrit Cole", "P"),
+                person(1, "Cole Young", "SS"),
+            ],
+        };
+        state.update(&query, &response);
+
+        assert!(!state.is_searching());
+        assert_eq!(state.result_count(), 2);
+        assert_eq!(state.selected_result().unwrap().group, StatGroup::Pitching);
+        state.next();
+        let result = state.selected_result().unwrap();
+        assert_eq!(
+            (result.position.as_str(), result.group),
+            ("SS", StatGroup::Hitting)
+        );
+        state.next();
+        assert_eq!(state.selected, 0);
+    }
+
+    #[test]
+    fn drops_stale_responses() {
+        let mut state = search("cole");
+        let query = state.submit().unwrap();
+        // typing after submitting starts a new query
+        state.handle_char('s');
+        let response = PeopleResponse {
+            people: vec![person(543037, "Gerrit Cole", "P")],
+        };
+        state.update(&query, &response);
+        assert!(state.results.is_none());
+    }
+}
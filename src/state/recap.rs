@@ -0,0 +1,122 @@
+use crate::components::game::content::{GameContent, Story};
+use crate::state::messages::NetworkRequest;
+use mlbt_api::content::ContentResponse;
+use tui::widgets::ScrollbarState;
+
+/// The articles for the game shown in Gameday and the Scoreboard box score area.
+#[derive(Default)]
+pub struct RecapState {
+    game_id: u64,
+    content: Option<GameContent>,
+    /// The game and whether it was final when its content was last requested. A game that goes
+    /// final after its preview was loaded is requested again to get the recap.
+    requested: Option<(u64, bool)>,
+    /// The request that `content` was loaded for.
+    loaded: Option<(u64, bool)>,
+    pub scroll: u16,
+    pub max_scroll: u16,
+    pub scroll_state: ScrollbarState,
+}
+
+impl RecapState {
+    /// The request for the game's content, if it hasn't already been requested in its current
+    /// state.
+    pub fn request(&mut self, game_id: u64, is_final: bool) -> Option<NetworkRequest> {
+        if game_id == 0 || self.requested == Some((game_id, is_final)) {
+            return None;
+        }
+        self.requested = Some((game_id, is_final));
+        Some(NetworkRequest::GameContent { game_id })
+    }
+
+    pub fn update(&mut self, game_id: u64, content: &ContentResponse) {
+        if self.game_id != game_id {
+            self.scroll = 0;
+        }
+        self.game_id = game_id;
+        self.content = Some(GameContent::from(content));
+        self.loaded = match self.requested {
+            Some((id, is_final)) if id == game_id => Some((id, is_final)),
+            _ => Some((game_id, false)),
+        };
+    }
+
+    /// Forget a failed request so the next call to `request` sends it again.
+    pub fn failed(&mut self, game_id: u64) {
+        if self.requested.is_some_and(|(id, _)| id == game_id) {
+            self.requested = self.loaded;
+        }
+    }
+
+    /// The story to show for the game, `None` if it hasn't loaded or there isn't one.
+    pub fn story(&self, game_id: u64, is_final: bool) -> Option<&Story> {
+        if self.game_id != game_id {
+            return None;
+        }
+        self.content.as_ref()?.story(is_final)
+    }
+
+    /// True while waiting on the content of the game in its current state, including the recap of
+    /// a game whose preview has loaded.
+    pub fn is_loading(&self, game_id: u64, is_final: bool) -> bool {
+        let key = Some((game_id, is_final));
+        self.requested == key && self.loaded != key
+    }
+
+    /// Clamp the scroll position to the rendered content height.
+    pub fn sync_scrollbar(&mut self, content_height: u16, viewport_height: u16) {
+        self.max_scroll = content_height.saturating_sub(viewport_height);
+        self.scroll = self.scroll.min(self.max_scroll);
+        self.scroll_state = self
+            .scroll_state
+            .content_length(content_height as usize)
+            .position(self.scroll as usize);
+    }
+
+    pub fn scroll_down(&mut self) {
+        self.scroll = (self.scroll + 1).min(self.max_scroll);
+    }
+
+    pub fn scroll_up(&mut self) {
+        self.scroll = self.scroll.saturating_sub(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn requests_again_once_the_game_is_final() {
+        let mut state = RecapState::default();
+        assert!(state.request(0, false).is_none());
+        assert!(matches!(
+            state.request(1, false),
+            Some(NetworkRequest::GameContent { game_id: 1 })
+        ));
+        assert!(state.is_loading(1, false));
+        assert!(state.request(1, false).is_none());
+
+        state.update(1, &ContentResponse::default());
+        assert!(!state.is_loading(1, false));
+        assert!(state.request(1, true).is_some());
+        assert!(state.is_loading(1, true), "the recap replaces the preview");
+        assert!(state.request(1, true).is_none());
+        state.update(1, &ContentResponse::default());
+        assert!(!state.is_loading(1, true));
+        // a different game is always requested
+        assert!(state.request(2, true).is_some());
+    }
+
+    #[test]
+    fn failed_request_is_sent_again() {
+        let mut state = RecapState::default();
+        state.request(1, false);
+        state.update(1, &ContentResponse::default());
+        state.request(1, true);
+
+        state.failed(1);
+        assert!(!state.is_loading(1, true));
+        assert!(state.request(1, true).is_some());
+    }
+}
@@ -46,13 +46,13 @@ impl PeriodicRefresher {
 
                 // Schedule updates (moderate frequency)
                 _ = schedule_interval.tick() => {
-                    let (active_tab, date, game_id) = {
+                    let (active_tab, date, sport, game_id) = {
                         let app = app.lock().await;
-                        (app.state.active_tab, app.state.schedule.date_selector.date, app.state.schedule.get_selected_game_opt().unwrap_or(0))
+                        (app.state.active_tab, app.state.schedule.date_selector.date, app.state.schedule.sport, app.state.schedule.get_selected_game_opt().unwrap_or(0))
                     };
 
                     if active_tab == MenuItem::Scoreboard {
-                        let _ = self.network_requests.send(RefreshableRequest::force(NetworkRequest::Schedule { date })).await;
+                        let _ = self.network_requests.send(RefreshableRequest::force(NetworkRequest::Schedule { date, sport })).await;
                         if game_id > 0 {
                             let _ = self.network_requests.send(RefreshableRequest::force(NetworkRequest::GameData { game_id })).await;
                         }
@@ -61,25 +61,25 @@ impl PeriodicRefresher {
 
                 // Standings updates (low frequency)
                 _ = standings_interval.tick() => {
-                    let (active_tab, date) = {
+                    let (active_tab, date, sport) = {
                         let app = app.lock().await;
-                        (app.state.active_tab, app.state.standings.date_selector.date)
+                        (app.state.active_tab, app.state.standings.date_selector.date, app.state.standings.sport)
                     };
 
                     if active_tab == MenuItem::Standings {
-                        let _ = self.network_requests.send(RefreshableRequest::force(NetworkRequest::Standings { date })).await;
+                        let _ = self.network_requests.send(RefreshableRequest::force(NetworkRequest::Standings { date, sport })).await;
                     }
                 }
 
                 // Stats updates (low frequency)
                 _ = stats_interval.tick() => {
-                    let (active_tab, date, stat_type) = {
+                    let (active_tab, date, stat_type, sport) = {
                         let app = app.lock().await;
-                        (app.state.active_tab, app.state.stats.date_selector.date, app.state.stats.stat_type)
+                        (app.state.active_tab, app.state.stats.date_selector.date, app.state.stats.stat_type, app.state.stats.sport)
                     };
 
                     if active_tab == MenuItem::Stats {
-                        let _ = self.network_requests.send(RefreshableRequest::force(NetworkRequest::Stats { date, stat_type })).await;
+                        let _ = self.network_requests.send(RefreshableRequest::force(NetworkRequest::Stats { date, stat_type, sport })).await;
                     }
                 }
 
@@ -0,0 +1,150 @@
+use crate::app::MenuItem;
+use crate::state::messages::{NetworkRequest, RequestKind};
+use mlbt_api::client::ApiError;
+use std::sync::Arc;
+
+/// A request that failed, kept so it can be retried.
+#[derive(Clone, Debug)]
+pub struct FailedRequest {
+    pub request: NetworkRequest,
+    pub error: Arc<ApiError>,
+}
+
+impl FailedRequest {
+    /// e.g. "Standings failed: timeout – press R to retry"
+    pub fn status_line(&self) -> String {
+        format!(
+            "{} failed: {} – press R to retry",
+            self.request.kind().label(),
+            self.error.summary()
+        )
+    }
+}
+
+/// The tabs that show a kind of request, the first one being where background refreshes of it
+/// belong.
+fn tabs_for(kind: RequestKind) -> &'static [MenuItem] {
+    match kind {
+        RequestKind::Teams | RequestKind::Schedule => &[MenuItem::Scoreboard],
+        RequestKind::Game | RequestKind::GameContent => &[MenuItem::Scoreboard, MenuItem::Gameday],
+        RequestKind::VsPlayer => &[MenuItem::Gameday],
+        RequestKind::Standings | RequestKind::PostseasonBracket => &[MenuItem::Standings],
+        RequestKind::Stats | RequestKind::LeagueLeaders => &[MenuItem::Stats],
+        // opened from the team pages and profiles of both tabs
+        RequestKind::PlayerProfile
+        | RequestKind::SprayChart
+        | RequestKind::TeamPage
+        | RequestKind::TeamRoster
+        | RequestKind::Draft
+        | RequestKind::InjuredList => &[MenuItem::Stats, MenuItem::Standings],
+        // the player search is opened over any tab
+        RequestKind::SearchPeople => &[
+            MenuItem::Scoreboard,
+            MenuItem::Gameday,
+            MenuItem::Stats,
+            MenuItem::Standings,
+        ],
+    }
+}
+
+/// The tab a failed request is shown on. That's the tab being viewed if it shows that kind of
+/// request, otherwise the request was a background refresh for another tab, e.g. the schedule
+/// polled while the stats are open.
+pub fn tab_for(request: &NetworkRequest, viewed: MenuItem) -> MenuItem {
+    let tabs = tabs_for(request.kind());
+    if tabs.contains(&viewed) {
+        viewed
+    } else {
+        tabs[0]
+    }
+}
+
+/// The last failed request of each tab. A failure is shown on the tab until it's retried or the
+/// same kind of data loads.
+#[derive(Default)]
+pub struct RequestErrors {
+    failed: Vec<(MenuItem, FailedRequest)>,
+}
+
+impl RequestErrors {
+    pub fn record(&mut self, tab: MenuItem, failed: FailedRequest) {
+        self.failed.retain(|(t, _)| *t != tab);
+        self.failed.push((tab, failed));
+    }
+
+    pub fn get(&self, tab: MenuItem) -> Option<&FailedRequest> {
+        self.failed
+            .iter()
+            .find(|(t, _)| *t == tab)
+            .map(|(_, failed)| failed)
+    }
+
+    /// Remove the tab's failure, to retry it.
+    pub fn take(&mut self, tab: MenuItem) -> Option<FailedRequest> {
+        let idx = self.failed.iter().position(|(t, _)| *t == tab)?;
+        Some(self.failed.remove(idx).1)
+    }
+
+    /// Clear failures on every tab for a kind of data that has now loaded.
+    pub fn loaded(&mut self, kind: RequestKind) {
+        self.failed.retain(|(_, f)| f.request.kind() != kind);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+    use mlbt_api::teams::SportId;
+
+    fn failed_standings() -> FailedRequest {
+        FailedRequest {
+            request: NetworkRequest::Standings {
+                date: NaiveDate::from_ymd_opt(2025, 4, 13).unwrap(),
+                sport: SportId::Mlb,
+            },
+            error: Arc::new(ApiError::Timeout { url: String::new() }),
+        }
+    }
+
+    #[test]
+    fn status_line_names_the_request_and_error() {
+        assert_eq!(
+            failed_standings().status_line(),
+            "Standings failed: timeout – press R to retry"
+        );
+    }
+
+    #[test]
+    fn background_failures_belong_to_the_tab_showing_them() {
+        let schedule = NetworkRequest::Schedule {
+            date: NaiveDate::from_ymd_opt(2025, 4, 13).unwrap(),
+            sport: SportId::Mlb,
+        };
+        let game = NetworkRequest::GameData { game_id: 1 };
+        assert_eq!(tab_for(&schedule, MenuItem::Stats), MenuItem::Scoreboard);
+        assert_eq!(tab_for(&game, MenuItem::Standings), MenuItem::Scoreboard);
+        assert_eq!(tab_for(&game, MenuItem::Gameday), MenuItem::Gameday);
+        assert_eq!(
+            tab_for(&failed_standings().request, MenuItem::Standings),
+            MenuItem::Standings
+        );
+    }
+
+    #[test]
+    fn failures_are_per_tab_and_cleared_when_loaded() {
+        let mut errors = RequestErrors::default();
+        errors.record(MenuItem::Standings, failed_standings());
+        assert!(errors.get(MenuItem::Standings).is_some());
+        assert!(errors.get(MenuItem::Stats).is_none());
+
+        errors.loaded(RequestKind::Stats);
+        assert!(errors.get(MenuItem::Standings).is_some());
+        errors.loaded(RequestKind::Standings);
+        assert!(errors.get(MenuItem::Standings).is_none());
+
+        errors.record(MenuItem::Standings, failed_standings());
+        assert!(errors.take(MenuItem::Standings).is_some());
+        assert!(errors.take(MenuItem::Standings).is_none());
+    }
+}
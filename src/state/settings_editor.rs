@@ -1,6 +1,6 @@
 use crate::components::constants::current_teams_sorted;
 use crate::components::standings::Team;
-use crate::config::LogLevel;
+use crate::config::{BroadcastLanguage, LogLevel};
 use crate::state::app_settings::AppSettings;
 use chrono_tz::Tz;
 use std::sync::LazyLock;
@@ -20,6 +20,7 @@ pub enum SettingsField {
     FavoriteTeam,
     Timezone,
     AutoAdvanceDate,
+    BroadcastLanguage,
     LogLevel,
 }
 
@@ -48,10 +49,11 @@ pub struct PickerState {
 }
 
 impl SettingsField {
-    pub const ALL: [SettingsField; 4] = [
+    pub const ALL: [SettingsField; 5] = [
         SettingsField::FavoriteTeam,
         SettingsField::Timezone,
         SettingsField::AutoAdvanceDate,
+        SettingsField::BroadcastLanguage,
         SettingsField::LogLevel,
     ];
 
@@ -60,6 +62,7 @@ impl SettingsField {
             SettingsField::FavoriteTeam => "Team",
             SettingsField::Timezone => "Timezone",
             SettingsField::AutoAdvanceDate => "Auto-date",
+            SettingsField::BroadcastLanguage => "Broadcasts",
             SettingsField::LogLevel => "Log",
         }
     }
@@ -68,7 +71,8 @@ impl SettingsField {
         match self {
             SettingsField::FavoriteTeam => SettingsField::Timezone,
             SettingsField::Timezone => SettingsField::AutoAdvanceDate,
-            SettingsField::AutoAdvanceDate => SettingsField::LogLevel,
+            SettingsField::AutoAdvanceDate => SettingsField::BroadcastLanguage,
+            SettingsField::BroadcastLanguage => SettingsField::LogLevel,
             SettingsField::LogLevel => SettingsField::FavoriteTeam,
         }
     }
@@ -78,7 +82,8 @@ impl SettingsField {
             SettingsField::FavoriteTeam => SettingsField::LogLevel,
             SettingsField::Timezone => SettingsField::FavoriteTeam,
             SettingsField::AutoAdvanceDate => SettingsField::Timezone,
-            SettingsField::LogLevel => SettingsField::AutoAdvanceDate,
+            SettingsField::BroadcastLanguage => SettingsField::AutoAdvanceDate,
+            SettingsField::LogLevel => SettingsField::BroadcastLanguage,
         }
     }
 
@@ -88,6 +93,7 @@ impl SettingsField {
             SettingsField::Timezone => TIMEZONE_OPTIONS.len(),
             SettingsField::LogLevel => LOG_LEVEL_OPTIONS.len(),
             SettingsField::AutoAdvanceDate => TOGGLE_OPTIONS.len(),
+            SettingsField::BroadcastLanguage => BROADCAST_LANGUAGE_OPTIONS.len(),
         }
     }
 
@@ -97,6 +103,9 @@ impl SettingsField {
             SettingsField::Timezone => TIMEZONE_OPTIONS.get(index).map(|o| o.picker_label),
             SettingsField::LogLevel => LOG_LEVEL_OPTIONS.get(index).map(|o| o.label),
             SettingsField::AutoAdvanceDate => TOGGLE_OPTIONS.get(index).map(|o| o.label),
+            SettingsField::BroadcastLanguage => {
+                BROADCAST_LANGUAGE_OPTIONS.get(index).map(|o| o.label)
+            }
         }
     }
 
@@ -119,6 +128,10 @@ impl SettingsField {
                 .iter()
                 .position(|o| o.value == settings.auto_advance_date)
                 .unwrap_or(0),
+            SettingsField::BroadcastLanguage => BROADCAST_LANGUAGE_OPTIONS
+                .iter()
+                .position(|o| o.value == settings.broadcast_language)
+                .unwrap_or(0),
             SettingsField::LogLevel => LOG_LEVEL_OPTIONS
                 .iter()
                 .position(|o| o.value == settings.log_level)
@@ -150,6 +163,11 @@ impl SettingsField {
                     settings.auto_advance_date = opt.value;
                 }
             }
+            SettingsField::BroadcastLanguage => {
+                if let Some(opt) = BROADCAST_LANGUAGE_OPTIONS.get(index) {
+                    settings.broadcast_language = opt.value;
+                }
+            }
         }
     }
 }
@@ -255,6 +273,11 @@ pub fn current_value_label(field: SettingsField, settings: &AppSettings) -> Stri
             .find(|o| o.value == settings.auto_advance_date)
             .map(|o| o.label.to_string())
             .unwrap_or_else(|| "<unset>".to_string()),
+        SettingsField::BroadcastLanguage => BROADCAST_LANGUAGE_OPTIONS
+            .iter()
+            .find(|o| o.value == settings.broadcast_language)
+            .map(|o| o.label.to_string())
+            .unwrap_or_else(|| "<unset>".to_string()),
     }
 }
 
@@ -310,6 +333,19 @@ pub const TOGGLE_OPTIONS: &[ToggleOption] = &[
     ToggleOption {label: "Off", value: false},
 ];
 
+#[derive(Debug, Clone, Copy)]
+pub struct BroadcastLanguageOption {
+    pub label: &'static str,
+    pub value: BroadcastLanguage,
+}
+
+#[rustfmt::skip]
+pub const BROADCAST_LANGUAGE_OPTIONS: &[BroadcastLanguageOption] = &[
+    BroadcastLanguageOption {label: "All", value: BroadcastLanguage::All},
+    BroadcastLanguageOption {label: "English", value: BroadcastLanguage::English},
+    BroadcastLanguageOption {label: "Spanish", value: BroadcastLanguage::Spanish},
+];
+
 /// Team picker list: `<none>` sentinel first, then all current MLB teams sorted by name.
 pub static TEAM_OPTIONS: LazyLock<Vec<Option<Team>>> = LazyLock::new(|| {
     let mut v: Vec<Option<Team>> = vec![None];
@@ -346,5 +382,10 @@ pub fn max_value_width(field: SettingsField) -> usize {
             .map(|o| o.label.chars().count())
             .max()
             .unwrap_or(0),
+        SettingsField::BroadcastLanguage => BROADCAST_LANGUAGE_OPTIONS
+            .iter()
+            .map(|o| o.label.chars().count())
+            .max()
+            .unwrap_or(0),
     }
 }
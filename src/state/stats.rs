@@ -1,5 +1,6 @@
 use crate::components::constants::lookup_team_by_id;
 use crate::components::date_selector::DateSelector;
+use crate::components::stats::leaders::Leaders;
 use crate::components::stats::search::SearchState;
 use crate::components::stats::table::{
     PLAYER_COLUMN_NAME, Sort, StatType, StatsTable, TEAM_COLUMN_NAME, TableData, TeamOrPlayer,
@@ -10,11 +11,13 @@ use crate::state::team_page::TeamPageState;
 use chrono::{Datelike, NaiveDate};
 use chrono_tz::Tz;
 use mlbt_api::client::StatGroup;
+use mlbt_api::leaders::LeagueLeadersResponse;
 use mlbt_api::player::PeopleResponse;
 use mlbt_api::schedule::ScheduleResponse;
 use mlbt_api::season::GameType;
 use mlbt_api::stats::StatsResponse;
 use mlbt_api::team::{RosterResponse, RosterType, TransactionsResponse};
+use mlbt_api::teams::SportId;
 use std::collections::HashMap;
 use std::sync::Arc;
 use tui::widgets::TableState;
@@ -60,6 +63,11 @@ pub struct StatsState {
     pub show_options: bool,
     /// Date selector for viewing stats on a specific date.
     pub date_selector: DateSelector,
+    /// MLB or one of the minor league levels.
+    pub sport: SportId,
+    /// Show the league leaders instead of the stats table.
+    pub show_leaders: bool,
+    pub leaders: Option<Leaders>,
     /// Visible row count in the data table, updated during render.
     pub visible_rows: usize,
     /// Search state for players or teams.
@@ -87,6 +95,9 @@ impl Default for StatsState {
             table: StatsTable::new(stat_type),
             show_options: true,
             date_selector: DateSelector::default(),
+            sport: SportId::default(),
+            show_leaders: false,
+            leaders: None,
             visible_rows: 0,
             search: SearchState::default(),
             player_profile: None,
@@ -151,6 +162,19 @@ impl StatsState {
         }
     }
 
+    /// Update the league leaders, ignoring responses for a season or level that is no longer
+    /// selected.
+    pub fn update_leaders(&mut self, season: i32, sport: SportId, leaders: &LeagueLeadersResponse) {
+        if season == self.date_selector.date.year() && sport == self.sport {
+            self.leaders = Some(Leaders::from_response(leaders));
+        }
+    }
+
+    /// Toggle between the stats table and the league leaders.
+    pub fn toggle_leaders(&mut self) {
+        self.show_leaders = !self.show_leaders;
+    }
+
     pub fn has_player_profile(&self) -> bool {
         self.player_profile.is_some()
     }
@@ -159,11 +183,13 @@ impl StatsState {
         self.team_page.is_some()
     }
 
-    /// Close the top layer overlay (player profile or team page).
+    /// Close the top layer overlay (player profile, draft browser or team page).
     pub fn close_overlay(&mut self) {
         if let Some(tp) = &mut self.team_page {
             if tp.player_profile.is_some() {
                 tp.player_profile = None;
+            } else if tp.draft.is_some() {
+                tp.draft = None;
             } else {
                 self.team_page = None;
             }
@@ -205,16 +231,34 @@ impl StatsState {
         }
     }
 
+    pub fn update_injured_list(
+        &mut self,
+        team_id: u16,
+        roster: &RosterResponse,
+        transactions: &TransactionsResponse,
+    ) {
+        if let Some(tp) = &mut self.team_page
+            && tp.team.id == team_id
+        {
+            tp.update_injured_list(roster, transactions);
+        }
+    }
+
     pub fn update_team_player_profile(&mut self, data: Arc<PeopleResponse>, game_type: GameType) {
         if let Some(tp) = &mut self.team_page {
             tp.update_player_profile(data, game_type);
         }
     }
 
-    pub fn update_player_profile(&mut self, data: Arc<PeopleResponse>, game_type: GameType) {
+    pub fn update_player_profile(
+        &mut self,
+        data: Arc<PeopleResponse>,
+        group: StatGroup,
+        game_type: GameType,
+    ) {
         let season_year = self.date_selector.date.year();
         self.player_profile =
-            PlayerProfileState::from_response(data, self.stat_type.group, game_type, season_year);
+            PlayerProfileState::from_response(&data, group, game_type, season_year);
     }
 
     /// Returns the request to open the selected row (player profile or team page).
@@ -227,9 +271,14 @@ impl StatsState {
             });
         }
         let player_id = self.get_selected_id()?;
+        // the profile only has hitting and pitching layouts
+        let group = match self.stat_type.group {
+            StatGroup::Pitching => StatGroup::Pitching,
+            _ => StatGroup::Hitting,
+        };
         Some(NetworkRequest::PlayerProfile {
             player_id,
-            group: self.stat_type.group,
+            group,
             date: self.date_selector.date,
             game_type: GameType::RegularSeason,
         })
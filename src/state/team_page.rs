@@ -1,10 +1,14 @@
 use crate::components::standings::Team;
-use crate::components::team_page::{RosterRow, TeamGame, TransactionRow};
+use crate::components::team_page::{
+    DepthChartRow, RosterRow, RosterView, TeamGame, TransactionRow,
+};
+use crate::state::draft::DraftState;
 use crate::state::messages::NetworkRequest;
 use crate::state::player_profile::PlayerProfileState;
 use chrono::Datelike;
 use chrono_tz::Tz;
 use mlbt_api::client::StatGroup;
+use mlbt_api::draft::DraftResponse;
 use mlbt_api::schedule::ScheduleResponse;
 use mlbt_api::season::GameType;
 use mlbt_api::team::{RosterResponse, RosterType, TransactionsResponse};
@@ -15,6 +19,7 @@ use tui::widgets::TableState;
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum TeamSection {
     Roster,
+    DepthChart,
     Schedule,
     Transactions,
 }
@@ -25,7 +30,10 @@ pub struct TeamPageState {
     pub schedule: Vec<TeamGame>,
     pub schedule_selection: TableState,
     pub roster: Vec<RosterRow>,
-    pub roster_type: RosterType,
+    pub roster_view: RosterView,
+    /// Loaded the first time the depth chart section is shown.
+    pub depth_chart: Vec<DepthChartRow>,
+    pub depth_chart_selection: TableState,
     pub transactions: Vec<TransactionRow>,
     pub selected_transaction: usize,
     pub transaction_scroll: u16,
@@ -37,6 +45,8 @@ pub struct TeamPageState {
     /// table row index -> roster vec index, None for group headers
     pub roster_row_map: Vec<Option<usize>>,
     pub player_profile: Option<PlayerProfileState>,
+    /// Draft browser overlay, opened on this team's picks.
+    pub draft: Option<DraftState>,
     pub show_calendar: bool,
 }
 
@@ -78,7 +88,9 @@ impl TeamPageState {
             schedule,
             schedule_selection,
             roster,
-            roster_type: RosterType::Active,
+            roster_view: RosterView::Active,
+            depth_chart: Vec::new(),
+            depth_chart_selection: TableState::default(),
             transactions,
             selected_transaction: 0,
             transaction_scroll: 0,
@@ -88,13 +100,39 @@ impl TeamPageState {
             roster_header_rows,
             roster_row_map,
             player_profile: None,
+            draft: None,
             show_calendar: true,
         }
     }
 
     pub fn update_roster(&mut self, roster: &RosterResponse, roster_type: RosterType) {
-        self.roster = RosterRow::from_roster(roster);
-        self.roster_type = roster_type;
+        let Some(view) = RosterView::from_roster_type(roster_type) else {
+            self.update_depth_chart(roster);
+            return;
+        };
+        self.roster_view = view;
+        self.set_roster(RosterRow::from_roster(roster));
+    }
+
+    pub fn update_injured_list(
+        &mut self,
+        roster: &RosterResponse,
+        transactions: &TransactionsResponse,
+    ) {
+        self.roster_view = RosterView::Injured;
+        self.set_roster(RosterRow::from_injured_list(roster, transactions));
+    }
+
+    fn update_depth_chart(&mut self, roster: &RosterResponse) {
+        self.depth_chart = DepthChartRow::from_roster(roster);
+        self.depth_chart_selection = TableState::default();
+        if !self.depth_chart.is_empty() {
+            self.depth_chart_selection.select(Some(0));
+        }
+    }
+
+    fn set_roster(&mut self, roster: Vec<RosterRow>) {
+        self.roster = roster;
         let (len, headers, map) = build_roster_row_map(&self.roster);
         self.roster_table_len = len;
         self.roster_header_rows = headers;
@@ -115,7 +153,8 @@ impl TeamPageState {
 
     pub fn next_section(&mut self) {
         self.active_section = match self.active_section {
-            TeamSection::Roster => TeamSection::Schedule,
+            TeamSection::Roster => TeamSection::DepthChart,
+            TeamSection::DepthChart => TeamSection::Schedule,
             TeamSection::Schedule => TeamSection::Transactions,
             TeamSection::Transactions => TeamSection::Roster,
         };
@@ -124,7 +163,8 @@ impl TeamPageState {
     pub fn previous_section(&mut self) {
         self.active_section = match self.active_section {
             TeamSection::Roster => TeamSection::Transactions,
-            TeamSection::Schedule => TeamSection::Roster,
+            TeamSection::DepthChart => TeamSection::Roster,
+            TeamSection::Schedule => TeamSection::DepthChart,
             TeamSection::Transactions => TeamSection::Schedule,
         };
     }
@@ -168,6 +208,7 @@ impl TeamPageState {
     fn active_len(&self) -> usize {
         match self.active_section {
             TeamSection::Roster => self.roster_table_len,
+            TeamSection::DepthChart => self.depth_chart.len(),
             TeamSection::Schedule => self.schedule.len(),
             TeamSection::Transactions => self.transactions.len(),
         }
@@ -198,6 +239,7 @@ impl TeamPageState {
     fn selection(&self) -> usize {
         match self.active_section {
             TeamSection::Roster => self.roster_selection.selected().unwrap_or(0),
+            TeamSection::DepthChart => self.depth_chart_selection.selected().unwrap_or(0),
             TeamSection::Schedule => self.schedule_selection.selected().unwrap_or(0),
             TeamSection::Transactions => self.selected_transaction,
         }
@@ -206,6 +248,7 @@ impl TeamPageState {
     fn set_selection(&mut self, idx: usize) {
         match self.active_section {
             TeamSection::Roster => self.roster_selection.select(Some(idx)),
+            TeamSection::DepthChart => self.depth_chart_selection.select(Some(idx)),
             TeamSection::Schedule => self.schedule_selection.select(Some(idx)),
             TeamSection::Transactions => self.selected_transaction = idx,
         }
@@ -218,6 +261,10 @@ impl TeamPageState {
                 self.roster_selection = TableState::default();
                 self.roster_selection.select(Some(idx));
             }
+            TeamSection::DepthChart => {
+                self.depth_chart_selection = TableState::default();
+                self.depth_chart_selection.select(Some(idx));
+            }
             TeamSection::Schedule => {
                 self.schedule_selection = TableState::default();
                 self.schedule_selection.select(Some(idx));
@@ -237,6 +284,9 @@ impl TeamPageState {
     }
 
     pub fn player_profile_request(&self) -> Option<NetworkRequest> {
+        if let Some(draft) = &self.draft {
+            return draft.player_profile_request(self.date);
+        }
         if self.active_section != TeamSection::Roster {
             return None;
         }
@@ -249,16 +299,31 @@ impl TeamPageState {
         })
     }
 
+    /// Request the next roster view. The view switches once the response arrives.
     pub fn roster_toggle_request(&self) -> NetworkRequest {
-        let roster_type = match self.roster_type {
-            RosterType::Active => RosterType::FortyMan,
-            RosterType::FortyMan => RosterType::Active,
-        };
-        NetworkRequest::TeamRoster {
+        match self.roster_view.next().roster_type() {
+            Some(roster_type) => NetworkRequest::TeamRoster {
+                team_id: self.team.id,
+                season: self.date.year(),
+                roster_type,
+            },
+            None => NetworkRequest::InjuredList {
+                team_id: self.team.id,
+                date: self.date,
+            },
+        }
+    }
+
+    /// Request the depth chart if its section is active and it hasn't been loaded yet.
+    pub fn depth_chart_request(&self) -> Option<NetworkRequest> {
+        if self.active_section != TeamSection::DepthChart || !self.depth_chart.is_empty() {
+            return None;
+        }
+        Some(NetworkRequest::TeamRoster {
             team_id: self.team.id,
             season: self.date.year(),
-            roster_type,
-        }
+            roster_type: RosterType::DepthChart,
+        })
     }
 
     pub fn update_player_profile(
@@ -266,18 +331,36 @@ impl TeamPageState {
         data: Arc<mlbt_api::player::PeopleResponse>,
         game_type: GameType,
     ) {
-        let group = self
-            .selected_roster_row()
-            .map(|r| r.position_group.stat_group())
-            .unwrap_or(StatGroup::Hitting);
+        let group = match &self.draft {
+            Some(draft) => draft.selected().map(|p| p.group),
+            None => self
+                .selected_roster_row()
+                .map(|r| r.position_group.stat_group()),
+        }
+        .unwrap_or(StatGroup::Hitting);
         self.player_profile =
-            PlayerProfileState::from_response(data, group, game_type, self.date.year());
+            PlayerProfileState::from_response(&data, group, game_type, self.date.year());
     }
 
     pub fn has_player_profile(&self) -> bool {
         self.player_profile.is_some()
     }
 
+    /// Request this season's draft for the draft browser.
+    pub fn draft_request(&self) -> NetworkRequest {
+        NetworkRequest::Draft {
+            year: self.date.year(),
+        }
+    }
+
+    /// Open the draft browser, or switch the year of the open one.
+    pub fn update_draft(&mut self, year: i32, response: &DraftResponse) {
+        match &mut self.draft {
+            Some(draft) => draft.update(year, response),
+            None => self.draft = Some(DraftState::from_response(year, self.team.id, response)),
+        }
+    }
+
     /// Update the transaction section scroll offset to keep the selected transaction visible.
     pub fn update_transaction_scroll(&mut self, area_width: u16, area_height: u16) {
         let selected = self.selected_transaction;
@@ -339,6 +422,7 @@ fn build_roster_row_map(roster: &[RosterRow]) -> (usize, HashSet<usize>, Vec<Opt
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::components::game_context::GameContext;
     use crate::components::team_page::PositionGroup;
 
     fn make_roster(groups: &[PositionGroup]) -> Vec<RosterRow> {
@@ -356,6 +440,7 @@ mod tests {
                 dob: String::new(),
                 status: String::new(),
                 status_code: String::new(),
+                injury: None,
             })
             .collect()
     }
@@ -383,12 +468,16 @@ mod tests {
                     start_time_utc: None,
                     is_home: false,
                     is_past: false,
+                    context: GameContext::default(),
+                    series_start: true,
                 };
                 schedule_len
             ],
             schedule_selection,
             roster,
-            roster_type: RosterType::Active,
+            roster_view: RosterView::Active,
+            depth_chart: vec![],
+            depth_chart_selection: TableState::default(),
             transactions: vec![],
             selected_transaction: 0,
             transaction_scroll: 0,
@@ -398,6 +487,7 @@ mod tests {
             roster_header_rows: headers,
             roster_row_map: map,
             player_profile: None,
+            draft: None,
             show_calendar: true,
         }
     }
@@ -525,6 +615,8 @@ mod tests {
         let mut s = nav_state(&[], 0);
         assert_eq!(s.active_section, TeamSection::Roster);
         s.next_section();
+        assert_eq!(s.active_section, TeamSection::DepthChart);
+        s.next_section();
         assert_eq!(s.active_section, TeamSection::Schedule);
         s.next_section();
         assert_eq!(s.active_section, TeamSection::Transactions);
@@ -534,6 +626,55 @@ mod tests {
         assert_eq!(s.active_section, TeamSection::Transactions);
         s.previous_section();
         assert_eq!(s.active_section, TeamSection::Schedule);
+        s.previous_section();
+        assert_eq!(s.active_section, TeamSection::DepthChart);
+    }
+
+    #[test]
+    fn roster_toggle_cycles_views() {
+        let mut s = nav_state(&[], 0);
+        let mut requested = Vec::new();
+        for _ in 0..4 {
+            let request = s.roster_toggle_request();
+            match &request {
+                NetworkRequest::TeamRoster { roster_type, .. } => {
+                    s.update_roster(&RosterResponse::default(), *roster_type)
+                }
+                NetworkRequest::InjuredList { .. } => {
+                    s.update_injured_list(&RosterResponse::default(), &Default::default())
+                }
+                _ => unreachable!(),
+            }
+            requested.push(s.roster_view);
+        }
+        assert_eq!(
+            requested,
+            [
+                RosterView::FortyMan,
+                RosterView::FullSeason,
+                RosterView::Injured,
+                RosterView::Active,
+            ]
+        );
+    }
+
+    #[test]
+    fn depth_chart_requested_once_when_section_entered() {
+        let mut s = nav_state(&[], 0);
+        assert!(s.depth_chart_request().is_none());
+        s.next_section();
+        let Some(NetworkRequest::TeamRoster { roster_type, .. }) = s.depth_chart_request() else {
+            panic!("expected a depth chart request");
+        };
+        assert_eq!(roster_type, RosterType::DepthChart);
+
+        // depth chart data doesn't replace the roster view
+        s.depth_chart = vec![DepthChartRow {
+            position: "SP".to_string(),
+            players: vec!["Skenes".to_string()],
+        }];
+        assert!(s.depth_chart_request().is_none());
+        assert_eq!(s.roster_view, RosterView::Active);
     }
 
     #[test]
@@ -554,6 +695,8 @@ mod tests {
                 ),
                 is_home: false,
                 is_past: false,
+                context: GameContext::default(),
+                series_start: true,
             },
             TeamGame {
                 date: chrono::NaiveDate::default(),
@@ -563,6 +706,8 @@ mod tests {
                 start_time_utc: None,
                 is_home: false,
                 is_past: true,
+                context: GameContext::default(),
+                series_start: true,
             },
         ];
 
@@ -34,10 +34,13 @@ const PITCHING_HEADER: &[&str] = &["pitcher", "ip", "h", "r", "er", "bb", "k", "
 pub struct TeamBatterBoxscoreWidget<'a> {
     pub active: HomeOrAway,
     pub state: &'a mut BoxscoreState,
+    /// Whether the Game Info section is drawn next to the box score.
+    pub game_info_shown: bool,
 }
 
 impl Widget for TeamBatterBoxscoreWidget<'_> {
     fn render(mut self, area: Rect, buf: &mut Buffer) {
+        self.state.set_skip_game_info(self.game_info_shown);
         self.state.sync_scrollbar(area.height, area.width);
 
         if self.state.get_total_content_height() > area.height {
@@ -0,0 +1,88 @@
+use crate::components::constants::lookup_team_by_id;
+use crate::state::draft::DraftState;
+use crate::ui::styling::{border_style, dim_style, header_style, selected_style};
+use tui::prelude::*;
+use tui::widgets::{Block, BorderType, Borders, Cell, Padding, Paragraph, Row, Table};
+
+const HEADER: &[&str] = &["Rd", "Pick", "Team", "Player", "Pos", "School"];
+
+pub struct DraftWidget<'a> {
+    pub state: &'a mut DraftState,
+}
+
+impl Widget for DraftWidget<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let filter = if self.state.team_only {
+            lookup_team_by_id(self.state.team_id)
+                .map(|t| t.name)
+                .unwrap_or_default()
+        } else {
+            "All teams"
+        };
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(border_style())
+            .padding(Padding::new(1, 1, 0, 0))
+            .title(Span::styled(
+                format!(" {} Draft - {filter} ", self.state.year),
+                selected_style(),
+            ))
+            .title_bottom(Line::styled(" ←/→ year, t team/all ", dim_style()).right_aligned());
+        let inner = block.inner(area);
+        block.render(area, buf);
+
+        if self.state.visible.is_empty() {
+            Paragraph::new(Span::styled("  No draft picks", dim_style())).render(inner, buf);
+            return;
+        }
+
+        let widths = [
+            Constraint::Length(4),  // Rd
+            Constraint::Length(4),  // Pick
+            Constraint::Length(5),  // Team
+            Constraint::Length(24), // Player
+            Constraint::Length(4),  // Pos
+            Constraint::Fill(1),    // School
+        ];
+        let header = Row::new(HEADER.iter().map(|h| Cell::from(*h))).style(header_style());
+
+        // only label the first pick of each round so the picks read as grouped by round
+        let mut current_round = None;
+        let picks = &self.state.picks;
+        let rows: Vec<Row> = self
+            .state
+            .visible
+            .iter()
+            .map(|&i| &picks[i])
+            .map(|pick| {
+                let round = if current_round != Some(pick.round.as_str()) {
+                    current_round = Some(pick.round.as_str());
+                    pick.round.as_str()
+                } else {
+                    ""
+                };
+                let name = if pick.player_id.is_some() {
+                    Span::raw(pick.name.as_str())
+                } else {
+                    Span::styled("-", dim_style())
+                };
+                Row::new(vec![
+                    Cell::from(Span::styled(round, Style::default().bold())),
+                    Cell::from(pick.pick_number.to_string()),
+                    Cell::from(pick.team.as_str()),
+                    Cell::from(name),
+                    Cell::from(pick.position.as_str()),
+                    Cell::from(Span::styled(pick.school.as_str(), dim_style())),
+                ])
+            })
+            .collect();
+
+        let table = Table::new(rows, widths)
+            .header(header)
+            .row_highlight_style(selected_style())
+            .column_spacing(1);
+
+        StatefulWidget::render(table, inner, buf, &mut self.state.selection);
+    }
+}
@@ -1,17 +1,22 @@
 use crate::components::game::live_game::GameState;
+use crate::components::game::pitches::Pitch;
 use crate::components::game::strikezone::{
     DEFAULT_SZ_BOT, DEFAULT_SZ_TOP, HOME_PLATE_WIDTH, StrikeZone,
 };
-use crate::ui::styling::border_style;
+use crate::ui::styling::{border_style, dim_style};
 use tui::prelude::*;
-use tui::widgets::canvas::{Canvas, Rectangle};
+use tui::widgets::canvas::{Canvas, Line as CanvasLine, Rectangle};
 use tui::widgets::{Block, Borders, Paragraph, Wrap};
 
 pub struct AtBatWidget<'a> {
     pub game: &'a GameState,
     pub selected_at_bat: Option<u8>,
+    pub selected_pitch: Option<u8>,
 }
 
+/// Movement plot bounds in inches, in both directions.
+const MOVEMENT_BOUNDS: [f64; 2] = [-25.0, 25.0];
+
 impl Widget for AtBatWidget<'_> {
     fn render(self, area: Rect, buf: &mut Buffer) {
         let (at_bat, _is_current) = self
@@ -19,7 +24,8 @@ impl Widget for AtBatWidget<'_> {
             .get_at_bat_by_index_or_current(self.selected_at_bat);
         let pitches = &at_bat.pitches;
 
-        let [kzone, hit, pitch_info] = Layout::vertical([
+        let [head_to_head, kzone, hit, pitch_info] = Layout::vertical([
+            Constraint::Length(1),      // batter vs pitcher
             Constraint::Percentage(65), // heatmap/pitches
             Constraint::Length(1),      // hit stats
             Constraint::Percentage(35), // pitch info
@@ -28,6 +34,14 @@ impl Widget for AtBatWidget<'_> {
         .vertical_margin(1)
         .areas(area);
 
+        let matchup = (at_bat.matchup.batter_id, at_bat.matchup.pitcher_id);
+        if let Some(Some(h2h)) = self.game.head_to_head.get(&matchup) {
+            Paragraph::new(h2h.summary())
+                .alignment(Alignment::Center)
+                .style(dim_style())
+                .render(head_to_head, buf);
+        }
+
         // grab the strike zone from the first pitch since it doesn't change during the at bat.
         let mut strike_zone_bot = DEFAULT_SZ_BOT * 12.0;
         let mut strike_zone_top = DEFAULT_SZ_TOP * 12.0;
@@ -40,6 +54,9 @@ impl Widget for AtBatWidget<'_> {
         }
         let height = strike_zone_top - strike_zone_bot;
         let coords = StrikeZone::build_coords(strike_zone_bot, strike_zone_top);
+        let [kzone, movement] =
+            Layout::horizontal([Constraint::Percentage(60), Constraint::Percentage(40)])
+                .areas(kzone);
         let strike_zone_area = generate_strike_zone_area(kzone);
 
         // strike zone and pitch display
@@ -73,6 +90,14 @@ impl Widget for AtBatWidget<'_> {
             .y_bounds([0.0, 55.0])
             .render(strike_zone_area, buf);
 
+        let tracked: Vec<&Pitch> = pitches
+            .pitches
+            .pitch_events
+            .iter()
+            .filter_map(|event| event.pitch.as_ref())
+            .collect();
+        render_movement(&tracked, self.selected_pitch, movement, buf);
+
         // display the event information
         let events: Vec<Line> = pitches
             .pitches
@@ -80,8 +105,13 @@ impl Widget for AtBatWidget<'_> {
             .iter()
             .rev() // reverse so that the last event is at the top
             .filter_map(|event| {
+                let selected = event
+                    .pitch
+                    .as_ref()
+                    .is_some_and(|pitch| Some(pitch.index) == self.selected_pitch);
                 event.as_lines(
                     false,
+                    selected,
                     &self.game.home_team,
                     &self.game.away_team,
                     &self.game.players,
@@ -109,6 +139,41 @@ impl Widget for AtBatWidget<'_> {
     }
 }
 
+/// Plot how much each pitch moved compared to a spinless pitch, from the catcher's view. Pitches
+/// are labeled with their number and the selected pitch is highlighted.
+fn render_movement(pitches: &[&Pitch], selected: Option<u8>, area: Rect, buf: &mut Buffer) {
+    let [title, plot] = Layout::vertical([Constraint::Length(1), Constraint::Fill(1)]).areas(area);
+    Paragraph::new("movement (in)")
+        .alignment(Alignment::Center)
+        .style(dim_style())
+        .render(title, buf);
+
+    Canvas::default()
+        .paint(|ctx| {
+            let [min, max] = MOVEMENT_BOUNDS;
+            ctx.draw(&CanvasLine::new(min, 0.0, max, 0.0, Color::DarkGray));
+            ctx.draw(&CanvasLine::new(0.0, min, 0.0, max, Color::DarkGray));
+            ctx.layer();
+            for pitch in pitches {
+                let Some((x, z)) = pitch.tracking.movement else {
+                    continue;
+                };
+                let mut style = Style::default().fg(pitch.color);
+                if Some(pitch.index) == selected {
+                    style = style.add_modifier(Modifier::REVERSED | Modifier::BOLD);
+                }
+                ctx.print(
+                    x.clamp(min, max),
+                    z.clamp(min, max),
+                    Span::styled(pitch.index.to_string(), style),
+                );
+            }
+        })
+        .x_bounds(MOVEMENT_BOUNDS)
+        .y_bounds(MOVEMENT_BOUNDS)
+        .render(plot, buf);
+}
+
 fn generate_strike_zone_area(area: Rect) -> Rect {
     // kind of arbitrary values to make the zone look good
     const STRIKE_ZONE_WIDTH: u16 = 35;
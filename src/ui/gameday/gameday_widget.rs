@@ -3,6 +3,7 @@ use crate::draw;
 use crate::state::app_state::HomeOrAway;
 use crate::state::boxscore::BoxscoreState;
 use crate::state::gameday::GamedayState;
+use crate::state::recap::RecapState;
 use crate::ui::boxscore::TeamBatterBoxscoreWidget;
 use crate::ui::gameday::at_bat::AtBatWidget;
 use crate::ui::gameday::matchup::MatchupWidget;
@@ -10,20 +11,26 @@ use crate::ui::gameday::plays::InningPlaysWidget;
 use crate::ui::gameday::win_probability::WinProbabilityWidget;
 use crate::ui::layout::LayoutAreas;
 use crate::ui::linescore::LineScoreWidget;
+use crate::ui::recap::RecapWidget;
+use crate::ui::spray_chart::SprayChartWidget;
 use crate::ui::styling::selected_style;
+use chrono_tz::Tz;
 use tui::prelude::{Buffer, Rect, Span, Widget};
+use tui::widgets::{Paragraph, Wrap};
 
 pub struct GamedayWidget<'a> {
     pub state: &'a mut GamedayState,
     pub boxscore_state: &'a mut BoxscoreState,
+    pub recap_state: &'a mut RecapState,
     pub active: HomeOrAway,
+    pub timezone: Tz,
 }
 
 impl Widget for GamedayWidget<'_> {
     fn render(self, area: Rect, buf: &mut Buffer) {
         let mut panels = LayoutAreas::generate_gameday_panels(&self.state.panels, area);
 
-        // I want the panels to be displayed [Info, Heat, Box] from left to right. So pop off
+        // I want the panels to be displayed [Info, Heat, Spray, Recap, Box] from left to right. So pop off
         // available panels starting with Box. Since `generate_layouts` takes into account how many
         // panels are active, all the pops are guaranteed to unwrap.
         if self.state.panels.boxscore {
@@ -40,9 +47,31 @@ impl Widget for GamedayWidget<'_> {
             let boxscore_widget = TeamBatterBoxscoreWidget {
                 active: self.active,
                 state: self.boxscore_state,
+                game_info_shown: self.state.panels.info && self.state.panels.game_info,
             };
             Widget::render(boxscore_widget, chunks[1], buf);
         }
+        if self.state.panels.recap {
+            let p = panels.pop().unwrap();
+            Self::draw_border(p, buf);
+            let recap_widget = RecapWidget {
+                state: self.recap_state,
+                game_id: self.state.current_game_id(),
+                is_final: self.state.is_final(),
+            };
+            Widget::render(recap_widget, LayoutAreas::for_recap(p), buf);
+        }
+        if self.state.panels.spray_chart {
+            let p = panels.pop().unwrap();
+            Self::draw_border(p, buf);
+            let (batter_id, balls) = self.state.spray_chart_balls();
+            let title = match batter_id.and_then(|id| self.state.game.players.get(&id)) {
+                Some(batter) => format!("{} balls in play", batter.last_name),
+                None => "all balls in play".to_string(),
+            };
+            let spray_chart_widget = SprayChartWidget { title, balls };
+            Widget::render(spray_chart_widget, LayoutAreas::for_spray_chart(p), buf);
+        }
         if self.state.panels.at_bat {
             let p = panels.pop().unwrap();
             Self::draw_border(p, buf);
@@ -57,6 +86,7 @@ impl Widget for GamedayWidget<'_> {
             let at_bat_widget = AtBatWidget {
                 game: &self.state.game,
                 selected_at_bat: self.state.selected_at_bat(),
+                selected_pitch: self.state.selected_pitch(),
             };
             Widget::render(at_bat_widget, at_bat, buf);
         }
@@ -67,7 +97,22 @@ impl Widget for GamedayWidget<'_> {
             } else {
                 Self::draw_border(p, buf);
             };
-            let chunks = LayoutAreas::for_info(p, self.state.panels.win_probability);
+            let game_info = match self.state.panels.game_info {
+                true => Paragraph::new(self.state.game.info.lines(self.timezone))
+                    .wrap(Wrap { trim: true }),
+                false => Paragraph::default(),
+            };
+            // the layout's horizontal margin is 2 on each side
+            let game_info_height = match self.state.panels.game_info {
+                true => game_info.line_count(p.width.saturating_sub(4)) as u16,
+                false => 0,
+            };
+            let mut chunks =
+                LayoutAreas::for_info(p, game_info_height, self.state.panels.win_probability)
+                    .into_iter();
+            if game_info_height > 0 {
+                Widget::render(game_info, chunks.next().unwrap(), buf);
+            }
 
             let innings_widget = InningPlaysWidget {
                 game: &self.state.game,
@@ -76,7 +121,7 @@ impl Widget for GamedayWidget<'_> {
                 scroll_offset: &mut self.state.plays_scroll_offset,
                 scroll_state: &mut self.state.plays_scroll_state,
             };
-            Widget::render(innings_widget, chunks[0], buf);
+            Widget::render(innings_widget, chunks.next().unwrap(), buf);
 
             if self.state.panels.win_probability {
                 let wps_widget = WinProbabilityWidget {
@@ -84,7 +129,7 @@ impl Widget for GamedayWidget<'_> {
                     selected_at_bat: self.state.selected_at_bat(),
                     active_tab: MenuItem::Gameday,
                 };
-                Widget::render(wps_widget, chunks[1], buf);
+                Widget::render(wps_widget, chunks.next().unwrap(), buf);
             }
         }
     }
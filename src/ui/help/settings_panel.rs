@@ -122,6 +122,7 @@ pub fn render_picker(picker: &PickerState, full_area: Rect, buf: &mut Buffer) {
         SettingsField::Timezone => " Timezone ",
         SettingsField::LogLevel => " Log level ",
         SettingsField::AutoAdvanceDate => " Auto-advance date ",
+        SettingsField::BroadcastLanguage => " Broadcast language ",
     };
 
     // Size the popup: width wide enough for the longest label + borders + padding, height capped so
@@ -1,6 +1,6 @@
 use crate::state::gameday::GamedayPanels;
 use crate::ui::input_popup;
-use tui::layout::{Constraint, Layout, Rect, Size};
+use tui::layout::{Constraint, Layout, Margin, Rect, Size};
 
 pub struct LayoutAreas {
     pub top_bar: [Rect; 2],
@@ -71,33 +71,40 @@ impl LayoutAreas {
         .areas(rect)
     }
 
-    /// Create two splits for displaying game info for the current inning and the recent win
-    /// probability. This is used in the `gameday` tab.
-    pub fn for_info(rect: Rect, show_win_probability: bool) -> Vec<Rect> {
-        let constraints = match show_win_probability {
-            true => vec![Constraint::Fill(1), Constraint::Length(6)],
-            false => vec![Constraint::Fill(1)],
-        };
+    /// Inner area of the spray chart panel in the `gameday` tab.
+    pub fn for_spray_chart(rect: Rect) -> Rect {
+        rect.inner(Margin::new(2, 1))
+    }
+
+    /// Inner area of the recap panel in the `gameday` tab and the box score area of the
+    /// `scoreboard` tab.
+    pub fn for_recap(rect: Rect) -> Rect {
+        rect.inner(Margin::new(2, 1))
+    }
+
+    /// Create splits for displaying the game info section, the plays for the current inning and the
+    /// recent win probability. A `game_info_height` of zero leaves out the game info section. This
+    /// is used in the `gameday` tab.
+    pub fn for_info(rect: Rect, game_info_height: u16, show_win_probability: bool) -> Vec<Rect> {
+        let mut constraints = vec![Constraint::Fill(1)];
+        if game_info_height > 0 {
+            constraints.insert(0, Constraint::Length(game_info_height));
+        }
+        if show_win_probability {
+            constraints.push(Constraint::Length(6));
+        }
         Layout::vertical(constraints)
             .horizontal_margin(2)
             .vertical_margin(1)
-            .spacing(1) // one row of padding between the plays and the win probability chart
+            .spacing(1) // one row of padding between each section
             .split(rect)
             .to_vec()
     }
 
     /// Create the Gameday layouts based on how many of the panels are active.
     pub fn generate_gameday_panels(active: &GamedayPanels, area: Rect) -> Vec<Rect> {
-        let constraints = match active.count() {
-            0 | 1 => vec![Constraint::Percentage(100)],
-            2 => vec![Constraint::Ratio(1, 2), Constraint::Ratio(1, 2)],
-            3 => vec![
-                Constraint::Ratio(1, 3),
-                Constraint::Ratio(1, 3),
-                Constraint::Ratio(1, 3),
-            ],
-            _ => vec![],
-        };
+        let count = active.count().max(1) as u32;
+        let constraints = vec![Constraint::Ratio(1, count); count as usize];
         Layout::horizontal(constraints.as_slice())
             .split(area)
             .to_vec()
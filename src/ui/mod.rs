@@ -2,6 +2,7 @@ pub(crate) mod boxscore;
 pub(crate) mod date_selector;
 pub(crate) mod debug;
 pub(crate) mod decision_pitchers;
+pub(crate) mod draft;
 pub(crate) mod gameday;
 pub(crate) mod help;
 pub(crate) mod input_popup;
@@ -9,9 +10,12 @@ pub(crate) mod layout;
 pub(crate) mod linescore;
 pub(crate) mod logs;
 pub(crate) mod player_profile;
+pub(crate) mod player_search;
 pub(crate) mod probable_pitchers;
+pub(crate) mod recap;
 pub(crate) mod schedule;
 pub(crate) mod scroll;
+pub(crate) mod spray_chart;
 pub(crate) mod standings;
 pub(crate) mod stats;
 pub mod styling;
@@ -1,6 +1,7 @@
 use crate::components::stats::player_profile::PlayerProfile;
 use crate::state::player_profile::PlayerProfileState;
 use crate::ui::scroll::{ScrollParams, adjust_area_for_scroll, render_scrollbar};
+use crate::ui::spray_chart::SprayChartWidget;
 use crate::ui::styling::{border_style, dim_style, selected_style};
 use mlbt_api::client::StatGroup;
 use mlbt_api::season::GameType;
@@ -42,6 +43,11 @@ impl Widget for PlayerProfileWidget<'_> {
             return;
         }
 
+        if self.state.show_spray_chart {
+            self.render_spray_chart(inner, buf);
+            return;
+        }
+
         let section_heights = self.state.section_heights();
         let total_content_height = section_heights.iter().sum::<u16>();
         self.state.content_height = total_content_height;
@@ -51,6 +57,7 @@ impl Widget for PlayerProfileWidget<'_> {
             let [
                 bio_area,
                 season_area,
+                arsenal_area,
                 splits_area,
                 career_area,
                 gamelog_area,
@@ -58,6 +65,7 @@ impl Widget for PlayerProfileWidget<'_> {
 
             self.render_bio(bio_area, 0, buf);
             self.render_season(season_area, 0, buf);
+            self.render_arsenal(arsenal_area, 0, buf);
             self.render_splits(splits_area, 0, buf);
             self.render_career(career_area, 0, buf);
             self.render_game_log(gamelog_area, 0, buf);
@@ -75,6 +83,7 @@ impl Widget for PlayerProfileWidget<'_> {
         let [
             bio_area,
             season_area,
+            arsenal_area,
             splits_area,
             career_area,
             gamelog_area,
@@ -92,6 +101,9 @@ impl Widget for PlayerProfileWidget<'_> {
         if let Some((area, skip)) = adjust_area_for_scroll(season_area, params) {
             self.render_season(area, skip, buf);
         }
+        if let Some((area, skip)) = adjust_area_for_scroll(arsenal_area, params) {
+            self.render_arsenal(area, skip, buf);
+        }
         if let Some((area, skip)) = adjust_area_for_scroll(splits_area, params) {
             self.render_splits(area, skip, buf);
         }
@@ -122,17 +134,35 @@ impl PlayerProfileWidget<'_> {
         let selected = selected_style();
         let normal = dim_style();
 
-        let (reg_style, st_style) = match self.state.game_type {
-            GameType::RegularSeason => (selected, normal),
-            GameType::SpringTraining => (normal, selected),
+        let selected_row = match self.state.game_type {
+            GameType::RegularSeason => Some(0),
+            GameType::SpringTraining => Some(1),
+            GameType::WildCard
+            | GameType::DivisionSeries
+            | GameType::LeagueChampionshipSeries
+            | GameType::WorldSeries => Some(2),
+            // profiles aren't opened with these and the toggle doesn't cycle through them
+            GameType::Exhibition | GameType::AllStar => None,
         };
-
-        Paragraph::new(vec![
-            Line::from(Span::styled(" Regular Season  ", reg_style)),
-            Line::from(Span::styled(" Spring Training ", st_style)),
-        ])
-        .alignment(Alignment::Right)
-        .render(area, buf);
+        let lines: Vec<Line> = [
+            " Regular Season  ",
+            " Spring Training ",
+            " Postseason      ",
+        ]
+        .into_iter()
+        .enumerate()
+        .map(|(row, label)| {
+            let style = if selected_row == Some(row) {
+                selected
+            } else {
+                normal
+            };
+            Line::from(Span::styled(label, style))
+        })
+        .collect();
+        Paragraph::new(lines)
+            .alignment(Alignment::Right)
+            .render(area, buf);
     }
 
     fn render_season(&self, area: Rect, skip: u16, buf: &mut Buffer) {
@@ -171,6 +201,36 @@ impl PlayerProfileWidget<'_> {
         }
     }
 
+    fn render_arsenal(&self, area: Rect, skip: u16, buf: &mut Buffer) {
+        let splits = &self.state.profile.splits.pitch_arsenal;
+        if let Some((header, widths, rows)) = PlayerProfile::build_arsenal_rows(splits) {
+            let title = format!("{} Pitch Arsenal", self.state.season_year);
+            render_table_with_title(&title, header, widths, rows, area, skip, buf);
+        }
+    }
+
+    fn render_spray_chart(&self, area: Rect, buf: &mut Buffer) {
+        let Some(balls) = &self.state.spray_chart else {
+            Paragraph::new(Span::styled("Loading spray chart...", dim_style()))
+                .alignment(Alignment::Center)
+                .render(area, buf);
+            return;
+        };
+        let allowed = match self.state.stat_group {
+            StatGroup::Pitching => " allowed",
+            _ => "",
+        };
+        let title = format!(
+            "Balls in play{allowed}, last {} games",
+            self.state.spray_chart_games
+        );
+        SprayChartWidget {
+            title,
+            balls: balls.iter().collect(),
+        }
+        .render(area, buf);
+    }
+
     fn render_game_log(&self, area: Rect, skip: u16, buf: &mut Buffer) {
         let splits = &self.state.profile.splits.game_log;
         if let Some((header, widths, rows)) = PlayerProfile::build_game_log_rows(splits) {
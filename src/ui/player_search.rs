@@ -0,0 +1,90 @@
+use crate::state::player_search::PlayerSearchState;
+use crate::ui::input_popup::InputPopup;
+use crate::ui::styling::{dim_style, selected_style};
+use tui::prelude::*;
+
+/// Maximum number of result rows shown at once.
+const MAX_VISIBLE_RESULTS: usize = 10;
+/// Borders, instructions and input.
+const INPUT_HEIGHT: u16 = 4;
+const NAME_WIDTH: usize = 26;
+
+pub struct PlayerSearchWidget<'a> {
+    pub state: &'a PlayerSearchState,
+}
+
+impl PlayerSearchWidget<'_> {
+    /// Popup height including the result rows or the status line.
+    pub fn height(state: &PlayerSearchState) -> u16 {
+        let rows = match &state.results {
+            Some(results) if !results.is_empty() => results.len().min(MAX_VISIBLE_RESULTS),
+            _ if state.searched_query.is_some() => 1,
+            _ => 0,
+        };
+        INPUT_HEIGHT + rows as u16
+    }
+}
+
+impl Widget for PlayerSearchWidget<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let info = match (&self.state.results, self.state.active_only) {
+            (Some(results), true) => format!("{} found, active only", results.len()),
+            (Some(results), false) => format!("{} found", results.len()),
+            (None, true) => "active only".to_string(),
+            (None, false) => "all players".to_string(),
+        };
+        InputPopup {
+            title: "Search players",
+            instructions: "Enter to search/open, Tab to toggle active, Esc to cancel",
+            input_text: &self.state.input,
+            border_color: Color::Blue,
+            info: Some(&info),
+        }
+        .render(area, buf);
+
+        let results_area = Rect {
+            x: area.x + 1,
+            y: area.y + INPUT_HEIGHT - 1,
+            width: area.width.saturating_sub(2),
+            height: area.height.saturating_sub(INPUT_HEIGHT),
+        };
+        Text::from(self.lines()).render(results_area, buf);
+    }
+}
+
+impl PlayerSearchWidget<'_> {
+    fn lines(&self) -> Vec<Line<'static>> {
+        let results = match &self.state.results {
+            Some(results) if !results.is_empty() => results,
+            Some(_) => return vec![Line::styled(" No players found", dim_style())],
+            None if self.state.is_searching() => {
+                return vec![Line::styled(" Searching...", dim_style())];
+            }
+            None => return vec![],
+        };
+
+        // keep the selected row in view
+        let start = self.state.selected.saturating_sub(MAX_VISIBLE_RESULTS - 1);
+        results
+            .iter()
+            .enumerate()
+            .skip(start)
+            .take(MAX_VISIBLE_RESULTS)
+            .map(|(idx, result)| {
+                let team = result.team.as_deref().unwrap_or("");
+                let text = format!(
+                    " {:<NAME_WIDTH$} {:<3} {}",
+                    result.name, result.position, team
+                );
+                let style = if idx == self.state.selected {
+                    selected_style()
+                } else if !result.active {
+                    dim_style()
+                } else {
+                    Style::default()
+                };
+                Line::styled(text, style)
+            })
+            .collect()
+    }
+}
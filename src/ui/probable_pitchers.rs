@@ -1,13 +1,17 @@
+use crate::components::broadcasts::{GameBroadcast, filter_broadcasts};
 use crate::components::probable_pitchers::ProbablePitcherMatchup;
 use crate::components::schedule::ScheduleRow;
-use crate::ui::styling::{border_style, header_style, selected_style};
+use crate::config::BroadcastLanguage;
+use crate::ui::styling::{border_style, dim_style, header_style, selected_style};
 use tui::prelude::*;
-use tui::widgets::{Block, BorderType, Borders, Padding, Row, Table};
+use tui::widgets::{Block, BorderType, Borders, Padding, Paragraph, Row, Table, Wrap};
 
 const HEADER: [&str; 8] = ["", "", "W", "L", "ERA", "IP", "K", "BB"];
 
 pub struct ProbablePitchersWidget<'a> {
     pub matchup: ProbablePitcherMatchup<'a>,
+    pub broadcast_language: BroadcastLanguage,
+    pub preferred_broadcasters: &'a [String],
 }
 
 impl Widget for ProbablePitchersWidget<'_> {
@@ -46,17 +50,86 @@ impl Widget for ProbablePitchersWidget<'_> {
             Constraint::Length(5),
         ];
 
-        let table = Table::new(vec![away_row, home_row], widths)
-            .header(header)
-            .block(
-                Block::default()
-                    .borders(Borders::ALL)
-                    .border_type(BorderType::Rounded)
-                    .border_style(border_style())
-                    .padding(Padding::new(1, 1, 0, 0))
-                    .title(Span::styled(" Probable Pitchers ", selected_style())),
-            );
-
-        Widget::render(table, area, buf);
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(border_style())
+            .padding(Padding::new(1, 1, 0, 0))
+            .title(Span::styled(" Probable Pitchers ", selected_style()));
+        let inner = block.inner(area);
+        block.render(area, buf);
+
+        // header and a row per team, then the broadcasts below a blank line
+        let [pitchers_area, _, broadcasts_area] = Layout::vertical([
+            Constraint::Length(3),
+            Constraint::Length(1),
+            Constraint::Fill(1),
+        ])
+        .areas(inner);
+
+        let table = Table::new(vec![away_row, home_row], widths).header(header);
+        Widget::render(table, pitchers_area, buf);
+
+        let broadcasts = filter_broadcasts(
+            self.matchup.broadcasts,
+            self.broadcast_language,
+            self.preferred_broadcasters,
+        );
+        Paragraph::new(self.broadcast_lines(&broadcasts, use_abbreviation))
+            .wrap(Wrap { trim: false })
+            .render(broadcasts_area, buf);
+    }
+}
+
+impl ProbablePitchersWidget<'_> {
+    /// A TV line and a radio line, each listing the broadcasts with the team they're for.
+    fn broadcast_lines(
+        &self,
+        broadcasts: &[&GameBroadcast],
+        use_abbreviation: bool,
+    ) -> Vec<Line<'static>> {
+        if broadcasts.is_empty() {
+            return vec![Line::styled("No broadcasts listed", dim_style())];
+        }
+        let team = |b: &GameBroadcast| {
+            let team = if b.is_home {
+                self.matchup.home_team
+            } else {
+                self.matchup.away_team
+            };
+            if b.is_national {
+                "national"
+            } else if use_abbreviation {
+                team.abbreviation
+            } else {
+                team.team_name
+            }
+        };
+
+        [("TV", false), ("Radio", true)]
+            .into_iter()
+            .filter_map(|(label, is_radio)| {
+                let listed: Vec<&&GameBroadcast> = broadcasts
+                    .iter()
+                    .filter(|b| b.is_radio == is_radio)
+                    .collect();
+                if listed.is_empty() {
+                    return None;
+                }
+                let mut spans = vec![Span::styled(format!("{label:<7}"), header_style())];
+                for (i, b) in listed.into_iter().enumerate() {
+                    if i > 0 {
+                        spans.push(Span::styled(" · ", dim_style()));
+                    }
+                    spans.push(Span::raw(b.name.clone()));
+                    let mut note = team(b).to_string();
+                    if b.language != "en" {
+                        note = format!("{note}, {}", b.language);
+                    }
+                    spans.push(Span::styled(format!(" ({note})"), dim_style()));
+                }
+                Some(Line::from(spans))
+            })
+            .collect()
     }
 }
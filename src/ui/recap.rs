@@ -0,0 +1,59 @@
+use crate::state::recap::RecapState;
+use crate::ui::scroll::render_scrollbar;
+use crate::ui::styling::dim_style;
+use tui::prelude::*;
+use tui::widgets::{Paragraph, Wrap};
+
+/// The selected game's recap, or its preview if the game isn't final.
+pub struct RecapWidget<'a> {
+    pub state: &'a mut RecapState,
+    pub game_id: u64,
+    pub is_final: bool,
+}
+
+impl Widget for RecapWidget<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let Some(story) = self.state.story(self.game_id, self.is_final) else {
+            let message = match (
+                self.state.is_loading(self.game_id, self.is_final),
+                self.is_final,
+            ) {
+                (true, _) => "loading...",
+                (false, true) => "no recap available",
+                (false, false) => "no preview available",
+            };
+            Paragraph::new(message)
+                .alignment(Alignment::Center)
+                .style(dim_style())
+                .render(area, buf);
+            return;
+        };
+
+        let mut lines = vec![
+            Line::styled(story.kind.label(), dim_style()),
+            Line::styled(
+                story.headline.clone(),
+                Style::default().add_modifier(Modifier::BOLD),
+            ),
+        ];
+        if let Some(subhead) = &story.subhead {
+            lines.push(Line::styled(
+                subhead.clone(),
+                Style::default().add_modifier(Modifier::ITALIC),
+            ));
+        }
+        for paragraph in &story.paragraphs {
+            lines.push(Line::default());
+            lines.push(Line::raw(paragraph.clone()));
+        }
+
+        let paragraph = Paragraph::new(lines).wrap(Wrap { trim: true });
+        let content_height = paragraph.line_count(area.width) as u16;
+        self.state.sync_scrollbar(content_height, area.height);
+        paragraph.scroll((self.state.scroll, 0)).render(area, buf);
+
+        if content_height > area.height {
+            render_scrollbar(area, &mut self.state.scroll_state, buf);
+        }
+    }
+}
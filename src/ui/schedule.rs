@@ -28,6 +28,14 @@ impl ScheduleRow {
         format!("{s:<3}")
     }
 
+    /// The game status followed by the series, doubleheader and suspension context.
+    fn status_with_context(&self) -> String {
+        let mut parts = vec![self.game_status.clone()];
+        parts.extend(self.context.labels());
+        parts.retain(|p| !p.is_empty());
+        parts.join(" · ")
+    }
+
     fn get_styles(&self, team: HomeOrAway) -> (Style, Style) {
         let winning_team = self.winning_team();
         let lose_style = dim_style();
@@ -57,8 +65,12 @@ impl ScheduleRow {
             Span::styled(home_team, home_team_style),
             Span::styled(home_record, home_team_style),
             Span::styled(Self::default_score(self.home_score), home_score_style),
-            Span::raw(self.start_time.to_string()),
-            Span::raw(self.game_status.to_string()),
+            Span::raw(format!(
+                "{} {}",
+                self.start_time,
+                self.context.day_night_symbol()
+            )),
+            Span::raw(self.status_with_context()),
         ]
     }
 }
@@ -109,10 +121,10 @@ impl StatefulWidget for ScheduleWidget {
         let title = if state.sort_mode == SortMode::GameStatus {
             format!(
                 "{} [status] ",
-                state.date_selector.format_date_border_title(),
+                state.date_selector.format_date_border_title(state.sport),
             )
         } else {
-            state.date_selector.format_date_border_title()
+            state.date_selector.format_date_border_title(state.sport)
         };
         let t = Table::new(rows, widths)
             .header(header)
@@ -0,0 +1,129 @@
+use crate::components::game::spray_chart::{BattedBall, HitOutcome};
+use crate::ui::styling::dim_style;
+use tui::prelude::*;
+use tui::widgets::Paragraph;
+use tui::widgets::canvas::{Canvas, Context, Line as CanvasLine};
+
+/// Field bounds in feet from home plate.
+const X_BOUNDS: [f64; 2] = [-260.0, 260.0];
+const Y_BOUNDS: [f64; 2] = [-20.0, 440.0];
+/// Distance to the fence down the lines and to center field.
+const FENCE_LINE: f64 = 330.0;
+const FENCE_CENTER: f64 = 400.0;
+const BASE_DISTANCE: f64 = 90.0;
+const FIELD_COLOR: Color = Color::DarkGray;
+const BALL_SYMBOL: &str = "•";
+
+/// Draws a baseball field with balls in play colored by outcome.
+pub struct SprayChartWidget<'a> {
+    pub title: String,
+    pub balls: Vec<&'a BattedBall>,
+}
+
+impl Widget for SprayChartWidget<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let [title, field, legend] = Layout::vertical([
+            Constraint::Length(1),
+            Constraint::Fill(1),
+            Constraint::Length(1),
+        ])
+        .areas(area);
+
+        Paragraph::new(self.title)
+            .alignment(Alignment::Center)
+            .render(title, buf);
+
+        if self.balls.is_empty() {
+            Paragraph::new("no balls in play")
+                .alignment(Alignment::Center)
+                .style(dim_style())
+                .render(field, buf);
+        } else {
+            Canvas::default()
+                .paint(|ctx| {
+                    draw_field(ctx);
+                    for ball in &self.balls {
+                        ctx.print(
+                            ball.x,
+                            ball.y,
+                            Span::styled(BALL_SYMBOL, Style::default().fg(ball.outcome.color())),
+                        );
+                    }
+                })
+                .x_bounds(X_BOUNDS)
+                .y_bounds(Y_BOUNDS)
+                .render(fit_field_area(field), buf);
+        }
+
+        let spans: Vec<Span> = HitOutcome::ALL
+            .iter()
+            .flat_map(|outcome| {
+                [
+                    Span::styled(BALL_SYMBOL, Style::default().fg(outcome.color())),
+                    Span::raw(format!(" {}  ", outcome.label())),
+                ]
+            })
+            .collect();
+        Paragraph::new(Line::from(spans))
+            .alignment(Alignment::Center)
+            .render(legend, buf);
+    }
+}
+
+/// Foul lines, the outfield fence and the infield diamond.
+fn draw_field(ctx: &mut Context) {
+    let line = |x1, y1, x2, y2| CanvasLine {
+        x1,
+        y1,
+        x2,
+        y2,
+        color: FIELD_COLOR,
+    };
+
+    // foul lines run at 45 degrees out to the fence
+    let foul = FENCE_LINE / 2f64.sqrt();
+    ctx.draw(&line(0.0, 0.0, -foul, foul));
+    ctx.draw(&line(0.0, 0.0, foul, foul));
+
+    // fence from the left field line to the right field line, deepest in center
+    const FENCE_SEGMENTS: usize = 24;
+    let fence_point = |i: usize| {
+        let angle = 135.0 - 90.0 * i as f64 / FENCE_SEGMENTS as f64;
+        let off_center = (angle - 90.0) / 45.0;
+        let distance = FENCE_CENTER - (FENCE_CENTER - FENCE_LINE) * off_center * off_center;
+        let radians = f64::to_radians(angle);
+        (distance * radians.cos(), distance * radians.sin())
+    };
+    for i in 0..FENCE_SEGMENTS {
+        let (x1, y1) = fence_point(i);
+        let (x2, y2) = fence_point(i + 1);
+        ctx.draw(&line(x1, y1, x2, y2));
+    }
+
+    let base = BASE_DISTANCE / 2f64.sqrt();
+    let diamond = [(0.0, 0.0), (base, base), (0.0, 2.0 * base), (-base, base)];
+    for (i, &(x1, y1)) in diamond.iter().enumerate() {
+        let (x2, y2) = diamond[(i + 1) % diamond.len()];
+        ctx.draw(&line(x1, y1, x2, y2));
+    }
+}
+
+/// Shrink the area so the field isn't stretched. Terminal cells are about twice as tall as they
+/// are wide.
+fn fit_field_area(area: Rect) -> Rect {
+    let field_ratio = (X_BOUNDS[1] - X_BOUNDS[0]) / (Y_BOUNDS[1] - Y_BOUNDS[0]);
+    let cell_ratio = 2.0 * field_ratio;
+
+    let width_constrained_height = (area.width as f64 / cell_ratio) as u16;
+    let (width, height) = if width_constrained_height <= area.height {
+        (area.width, width_constrained_height)
+    } else {
+        ((area.height as f64 * cell_ratio) as u16, area.height)
+    };
+    Rect::new(
+        area.x + (area.width - width) / 2,
+        area.y + (area.height - height) / 2,
+        width,
+        height,
+    )
+}
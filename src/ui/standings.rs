@@ -1,7 +1,8 @@
+use crate::components::bracket::{Bracket, BracketRound, SeriesTeam};
 use crate::components::standings::{StandingsState, ViewMode};
-use crate::ui::styling::{border_style, header_style, selected_style};
+use crate::ui::styling::{border_style, dim_style, header_style, selected_style, text_style};
 use tui::prelude::*;
-use tui::widgets::{Block, BorderType, Borders, Cell, Padding, Row, Table};
+use tui::widgets::{Block, BorderType, Borders, Cell, Padding, Paragraph, Row, Table};
 
 const HEADER: &[&str] = &[
     "Team", "W", "L", "PCT", "GB", "WCGB", "L10", "STRK", "RS", "RA", "DIFF", "X-W/L", "HOME",
@@ -66,7 +67,7 @@ impl StatefulWidget for StandingsWidget {
                     .border_style(border_style())
                     .padding(Padding::new(1, 1, 0, 0))
                     .title(Span::styled(
-                        state.date_selector.format_date_border_title(),
+                        state.date_selector.format_date_border_title(state.sport),
                         selected_style(),
                     )),
             )
@@ -75,3 +76,72 @@ impl StatefulWidget for StandingsWidget {
         StatefulWidget::render(t, area, buf, &mut state.state);
     }
 }
+
+/// Draws the postseason bracket as one column per round.
+pub struct BracketWidget<'a> {
+    pub bracket: Option<&'a Bracket>,
+    pub season: i32,
+}
+
+impl Widget for BracketWidget<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(border_style())
+            .padding(Padding::new(1, 1, 0, 0))
+            .title(Span::styled(
+                format!(" {} Postseason ", self.season),
+                selected_style(),
+            ));
+        let inner = block.inner(area);
+        block.render(area, buf);
+
+        let Some(bracket) = self.bracket.filter(|b| !b.is_empty()) else {
+            Paragraph::new(format!("No postseason games for {}", self.season))
+                .style(dim_style())
+                .render(inner, buf);
+            return;
+        };
+
+        let columns = Layout::horizontal(
+            bracket
+                .rounds
+                .iter()
+                .map(|_| Constraint::Length(ROUND_WIDTH)),
+        )
+        .spacing(2)
+        .split(inner);
+        for (round, column) in bracket.rounds.iter().zip(columns.iter()) {
+            Paragraph::new(round_lines(round)).render(*column, buf);
+        }
+    }
+}
+
+const ROUND_WIDTH: u16 = 26;
+
+fn round_lines(round: &BracketRound) -> Vec<Line<'static>> {
+    let mut lines = vec![Line::styled(round.game_type.to_string(), header_style())];
+    for matchup in &round.matchups {
+        lines.push(Line::default());
+        if !matchup.league.is_empty() {
+            lines.push(Line::styled(matchup.league, dim_style()));
+        }
+        let eliminated = matchup.teams.iter().any(|t| t.clinched);
+        for team in &matchup.teams {
+            lines.push(series_team_line(team, eliminated));
+        }
+    }
+    lines
+}
+
+fn series_team_line(team: &SeriesTeam, series_over: bool) -> Line<'static> {
+    let style = if team.clinched {
+        text_style().bold()
+    } else if series_over {
+        dim_style()
+    } else {
+        text_style()
+    };
+    Line::styled(format!("{:<21}{:>2}", team.team.name, team.wins), style)
+}
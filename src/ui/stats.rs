@@ -1,3 +1,4 @@
+use crate::components::stats::leaders::{CategoryLeaderboard, LEADERS_LIMIT, Leaders};
 use crate::components::stats::table::TeamOrPlayer;
 use crate::components::stats::{STATS_DEFAULT_COL_WIDTH, STATS_FIRST_COL_WIDTH};
 use crate::state::stats::{ActivePane, StatsState};
@@ -94,7 +95,7 @@ impl StatefulWidget for StatsDataWidget {
                     .border_style(border_style())
                     .padding(Padding::new(1, 1, 0, 0))
                     .title(Span::styled(
-                        state.date_selector.format_date_border_title(),
+                        state.date_selector.format_date_border_title(state.sport),
                         selected_style(),
                     )),
             );
@@ -117,14 +118,18 @@ impl StatefulWidget for StatsOptionsWidget {
 
     fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
         let [stats_rect, options_rect] =
-            Layout::vertical([Constraint::Length(5), Constraint::Percentage(100)]).areas(area);
-
-        // hitting | pitching
-        //    team | player
-        //     all | qualified
-        let (hitting_style, pitching_style) = match state.stat_type.group {
-            StatGroup::Pitching => (Style::default(), selected_style()),
-            StatGroup::Hitting => (selected_style(), Style::default()),
+            Layout::vertical([Constraint::Length(6), Constraint::Percentage(100)]).areas(area);
+
+        //  hitting | pitching
+        // fielding  catching  running
+        //     team | player
+        //      all | qualified
+        let group_style = |group: StatGroup| {
+            if state.stat_type.group == group {
+                selected_style()
+            } else {
+                Style::default()
+            }
         };
         let (team_style, player_style) = match state.stat_type.team_player {
             TeamOrPlayer::Player => (Style::default(), selected_style()),
@@ -163,7 +168,8 @@ impl StatefulWidget for StatsOptionsWidget {
         .areas(inner);
 
         Paragraph::new(vec![
-            Line::from(Span::styled("hitting", hitting_style)),
+            Line::from(Span::styled("hitting", group_style(StatGroup::Hitting))),
+            Line::default(),
             Line::from(Span::styled("team", team_style)),
             Line::from(Span::styled("all", all_style)),
         ])
@@ -172,6 +178,7 @@ impl StatefulWidget for StatsOptionsWidget {
 
         Paragraph::new(vec![
             Line::from(" | "),
+            Line::default(),
             Line::from(" | "),
             Line::from(" | ").style(dim_if_team),
         ])
@@ -179,13 +186,31 @@ impl StatefulWidget for StatsOptionsWidget {
         .render(divider_area, buf);
 
         Paragraph::new(vec![
-            Line::from(Span::styled("pitching", pitching_style)),
+            Line::from(Span::styled("pitching", group_style(StatGroup::Pitching))),
+            Line::default(),
             Line::from(Span::styled("player", player_style)),
             Line::from(Span::styled("qualified", qualified_style)),
         ])
         .alignment(Alignment::Left)
         .render(right_area, buf);
 
+        // the less common groups share a single centered line below hitting/pitching
+        let [_, groups_area, _] = Layout::vertical([
+            Constraint::Length(1),
+            Constraint::Length(1),
+            Constraint::Fill(1),
+        ])
+        .areas(inner);
+        Paragraph::new(Line::from(vec![
+            Span::styled("fielding", group_style(StatGroup::Fielding)),
+            Span::raw("  "),
+            Span::styled("catching", group_style(StatGroup::Catching)),
+            Span::raw("  "),
+            Span::styled("running", group_style(StatGroup::Running)),
+        ]))
+        .alignment(Alignment::Center)
+        .render(groups_area, buf);
+
         // Create the options rows, e.g. ["[X]", "ERA", "earned run average"]
         let mut options = Vec::new();
         for (name, stat) in &state.table.columns {
@@ -215,3 +240,82 @@ impl StatefulWidget for StatsOptionsWidget {
         StatefulWidget::render(t, options_rect, buf, &mut state.options_state);
     }
 }
+
+/// Width of a single category in the leaders grid.
+const LEADERS_CELL_WIDTH: u16 = 36;
+
+/// Renders the league leaders as a grid of small leaderboards, one per category.
+pub struct LeadersWidget<'a> {
+    pub leaders: Option<&'a Leaders>,
+    pub season: i32,
+}
+
+impl Widget for LeadersWidget<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(border_style())
+            .padding(Padding::new(1, 1, 0, 0))
+            .title(Span::styled(
+                format!(" {} League Leaders ", self.season),
+                selected_style(),
+            ));
+        let inner = block.inner(area);
+        block.render(area, buf);
+
+        let Some(leaders) = self.leaders.filter(|l| !l.is_empty()) else {
+            Paragraph::new(format!("No league leaders for {}", self.season))
+                .style(dim_style())
+                .render(inner, buf);
+            return;
+        };
+
+        let columns = (inner.width / LEADERS_CELL_WIDTH).max(1) as usize;
+        let cell_height = LEADERS_LIMIT as u16 + 2;
+        let rows = Layout::vertical(
+            leaders
+                .categories
+                .chunks(columns)
+                .map(|_| Constraint::Length(cell_height)),
+        )
+        .split(inner);
+        for (categories, row) in leaders.categories.chunks(columns).zip(rows.iter()) {
+            let cells =
+                Layout::horizontal((0..columns).map(|_| Constraint::Length(LEADERS_CELL_WIDTH)))
+                    .split(*row);
+            for (category, cell) in categories.iter().zip(cells.iter()) {
+                render_category(category, *cell, buf);
+            }
+        }
+    }
+}
+
+fn render_category(category: &CategoryLeaderboard, area: Rect, buf: &mut Buffer) {
+    let block = Block::default()
+        .borders(Borders::TOP)
+        .border_style(border_style())
+        .title(Span::styled(
+            format!(" {} ", category.category.abbreviation()),
+            header_style(),
+        ));
+    let inner = block.inner(area);
+    block.render(area, buf);
+
+    // rank (2) + team (3) + value (5) + spacing (3) + right margin (2)
+    let name_width = (inner.width as usize).saturating_sub(15);
+    let lines: Vec<Line> = category
+        .leaders
+        .iter()
+        .map(|leader| {
+            let name: String = leader.name.chars().take(name_width).collect();
+            Line::from(vec![
+                Span::styled(format!("{:>2} ", leader.rank), dim_style()),
+                Span::raw(format!("{name:<name_width$} ")),
+                Span::styled(format!("{:<3} ", leader.team), dim_style()),
+                Span::raw(format!("{:>5}", leader.value)),
+            ])
+        })
+        .collect();
+    Paragraph::new(lines).render(inner, buf);
+}
@@ -1,14 +1,14 @@
-use crate::components::team_page::TeamGame;
+use crate::components::team_page::{RosterRow, RosterView, TeamGame};
 use crate::state::team_page::{TeamPageState, TeamSection};
 use crate::ui::styling::{TEXT_COLOR, border_style, dim_style, header_style, selected_style};
 use chrono::{Datelike, NaiveDate};
-use mlbt_api::team::RosterType;
 use time::{Date, Month};
 use tui::prelude::*;
 use tui::widgets::calendar::{CalendarEventStore, Monthly};
 use tui::widgets::{Block, BorderType, Borders, Cell, Padding, Paragraph, Row, Table};
 
 const ROSTER_HEADER: &[&str] = &["Pos", "B/T", "Ht", "Wt", "DOB"];
+const INJURED_HEADER: &[&str] = &["Pos", "Status", "Eligible", "Injury"];
 
 const HOME_STYLE: Style = Style::new().fg(Color::Blue);
 const AWAY_STYLE: Style = Style::new().fg(TEXT_COLOR);
@@ -21,7 +21,7 @@ pub struct TeamPageWidget<'a> {
 impl Widget for TeamPageWidget<'_> {
     fn render(mut self, area: Rect, buf: &mut Buffer) {
         let active = self.state.active_section;
-        let roster_type = self.state.roster_type;
+        let roster_view = self.state.roster_view;
 
         let block = Block::default()
             .borders(Borders::ALL)
@@ -49,7 +49,7 @@ impl Widget for TeamPageWidget<'_> {
 
         // top: roster | separator | schedule
         let [left_with_pad, right_with_border] =
-            Layout::horizontal([Constraint::Fill(1), Constraint::Length(30)]).areas(top);
+            Layout::horizontal([Constraint::Fill(1), Constraint::Length(34)]).areas(top);
 
         let left = Rect {
             width: left_with_pad.width.saturating_sub(1),
@@ -69,7 +69,11 @@ impl Widget for TeamPageWidget<'_> {
         let right = sep_block.inner(right_with_border);
         sep_block.render(sep_render_area, buf);
 
-        self.render_roster(left, active, roster_type, buf);
+        if active == TeamSection::DepthChart {
+            self.render_depth_chart(left, buf);
+        } else {
+            self.render_roster(left, active, roster_view, buf);
+        }
         self.render_schedule(right, active, buf);
         self.render_transactions(bottom, active, buf);
     }
@@ -80,7 +84,7 @@ impl TeamPageWidget<'_> {
         &mut self,
         area: Rect,
         active: TeamSection,
-        roster_type: RosterType,
+        roster_view: RosterView,
         buf: &mut Buffer,
     ) {
         if area.height < 1 {
@@ -89,27 +93,42 @@ impl TeamPageWidget<'_> {
 
         let roster = &self.state.roster;
         if roster.is_empty() {
-            Paragraph::new(Span::styled("  No roster data", dim_style())).render(area, buf);
+            let message = match roster_view {
+                RosterView::Injured => "  No players on the injured list",
+                _ => "  No roster data",
+            };
+            // keep the label so it's clear which view is empty
+            let [header_area, message_area] =
+                Layout::vertical([Constraint::Length(1), Constraint::Fill(1)]).areas(area);
+            Line::from(Span::styled(roster_view.label(), header_style())).render(header_area, buf);
+            Paragraph::new(Span::styled(message, dim_style())).render(message_area, buf);
             return;
         }
 
-        let is_40man = roster_type == RosterType::FortyMan;
-        let roster_label = match roster_type {
-            RosterType::Active => "Roster (active)",
-            RosterType::FortyMan => "Roster (40 man)",
-        };
-
-        let mut header_cells: Vec<Cell> = vec![Cell::from(roster_label)];
-        header_cells.extend(ROSTER_HEADER.iter().map(|h| Cell::from(*h)));
-        let mut widths = vec![
-            Constraint::Length(26), // ## Name
-            Constraint::Length(4),  // Pos
-            Constraint::Length(5),  // B/T
-            Constraint::Length(7),  // Ht
-            Constraint::Length(5),  // Wt
-            Constraint::Length(12), // DOB
-        ];
-        if is_40man {
+        let show_status = matches!(roster_view, RosterView::FortyMan | RosterView::FullSeason);
+        let is_injured = roster_view == RosterView::Injured;
+
+        let mut header_cells: Vec<Cell> = vec![Cell::from(roster_view.label())];
+        let mut widths = vec![Constraint::Length(26)]; // ## Name
+        if is_injured {
+            header_cells.extend(INJURED_HEADER.iter().map(|h| Cell::from(*h)));
+            widths.extend([
+                Constraint::Length(4),  // Pos
+                Constraint::Length(16), // Status
+                Constraint::Length(8),  // Eligible
+                Constraint::Fill(1),    // Injury
+            ]);
+        } else {
+            header_cells.extend(ROSTER_HEADER.iter().map(|h| Cell::from(*h)));
+            widths.extend([
+                Constraint::Length(4),  // Pos
+                Constraint::Length(5),  // B/T
+                Constraint::Length(7),  // Ht
+                Constraint::Length(5),  // Wt
+                Constraint::Length(12), // DOB
+            ]);
+        }
+        if show_status {
             header_cells.push(Cell::from("Status"));
             widths.push(Constraint::Fill(1));
         }
@@ -128,18 +147,24 @@ impl TeamPageWidget<'_> {
                 ))]));
             }
 
+            let name = Cell::from(Line::from(vec![
+                Span::styled(format!("{:>2}  ", row.number), dim_style()),
+                Span::raw(row.name.as_str()),
+            ]));
+            if is_injured {
+                rows.push(injured_row(name, row));
+                continue;
+            }
+
             let mut cells = vec![
-                Cell::from(Line::from(vec![
-                    Span::styled(format!("{:>2}  ", row.number), dim_style()),
-                    Span::raw(row.name.as_str()),
-                ])),
+                name,
                 Cell::from(row.position.as_str()),
                 Cell::from(row.bats_throws.as_str()),
                 Cell::from(row.height.as_str()),
                 Cell::from(row.weight.as_str()),
                 Cell::from(row.dob.as_str()),
             ];
-            if is_40man {
+            if show_status {
                 cells.push(Cell::from(Span::styled(
                     row.status.as_str(),
                     il_status_style(&row.status_code),
@@ -156,6 +181,49 @@ impl TeamPageWidget<'_> {
         StatefulWidget::render(table, area, buf, &mut self.state.roster_selection);
     }
 
+    fn render_depth_chart(&mut self, area: Rect, buf: &mut Buffer) {
+        if area.height < 1 {
+            return;
+        }
+
+        let depth_chart = &self.state.depth_chart;
+        if depth_chart.is_empty() {
+            Paragraph::new(Span::styled("  No depth chart data", dim_style())).render(area, buf);
+            return;
+        }
+
+        let header =
+            Row::new(vec![Cell::from("Pos"), Cell::from("Depth chart")]).style(header_style());
+        let widths = [Constraint::Length(4), Constraint::Fill(1)];
+
+        // the starter is listed first, backups are dimmed
+        let rows: Vec<Row> = depth_chart
+            .iter()
+            .map(|row| {
+                let mut spans = Vec::with_capacity(row.players.len() * 2);
+                for (i, player) in row.players.iter().enumerate() {
+                    if i == 0 {
+                        spans.push(Span::raw(player.as_str()));
+                    } else {
+                        spans.push(Span::styled(", ", dim_style()));
+                        spans.push(Span::styled(player.as_str(), dim_style()));
+                    }
+                }
+                Row::new(vec![
+                    Cell::from(row.position.as_str()),
+                    Cell::from(Line::from(spans)),
+                ])
+            })
+            .collect();
+
+        let table = Table::new(rows, widths)
+            .header(header)
+            .row_highlight_style(selected_style())
+            .column_spacing(1);
+
+        StatefulWidget::render(table, area, buf, &mut self.state.depth_chart_selection);
+    }
+
     fn render_calendar(&self, area: Rect, buf: &mut Buffer) {
         let selected_date = self
             .state
@@ -216,19 +284,33 @@ impl TeamPageWidget<'_> {
         }
 
         let widths = [
-            Constraint::Length(8), // Date
+            Constraint::Length(1), // Series bracket
+            Constraint::Length(7), // Date
             Constraint::Length(9), // Opponent
             Constraint::Fill(1),   // Time/Score
         ];
 
         let rows: Vec<Row> = games
             .iter()
-            .map(|g| {
+            .enumerate()
+            .map(|(i, g)| {
                 let (date_style, text_style) = style_schedule_game(self.state.date, g);
+                let series_end = games.get(i + 1).is_none_or(|next| next.series_start);
+                let mut time_or_score = g.time_or_score.clone();
+                if let Some(label) = g.context.short_label() {
+                    time_or_score = format!("{time_or_score} {label}");
+                }
+                if !g.is_past {
+                    time_or_score = format!("{time_or_score} {}", g.context.day_night_symbol());
+                }
                 Row::new(vec![
+                    Cell::from(Span::styled(
+                        series_bracket(g.series_start, series_end),
+                        dim_style(),
+                    )),
                     Cell::from(Span::styled(g.date_display.as_str(), date_style)),
                     Cell::from(Span::styled(g.opponent.as_str(), text_style)),
-                    Cell::from(Span::styled(g.time_or_score.as_str(), text_style)),
+                    Cell::from(Span::styled(time_or_score, text_style)),
                 ])
             })
             .collect();
@@ -296,10 +378,31 @@ impl TeamPageWidget<'_> {
     }
 }
 
-/// Style for IL status codes in the 40-man roster view.
+/// A row in the injured list view. The eligible date is the earliest the player can be activated,
+/// not an expected return date, which the API doesn't provide.
+fn injured_row<'a>(name: Cell<'a>, row: &'a RosterRow) -> Row<'a> {
+    let injury = row.injury.as_ref();
+    let eligible = injury
+        .and_then(|i| i.eligible)
+        .map(|d| d.format("%b %-d").to_string())
+        .unwrap_or_else(|| "-".to_string());
+    let description = injury.map(|i| i.description.as_str()).unwrap_or_default();
+    Row::new(vec![
+        name,
+        Cell::from(row.position.as_str()),
+        Cell::from(Span::styled(
+            row.status.as_str(),
+            il_status_style(&row.status_code),
+        )),
+        Cell::from(eligible),
+        Cell::from(Span::styled(description, dim_style())),
+    ])
+}
+
+/// Style for IL status codes in the roster views.
 fn il_status_style(code: &str) -> Style {
     match code {
-        "D10" | "D15" => Style::default().fg(Color::Yellow),
+        "D7" | "D10" | "D15" => Style::default().fg(Color::Yellow),
         "D60" => Style::default().fg(Color::Red),
         "RM" => dim_style(),
         _ => Style::default(),
@@ -315,6 +418,16 @@ fn highlight_style(active: TeamSection, desired: TeamSection) -> Style {
     }
 }
 
+/// Bracket that groups the games of a series in the schedule.
+fn series_bracket(start: bool, end: bool) -> &'static str {
+    match (start, end) {
+        (true, true) => " ",
+        (true, false) => "┌",
+        (false, false) => "│",
+        (false, true) => "└",
+    }
+}
+
 /// Style for the calendar day and list text of a game in the schedule.
 fn style_schedule_game(today: NaiveDate, g: &TeamGame) -> (Style, Style) {
     let date_style = if g.date == today {